@@ -1,7 +1,13 @@
 use crate::graph::grad::Grads;
 use crate::tensor::ADTensor;
 use burn_tensor::backend::{ADBackend, Backend};
+use burn_tensor::{Generator, RngState};
 
+/// Backend decorator adding reverse mode autodiff to a backend.
+///
+/// Gradients are tensors of the decorated backend. Nesting the decorator, as in
+/// `ADBackendDecorator<ADBackendDecorator<B>>`, records the backward pass on the inner autodiff
+/// graph, which allows differentiating gradients to compute higher order gradients.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ADBackendDecorator<B> {
     _b: B,
@@ -27,6 +33,18 @@ impl<B: Backend> Backend for ADBackendDecorator<B> {
     fn seed(seed: u64) {
         B::seed(seed)
     }
+
+    fn fork_rng(device: B::Device) -> Generator {
+        B::fork_rng(device)
+    }
+
+    fn get_rng_state(device: B::Device) -> RngState {
+        B::get_rng_state(device)
+    }
+
+    fn set_rng_state(device: B::Device, state: RngState) {
+        B::set_rng_state(device, state)
+    }
 }
 
 impl<B: Backend> ADBackend for ADBackendDecorator<B> {
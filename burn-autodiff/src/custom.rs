@@ -0,0 +1,175 @@
+use crate::graph::ops::{BinaryOps, BinaryOpsNodeState, UnaryOps, UnaryOpsNodeState};
+use crate::ops::{binary_ops_wrapper, unary_ops_wrapper};
+use crate::ADBackendDecorator;
+use burn_tensor::{backend::Backend, Tensor};
+use std::marker::PhantomData;
+
+/// Differentiable operation with one input, defined by its forward and backward passes on the
+/// inner backend.
+///
+/// The operation is applied on autodiff tensors with [custom_op](custom_op).
+pub trait CustomOp<B: Backend, const D1: usize, const D2: usize>:
+    std::fmt::Debug + Send + Sync + 'static
+{
+    /// State saved during the forward pass and used by the backward pass.
+    type State: std::fmt::Debug + Send + Sync + 'static;
+
+    /// Computes the output and the state needed to compute the gradient.
+    fn forward(&self, input: Tensor<B, D1>) -> (Tensor<B, D2>, Self::State);
+    /// Computes the gradient of the input from the gradient of the output.
+    fn backward(&self, grad: Tensor<B, D2>, state: &Self::State) -> Tensor<B, D1>;
+    /// Name of the operation, used to inspect the graph.
+    fn name(&self) -> &'static str {
+        "custom"
+    }
+}
+
+/// Differentiable operation with two inputs, defined by its forward and backward passes on the
+/// inner backend.
+///
+/// The operation is applied on autodiff tensors with [custom_binary_op](custom_binary_op).
+pub trait CustomBinaryOp<B: Backend, const D1: usize, const D2: usize, const D3: usize>:
+    std::fmt::Debug + Send + Sync + 'static
+{
+    /// State saved during the forward pass and used by the backward pass.
+    type State: std::fmt::Debug + Send + Sync + 'static;
+
+    /// Computes the output and the state needed to compute the gradients.
+    fn forward(&self, lhs: Tensor<B, D1>, rhs: Tensor<B, D2>) -> (Tensor<B, D3>, Self::State);
+    /// Computes the gradients of both inputs from the gradient of the output.
+    fn backward(&self, grad: Tensor<B, D3>, state: &Self::State) -> (Tensor<B, D1>, Tensor<B, D2>);
+    /// Name of the operation, used to inspect the graph.
+    fn name(&self) -> &'static str {
+        "custom"
+    }
+}
+
+/// Applies the [custom operation](CustomOp) on the input tensor, recording it in the graph.
+pub fn custom_op<B, O, const D1: usize, const D2: usize>(
+    op: O,
+    input: &Tensor<ADBackendDecorator<B>, D1>,
+) -> Tensor<ADBackendDecorator<B>, D2>
+where
+    B: Backend,
+    O: CustomOp<B, D1, D2>,
+{
+    let node = input.clone().into_primitive().node;
+    let (output, state) = op.forward(input.inner());
+    let ops = CustomOpBackward::<B, O, D1, D2>::new(op, state);
+
+    Tensor::from_primitive(unary_ops_wrapper(node, output.into_primitive(), ops))
+}
+
+/// Applies the [custom operation](CustomBinaryOp) on the input tensors, recording it in the
+/// graph.
+pub fn custom_binary_op<B, O, const D1: usize, const D2: usize, const D3: usize>(
+    op: O,
+    lhs: &Tensor<ADBackendDecorator<B>, D1>,
+    rhs: &Tensor<ADBackendDecorator<B>, D2>,
+) -> Tensor<ADBackendDecorator<B>, D3>
+where
+    B: Backend,
+    O: CustomBinaryOp<B, D1, D2, D3>,
+{
+    let node_lhs = lhs.clone().into_primitive().node;
+    let node_rhs = rhs.clone().into_primitive().node;
+    let (output, state) = op.forward(lhs.inner(), rhs.inner());
+    let ops = CustomBinaryOpBackward::<B, O, D1, D2, D3>::new(op, state);
+
+    Tensor::from_primitive(binary_ops_wrapper(
+        node_lhs,
+        node_rhs,
+        output.into_primitive(),
+        ops,
+    ))
+}
+
+#[derive(new, Debug)]
+struct CustomOpBackward<B, O, const D1: usize, const D2: usize>
+where
+    B: Backend,
+    O: CustomOp<B, D1, D2>,
+{
+    op: O,
+    state: O::State,
+    _b: PhantomData<B>,
+}
+
+impl<B, O, const D1: usize, const D2: usize>
+    UnaryOps<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>> for CustomOpBackward<B, O, D1, D2>
+where
+    B: Backend,
+    O: CustomOp<B, D1, D2>,
+{
+    fn partial(
+        &self,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>>,
+    ) -> B::TensorPrimitive<D1> {
+        let grad = Tensor::from_primitive(state.output.grad());
+
+        self.op.backward(grad, &self.state).into_primitive()
+    }
+
+    fn name(&self) -> &'static str {
+        self.op.name()
+    }
+}
+
+#[derive(new, Debug)]
+struct CustomBinaryOpBackward<B, O, const D1: usize, const D2: usize, const D3: usize>
+where
+    B: Backend,
+    O: CustomBinaryOp<B, D1, D2, D3>,
+{
+    op: O,
+    state: O::State,
+    _b: PhantomData<B>,
+}
+
+impl<B, O, const D1: usize, const D2: usize, const D3: usize>
+    BinaryOps<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>, B::TensorPrimitive<D3>>
+    for CustomBinaryOpBackward<B, O, D1, D2, D3>
+where
+    B: Backend,
+    O: CustomBinaryOp<B, D1, D2, D3>,
+{
+    fn partial_left(
+        &self,
+        state: &BinaryOpsNodeState<
+            B::TensorPrimitive<D1>,
+            B::TensorPrimitive<D2>,
+            B::TensorPrimitive<D3>,
+        >,
+    ) -> B::TensorPrimitive<D1> {
+        self.partials(state).0
+    }
+
+    fn partial_right(
+        &self,
+        state: &BinaryOpsNodeState<
+            B::TensorPrimitive<D1>,
+            B::TensorPrimitive<D2>,
+            B::TensorPrimitive<D3>,
+        >,
+    ) -> B::TensorPrimitive<D2> {
+        self.partials(state).1
+    }
+
+    fn partials(
+        &self,
+        state: &BinaryOpsNodeState<
+            B::TensorPrimitive<D1>,
+            B::TensorPrimitive<D2>,
+            B::TensorPrimitive<D3>,
+        >,
+    ) -> (B::TensorPrimitive<D1>, B::TensorPrimitive<D2>) {
+        let grad = Tensor::from_primitive(state.output.grad());
+        let (grad_lhs, grad_rhs) = self.op.backward(grad, &self.state);
+
+        (grad_lhs.into_primitive(), grad_rhs.into_primitive())
+    }
+
+    fn name(&self) -> &'static str {
+        self.op.name()
+    }
+}
@@ -0,0 +1,62 @@
+use crate::graph::converter::Forward2BackwardGraphConverter;
+use crate::graph::ops::RecordedOpsParentRef;
+use crate::graph::traversal::{BreadthFirstSearch, GraphTraversal};
+use crate::ADBackendDecorator;
+use burn_tensor::backend::{ADBackend, Backend};
+use burn_tensor::Tensor;
+use std::fmt::Write;
+
+/// Exports the backward graph of a tensor in the [Graphviz](https://graphviz.org) DOT format.
+///
+/// Each node is labelled with its operation, id, shape and order, and points to the nodes
+/// computed from it. Leaves requiring gradients are filled and constants are dashed. When the
+/// gradients of a backward pass are given, nodes are annotated with the L2 norm of their
+/// gradient, which helps finding where gradients vanish or are never registered.
+pub fn graph_to_dot<B: Backend, const D: usize>(
+    tensor: &Tensor<ADBackendDecorator<B>, D>,
+    grads: Option<&<ADBackendDecorator<B> as ADBackend>::Gradients>,
+) -> String {
+    let node = tensor.clone().into_primitive().node;
+    let mut converter = Forward2BackwardGraphConverter::new();
+    let root = converter.from(&node);
+
+    let mut nodes: Vec<RecordedOpsParentRef> = vec![root.clone()];
+    BreadthFirstSearch::new(&root).traverse(|node| nodes.push(node));
+    nodes.sort_by_key(|node| node.order());
+
+    let mut dot = String::from("digraph {\n");
+
+    for node in nodes.iter() {
+        let leaf = node.order() == 0;
+        let name = match leaf && !node.requires_grad() {
+            true => "constant",
+            false => node.name(),
+        };
+        let mut label = format!(
+            "{}\\nid: {}\\nshape: {:?}\\norder: {}",
+            name,
+            node.id(),
+            node.shape(),
+            node.order()
+        );
+        if let Some(norm) = grads.and_then(|grads| node.grad_norm(grads)) {
+            write!(label, "\\ngrad norm: {norm:e}").unwrap();
+        }
+        let style = match (leaf, node.requires_grad()) {
+            (true, true) => ", style=filled, fillcolor=lightblue",
+            (true, false) => ", style=dashed",
+            _ => "",
+        };
+
+        writeln!(dot, "  \"{}\" [label=\"{}\"{}];", node.id(), label, style).unwrap();
+    }
+
+    for node in nodes.iter() {
+        for parent in node.backward_parents() {
+            writeln!(dot, "  \"{}\" -> \"{}\";", parent.id(), node.id()).unwrap();
+        }
+    }
+
+    dot.push_str("}\n");
+    dot
+}
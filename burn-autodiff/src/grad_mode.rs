@@ -0,0 +1,39 @@
+use std::cell::Cell;
+
+thread_local! {
+    static GRAD_ENABLED: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Executes the function without recording its operations in the autodiff graph.
+///
+/// Operations executed in the scope return tensors with their gradients disabled. The scope only
+/// applies to operations executed on the current thread.
+pub fn no_grad<F, R>(function: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    with_grad_mode(false, function)
+}
+
+/// Returns whether operations are recorded in the autodiff graph on the current thread.
+pub fn is_grad_enabled() -> bool {
+    GRAD_ENABLED.with(|enabled| enabled.get())
+}
+
+pub(crate) fn with_grad_mode<F, R>(enabled: bool, function: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    // Restore the previous mode even if the function panics.
+    struct Guard(bool);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            GRAD_ENABLED.with(|enabled| enabled.set(self.0));
+        }
+    }
+
+    let _guard = Guard(GRAD_ENABLED.with(|mode| mode.replace(enabled)));
+
+    function()
+}
@@ -0,0 +1,162 @@
+use crate::ADBackendDecorator;
+use burn_tensor::{backend::Backend, Data, Distribution, ElementConversion, Generator, Tensor};
+
+/// Element of an input whose gradient differs from its finite difference approximation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradCheckDiscrepancy {
+    /// Position of the input in the given slice.
+    pub input: usize,
+    /// Index of the element in the input.
+    pub index: Vec<usize>,
+    /// Gradient computed by the autodiff backend.
+    pub analytical: f64,
+    /// Gradient approximated with central finite differences.
+    pub numerical: f64,
+}
+
+/// Error returned by [gradcheck](gradcheck) with all the elements failing the check.
+#[derive(Debug, Clone)]
+pub struct GradCheckError {
+    pub discrepancies: Vec<GradCheckDiscrepancy>,
+}
+
+impl std::fmt::Display for GradCheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            format!(
+                "Gradient check error => {} elements differ from finite differences:",
+                self.discrepancies.len()
+            )
+            .as_str(),
+        )?;
+
+        for discrepancy in self.discrepancies.iter() {
+            f.write_str(
+                format!(
+                    "\n  input {} at {:?}: analytical {}, numerical {}",
+                    discrepancy.input,
+                    discrepancy.index,
+                    discrepancy.analytical,
+                    discrepancy.numerical
+                )
+                .as_str(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for GradCheckError {}
+
+/// Function checked by [gradcheck](gradcheck).
+///
+/// The gradients are computed on the autodiff backend, while the finite differences are computed
+/// on its inner backend, so that they don't depend on the operations recorded in the graph. Both
+/// methods usually share the same generic body, except for operations only defined on the
+/// autodiff backend, like [custom operations](crate::CustomOp).
+pub trait GradCheckFunction<B: Backend, const D1: usize, const D2: usize> {
+    /// Applies the function on autodiff tensors.
+    fn forward(
+        &self,
+        inputs: &[Tensor<ADBackendDecorator<B>, D1>],
+    ) -> Tensor<ADBackendDecorator<B>, D2>;
+    /// Applies the function on the tensors of the inner backend.
+    fn forward_inner(&self, inputs: &[Tensor<B, D1>]) -> Tensor<B, D2>;
+}
+
+/// Compares the gradients of the function with respect to each input against central finite
+/// differences.
+///
+/// The output is projected on fixed random weights before being summed, so that every output
+/// element contributes differently to the gradients. An element fails the check when the
+/// absolute difference between both gradients is greater than `tolerance * max(1, |numerical|)`.
+pub fn gradcheck<B, F, const D1: usize, const D2: usize>(
+    function: &F,
+    inputs: &[Tensor<ADBackendDecorator<B>, D1>],
+    eps: f64,
+    tolerance: f64,
+) -> Result<(), GradCheckError>
+where
+    B: Backend,
+    F: GradCheckFunction<B, D1, D2>,
+{
+    let data: Vec<Data<B::Elem, D1>> = inputs.iter().map(|input| input.to_data()).collect();
+
+    let leaves: Vec<Tensor<ADBackendDecorator<B>, D1>> = data
+        .iter()
+        .zip(inputs.iter())
+        .map(|(data, input)| Tensor::from_data_device(data.clone(), input.device()))
+        .collect();
+    let output = function.forward(&leaves);
+    let distribution = Distribution::Uniform((-1.0).to_elem(), 1.0.to_elem());
+    let weights = Tensor::<B, D2>::random_with_device(
+        *output.shape(),
+        distribution,
+        &mut Generator::new(0),
+        output.device(),
+    );
+    let grads = output
+        .mul(&Tensor::from_inner(weights.clone()))
+        .sum()
+        .backward();
+
+    let mut discrepancies = Vec::new();
+
+    for (input, leaf) in leaves.iter().enumerate() {
+        let num_elements = data[input].value.len();
+        let analytical = match leaf.grad(&grads) {
+            Some(grad) => grad.into_data().value,
+            None => vec![0.0.to_elem(); num_elements],
+        };
+
+        for i in 0..num_elements {
+            let evaluate = |delta: f64| {
+                let mut data = data.clone();
+                let value = data[input].value[i].to_elem::<f64>() + delta;
+                data[input].value[i] = value.to_elem();
+
+                let tensors: Vec<Tensor<B, D1>> = data
+                    .into_iter()
+                    .zip(inputs.iter())
+                    .map(|(data, input)| Tensor::from_data_device(data, input.device()))
+                    .collect();
+
+                function
+                    .forward_inner(&tensors)
+                    .mul(&weights)
+                    .sum()
+                    .into_data()
+                    .value[0]
+                    .to_elem::<f64>()
+            };
+            let numerical = (evaluate(eps) - evaluate(-eps)) / (2.0 * eps);
+            let analytical = analytical[i].to_elem::<f64>();
+
+            if (analytical - numerical).abs() > tolerance * f64::max(1.0, numerical.abs()) {
+                discrepancies.push(GradCheckDiscrepancy {
+                    input,
+                    index: unravel_index(i, &data[input].shape.dims),
+                    analytical,
+                    numerical,
+                });
+            }
+        }
+    }
+
+    match discrepancies.is_empty() {
+        true => Ok(()),
+        false => Err(GradCheckError { discrepancies }),
+    }
+}
+
+fn unravel_index(mut position: usize, dims: &[usize]) -> Vec<usize> {
+    let mut index = vec![0; dims.len()];
+
+    for (i, dim) in dims.iter().enumerate().rev() {
+        index[i] = position % dim;
+        position /= dim;
+    }
+
+    index
+}
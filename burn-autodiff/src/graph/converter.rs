@@ -1,6 +1,6 @@
-use crate::graph::node::{BackwardNode, BackwardNodeRef, ForwardNodeRef};
+use crate::graph::node::{BackwardNode, BackwardNodeRef, ForwardNode};
 use burn_tensor::ops::Zeros;
-use std::{any::Any, collections::HashMap, sync::Arc};
+use std::{any::Any, collections::HashMap, ops::Add, sync::Arc};
 
 #[derive(Default)]
 pub struct Forward2BackwardGraphConverter {
@@ -15,7 +15,7 @@ impl Forward2BackwardGraphConverter {
     }
     pub fn from<T: Clone + 'static + Zeros>(
         &mut self,
-        node: &ForwardNodeRef<T>,
+        node: &ForwardNode<T>,
     ) -> BackwardNodeRef<T> {
         if let Some(node) = self.state.get(&node.id) {
             let node: &BackwardNodeRef<T> = node.downcast_ref().unwrap();
@@ -26,4 +26,17 @@ impl Forward2BackwardGraphConverter {
         self.state.insert(node.id.clone(), Box::new(node.clone()));
         node
     }
+    pub fn leaf<T>(&mut self, node: &ForwardNode<T>) -> BackwardNodeRef<T>
+    where
+        T: Clone + Zeros + Add<Output = T> + std::fmt::Debug + 'static,
+    {
+        if let Some(node) = self.state.get(&node.id) {
+            let node: &BackwardNodeRef<T> = node.downcast_ref().unwrap();
+            return node.clone();
+        };
+
+        let node = Arc::new(BackwardNode::from_leaf(node));
+        self.state.insert(node.id.clone(), Box::new(node.clone()));
+        node
+    }
 }
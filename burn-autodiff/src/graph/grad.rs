@@ -60,8 +60,11 @@ impl Grads {
     }
 
     pub fn wrt<T: 'static, V: AsNode<T>>(&self, variable: &V) -> Option<&T> {
-        let node = variable.as_node();
-        let grad = match self.grads.get(&node.id) {
+        self.get_node(&variable.as_node().id)
+    }
+
+    pub fn get_node<T: 'static>(&self, id: &str) -> Option<&T> {
+        let grad = match self.grads.get(id) {
             Some(grad) => grad,
             None => return None,
         };
@@ -1,8 +1,8 @@
-use super::{BackwardNodeState, ForwardNodeRef};
+use super::{BackwardNodeState, ForwardNode};
 use crate::graph::grad::Grads;
 use crate::graph::{
     converter::Forward2BackwardGraphConverter,
-    ops::{BackwardRecordedOpsBoxed, RecordedOpsParent, RecordedOpsParentRef},
+    ops::{BackwardRecordedOpsBoxed, InitRecordedOps, RecordedOpsParent, RecordedOpsParentRef},
     traversal::{BreadthFirstSearch, GraphTraversal},
 };
 use burn_tensor::ops::{Ones, Zeros};
@@ -14,19 +14,38 @@ pub struct BackwardNode<Out> {
     pub order: usize,
     pub state: BackwardNodeState<Out>,
     pub ops: BackwardRecordedOpsBoxed<Out>,
+    pub requires_grad: bool,
 }
 pub type BackwardNodeRef<Out> = Arc<BackwardNode<Out>>;
 
 impl<Out: Clone + Zeros> BackwardNode<Out> {
     pub fn from_node(
-        node: &ForwardNodeRef<Out>,
+        node: &ForwardNode<Out>,
         converter: &mut Forward2BackwardGraphConverter,
     ) -> Self {
         BackwardNode {
             id: node.id.clone(),
             order: node.order,
-            state: BackwardNodeState::new(node.state.value()),
+            state: BackwardNodeState::new(node.state.value(), node.state.metadata()),
             ops: node.ops.to_backward(converter),
+            requires_grad: node.requires_grad,
+        }
+    }
+}
+
+impl<Out> BackwardNode<Out>
+where
+    Out: Clone + Zeros + Add<Output = Out> + std::fmt::Debug + 'static,
+{
+    /// Creates a node without the operations of its forward node, the node becoming a leaf of
+    /// the backward graph.
+    pub fn from_leaf(node: &ForwardNode<Out>) -> Self {
+        BackwardNode {
+            id: node.id.clone(),
+            order: node.order,
+            state: BackwardNodeState::new(node.state.value(), node.state.metadata()),
+            ops: Box::new(InitRecordedOps::new()),
+            requires_grad: node.requires_grad,
         }
     }
 }
@@ -38,6 +57,10 @@ where
 {
     pub fn backward(&mut self) -> Grads {
         let grad = self.state.value().ones();
+        self.backward_with_grad(grad)
+    }
+
+    pub fn backward_with_grad(&mut self, grad: Out) -> Grads {
         self.state.update_grad(grad);
         self.ops.backward_step(&self.state);
 
@@ -88,7 +111,26 @@ where
     fn id(&self) -> &String {
         &self.id
     }
+    fn requires_grad(&self) -> bool {
+        self.requires_grad
+    }
+    fn name(&self) -> &'static str {
+        self.ops.name()
+    }
+    fn shape(&self) -> Vec<usize> {
+        (self.state.metadata.shape)(&self.state.value)
+    }
+    fn grad_norm(&self, grads: &Grads) -> Option<f64> {
+        grads.get_node::<T>(&self.id).map(self.state.metadata.norm)
+    }
     fn register_grad(&self, grads: &mut Grads) {
-        grads.register_node(self)
+        if self.requires_grad {
+            grads.register_node(self)
+        }
+    }
+    fn accumulate_grad(&self, grads: &Grads) {
+        if let Some(grad) = grads.get_node::<T>(&self.id) {
+            self.state.update_grad(grad.clone());
+        }
     }
 }
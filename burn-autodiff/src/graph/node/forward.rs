@@ -1,22 +1,53 @@
 use super::ForwardNodeState;
-use crate::graph::ops::ForwardRecordedOpsBoxed;
-use std::sync::Arc;
+use crate::graph::{
+    converter::Forward2BackwardGraphConverter,
+    ops::{
+        ForwardRecordedOpsBoxed, ForwardRecordedOpsParent, ForwardRecordedOpsParentRef,
+        RecordedOpsParentRef,
+    },
+};
+use burn_tensor::ops::Zeros;
+use std::{
+    ops::Add,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+static NUM_NODES_CREATED: AtomicU64 = AtomicU64::new(0);
 
 #[derive(Debug)]
 pub struct ForwardNode<Out> {
     pub id: String,
     pub order: usize,
+    /// Position of the node in the order in which the nodes are created.
+    pub sequence: u64,
     pub state: ForwardNodeState<Out>,
     pub ops: ForwardRecordedOpsBoxed<Out>,
+    pub requires_grad: bool,
 }
 pub type ForwardNodeRef<Out> = Arc<ForwardNode<Out>>;
 
 impl<Out> ForwardNode<Out> {
+    /// Returns the sequence of the next node created, every node created afterward having a
+    /// greater or equal sequence.
+    pub fn next_sequence() -> u64 {
+        NUM_NODES_CREATED.load(Ordering::Relaxed)
+    }
+
     pub fn from_root(state: ForwardNodeState<Out>, ops: ForwardRecordedOpsBoxed<Out>) -> Self {
         let order = 0;
         Self::new(order, state, ops)
     }
 
+    pub fn from_constant(state: ForwardNodeState<Out>, ops: ForwardRecordedOpsBoxed<Out>) -> Self {
+        Self {
+            requires_grad: false,
+            ..Self::from_root(state, ops)
+        }
+    }
+
     pub fn from_unary<T>(
         node: &ForwardNode<T>,
         state: ForwardNodeState<Out>,
@@ -42,11 +73,41 @@ impl<Out> ForwardNode<Out> {
         ops: ForwardRecordedOpsBoxed<Out>,
     ) -> Self {
         let id = nanoid::nanoid!();
+        let sequence = NUM_NODES_CREATED.fetch_add(1, Ordering::Relaxed);
         Self {
             id,
             order,
+            sequence,
             state,
             ops,
+            requires_grad: true,
         }
     }
 }
+
+impl<Out> ForwardRecordedOpsParent for ForwardNode<Out>
+where
+    Out: Clone + Zeros + Add<Output = Out> + std::fmt::Debug + 'static + Send + Sync,
+{
+    fn order(&self) -> usize {
+        self.order
+    }
+    fn id(&self) -> &String {
+        &self.id
+    }
+    fn sequence(&self) -> u64 {
+        self.sequence
+    }
+    fn requires_grad(&self) -> bool {
+        self.requires_grad
+    }
+    fn forward_parents(&self) -> Vec<ForwardRecordedOpsParentRef> {
+        self.ops.forward_parents()
+    }
+    fn to_backward(&self, graph: &mut Forward2BackwardGraphConverter) -> RecordedOpsParentRef {
+        graph.from(self)
+    }
+    fn to_backward_leaf(&self, graph: &mut Forward2BackwardGraphConverter) -> RecordedOpsParentRef {
+        graph.leaf(self)
+    }
+}
@@ -1,9 +1,19 @@
 use burn_tensor::ops::Zeros;
 use std::{cell::RefCell, ops::Add};
 
+/// Description of the value of a node, used to inspect the graph.
+///
+/// The description is computed from the value only when the graph is inspected.
+#[derive(new, Debug, Clone)]
+pub struct NodeMetadata<Out> {
+    pub shape: fn(&Out) -> Vec<usize>,
+    pub norm: fn(&Out) -> f64,
+}
+
 #[derive(new, Debug)]
 pub struct ForwardNodeState<Out> {
     value: Out,
+    metadata: NodeMetadata<Out>,
 }
 impl<Out> ForwardNodeState<Out>
 where
@@ -15,20 +25,28 @@ where
     pub fn value_ref(&self) -> &Out {
         &self.value
     }
+    pub fn metadata(&self) -> NodeMetadata<Out> {
+        self.metadata.clone()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct BackwardNodeState<Out> {
     pub value: Out,
     pub grad: RefCell<Out>,
+    pub metadata: NodeMetadata<Out>,
 }
 
 impl<Out: Zeros> BackwardNodeState<Out> {
-    pub fn new(value: Out) -> Self {
+    pub fn new(value: Out, metadata: NodeMetadata<Out>) -> Self {
         let grad = value.zeros();
         let grad = RefCell::new(grad);
 
-        Self { value, grad }
+        Self {
+            value,
+            grad,
+            metadata,
+        }
     }
 }
 impl<Out> BackwardNodeState<Out>
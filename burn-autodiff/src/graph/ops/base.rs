@@ -19,6 +19,7 @@ pub struct UnaryOpsNodeState<'a, In, Out> {
 pub trait BackwardRecordedOps<T>: std::fmt::Debug {
     fn backward_step(&self, state: &BackwardNodeState<T>);
     fn backward_parents(&self) -> Vec<RecordedOpsParentRef>;
+    fn name(&self) -> &'static str;
 }
 
 pub trait ForwardRecordedOps<T>: std::fmt::Debug + Send + Sync {
@@ -26,16 +27,34 @@ pub trait ForwardRecordedOps<T>: std::fmt::Debug + Send + Sync {
         &self,
         graph: &mut Forward2BackwardGraphConverter,
     ) -> BackwardRecordedOpsBoxed<T>;
+    fn forward_parents(&self) -> Vec<ForwardRecordedOpsParentRef>;
 }
 
 pub trait RecordedOpsParent: std::fmt::Debug {
     fn order(&self) -> usize;
     fn id(&self) -> &String;
+    fn requires_grad(&self) -> bool;
+    fn name(&self) -> &'static str;
+    fn shape(&self) -> Vec<usize>;
+    fn grad_norm(&self, grads: &Grads) -> Option<f64>;
     fn backward_step(&self);
     fn backward_parents(&self) -> Vec<RecordedOpsParentRef>;
     fn register_grad(&self, grads: &mut Grads);
+    fn accumulate_grad(&self, grads: &Grads);
+}
+
+pub trait ForwardRecordedOpsParent: std::fmt::Debug + Send + Sync {
+    fn order(&self) -> usize;
+    fn id(&self) -> &String;
+    fn sequence(&self) -> u64;
+    fn requires_grad(&self) -> bool;
+    fn forward_parents(&self) -> Vec<ForwardRecordedOpsParentRef>;
+    fn to_backward(&self, graph: &mut Forward2BackwardGraphConverter) -> RecordedOpsParentRef;
+    /// Converts the node without its parents, so that the backward pass stops at the node.
+    fn to_backward_leaf(&self, graph: &mut Forward2BackwardGraphConverter) -> RecordedOpsParentRef;
 }
 
 pub type ForwardRecordedOpsBoxed<T> = Box<dyn ForwardRecordedOps<T>>;
 pub type BackwardRecordedOpsBoxed<T> = Box<dyn BackwardRecordedOps<T>>;
 pub type RecordedOpsParentRef = Arc<dyn RecordedOpsParent>;
+pub type ForwardRecordedOpsParentRef = Arc<dyn ForwardRecordedOpsParent>;
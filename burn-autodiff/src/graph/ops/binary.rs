@@ -1,6 +1,6 @@
 use super::{
     BackwardRecordedOps, BackwardRecordedOpsBoxed, BinaryOpsNodeState, ForwardRecordedOps,
-    RecordedOpsParentRef,
+    ForwardRecordedOpsParentRef, RecordedOpsParentRef,
 };
 use crate::graph::{
     converter::Forward2BackwardGraphConverter,
@@ -12,6 +12,11 @@ use std::{ops::Add, sync::Arc};
 pub trait BinaryOps<Lhs, Rhs, Out>: std::fmt::Debug + Send + Sync {
     fn partial_left(&self, state: &BinaryOpsNodeState<Lhs, Rhs, Out>) -> Lhs;
     fn partial_right(&self, state: &BinaryOpsNodeState<Lhs, Rhs, Out>) -> Rhs;
+    fn partials(&self, state: &BinaryOpsNodeState<Lhs, Rhs, Out>) -> (Lhs, Rhs) {
+        (self.partial_left(state), self.partial_right(state))
+    }
+    /// Name of the operation, used to inspect the graph.
+    fn name(&self) -> &'static str;
 }
 
 #[derive(new, Debug)]
@@ -45,6 +50,10 @@ where
 
         Box::new(BackwardBinaryRecordedOps::new(lhs, rhs, ops))
     }
+
+    fn forward_parents(&self) -> Vec<ForwardRecordedOpsParentRef> {
+        vec![self.lhs.clone(), self.rhs.clone()]
+    }
 }
 
 impl<Lhs, Rhs, Out, Ops> BackwardRecordedOps<Out> for BackwardBinaryRecordedOps<Lhs, Rhs, Ops>
@@ -57,8 +66,7 @@ where
     fn backward_step(&self, state: &BackwardNodeState<Out>) {
         let state = BinaryOpsNodeState::new(&self.lhs.state, &self.rhs.state, state);
 
-        let partial_left: Lhs = self.ops.partial_left(&state);
-        let partial_right: Rhs = self.ops.partial_right(&state);
+        let (partial_left, partial_right) = self.ops.partials(&state);
 
         self.lhs.state.update_grad(partial_left);
         self.rhs.state.update_grad(partial_right);
@@ -67,4 +75,8 @@ where
     fn backward_parents(&self) -> Vec<RecordedOpsParentRef> {
         vec![self.lhs.clone(), self.rhs.clone()]
     }
+
+    fn name(&self) -> &'static str {
+        self.ops.name()
+    }
 }
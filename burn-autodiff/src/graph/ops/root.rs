@@ -1,4 +1,6 @@
-use super::{BackwardRecordedOps, ForwardRecordedOps, RecordedOpsParentRef};
+use super::{
+    BackwardRecordedOps, ForwardRecordedOps, ForwardRecordedOpsParentRef, RecordedOpsParentRef,
+};
 use crate::graph::{converter::Forward2BackwardGraphConverter, node::BackwardNodeState};
 use burn_tensor::ops::Zeros;
 use std::ops::Add;
@@ -14,6 +16,9 @@ where
     fn backward_parents(&self) -> Vec<RecordedOpsParentRef> {
         vec![]
     }
+    fn name(&self) -> &'static str {
+        "leaf"
+    }
 }
 
 impl<Out> ForwardRecordedOps<Out> for InitRecordedOps
@@ -26,4 +31,8 @@ where
     ) -> super::BackwardRecordedOpsBoxed<Out> {
         Box::new(self.clone())
     }
+
+    fn forward_parents(&self) -> Vec<ForwardRecordedOpsParentRef> {
+        vec![]
+    }
 }
@@ -1,4 +1,7 @@
-use super::{BackwardRecordedOps, ForwardRecordedOps, RecordedOpsParentRef, UnaryOpsNodeState};
+use super::{
+    BackwardRecordedOps, ForwardRecordedOps, ForwardRecordedOpsParentRef, RecordedOpsParentRef,
+    UnaryOpsNodeState,
+};
 use crate::graph::{
     converter::Forward2BackwardGraphConverter,
     node::{BackwardNodeRef, BackwardNodeState, ForwardNodeRef},
@@ -8,6 +11,8 @@ use std::{ops::Add, sync::Arc};
 
 pub trait UnaryOps<In, Out>: std::fmt::Debug + Send + Sync {
     fn partial(&self, state: &UnaryOpsNodeState<In, Out>) -> In;
+    /// Name of the operation, used to inspect the graph.
+    fn name(&self) -> &'static str;
 }
 
 #[derive(new, Debug)]
@@ -37,6 +42,10 @@ where
 
         Box::new(BackwareUnaryRecordedOps::new(input, ops))
     }
+
+    fn forward_parents(&self) -> Vec<ForwardRecordedOpsParentRef> {
+        vec![self.input.clone()]
+    }
 }
 
 impl<In, Out, Ops> BackwardRecordedOps<Out> for BackwareUnaryRecordedOps<In, Ops>
@@ -53,4 +62,7 @@ where
     fn backward_parents(&self) -> Vec<RecordedOpsParentRef> {
         vec![self.input.clone()]
     }
+    fn name(&self) -> &'static str {
+        self.ops.name()
+    }
 }
@@ -1,4 +1,7 @@
-use crate::graph::{node::BackwardNode, ops::RecordedOpsParentRef};
+use crate::graph::{
+    node::BackwardNode,
+    ops::{ForwardRecordedOpsParentRef, RecordedOpsParentRef},
+};
 use std::collections::HashSet;
 
 pub trait GraphTraversal {
@@ -43,3 +46,34 @@ impl<'a, T> GraphTraversal for BreadthFirstSearch<'a, T> {
         }
     }
 }
+
+/// Traverse the forward graph from a node, including the node itself.
+///
+/// The parents of a node are only traversed when the callback returns true.
+#[derive(new)]
+pub struct ForwardBreadthFirstSearch {
+    node: ForwardRecordedOpsParentRef,
+}
+
+impl ForwardBreadthFirstSearch {
+    pub fn traverse<F: FnMut(ForwardRecordedOpsParentRef) -> bool>(&self, mut callback: F) {
+        let mut visited = HashSet::new();
+        let mut parents = vec![self.node.clone()];
+
+        while let Some(node) = parents.pop() {
+            if !visited.insert(node.id().clone()) {
+                continue;
+            }
+
+            if !callback(node.clone()) {
+                continue;
+            }
+
+            for parent in node.forward_parents() {
+                if !visited.contains(parent.id()) {
+                    parents.push(parent);
+                }
+            }
+        }
+    }
+}
@@ -6,7 +6,15 @@ pub(crate) mod ops;
 pub(crate) mod tensor;
 
 mod backend;
+mod custom;
+mod dot;
+mod grad_mode;
+mod gradcheck;
 pub use backend::*;
+pub use custom::*;
+pub use dot::*;
+pub use grad_mode::*;
+pub use gradcheck::*;
 
 #[cfg(feature = "export_tests")]
 mod tests;
@@ -1,7 +1,8 @@
 use crate::graph::{
-    node::{ForwardNode, ForwardNodeRef, ForwardNodeState},
+    node::{ForwardNode, ForwardNodeRef},
     ops::{BinaryOps, ForwardBinaryRecordedOps, ForwardUnaryRecordedOps, UnaryOps},
 };
+use crate::is_grad_enabled;
 use crate::tensor::ADTensor;
 use burn_tensor::backend::Backend;
 use std::sync::Arc;
@@ -16,8 +17,12 @@ where
     B2: Backend,
     O: UnaryOps<B1::TensorPrimitive<D1>, B2::TensorPrimitive<D2>> + 'static,
 {
+    if !input.requires_grad || !is_grad_enabled() {
+        return ADTensor::from_constant(output);
+    }
+
     let shape = *B2::shape(&output);
-    let state = ForwardNodeState::new(output);
+    let state = ADTensor::<D2, B2>::node_state(output);
 
     let ops = Arc::new(ops);
     let ops = ForwardUnaryRecordedOps::new(input.clone(), ops);
@@ -51,8 +56,12 @@ where
     B: Backend,
     O: BinaryOps<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>, B::TensorPrimitive<D3>> + 'static,
 {
+    if !(lhs.requires_grad || rhs.requires_grad) || !is_grad_enabled() {
+        return ADTensor::from_constant(output);
+    }
+
     let shape = *B::shape(&output);
-    let state = ForwardNodeState::new(output);
+    let state = ADTensor::<D3, B>::node_state(output);
 
     let ops = Arc::new(ops);
     let ops = ForwardBinaryRecordedOps::new(lhs.clone(), rhs.clone(), ops);
@@ -1,9 +1,18 @@
 use super::unary_ops_wrapper;
-use crate::graph::ops::{UnaryOps, UnaryOpsNodeState};
+use crate::grad_mode::with_grad_mode;
+use crate::graph::converter::Forward2BackwardGraphConverter;
+use crate::graph::node::{BackwardNodeRef, BackwardNodeState, ForwardNode, ForwardNodeRef};
+use crate::graph::ops::{
+    BackwardRecordedOps, BackwardRecordedOpsBoxed, ForwardRecordedOps, ForwardRecordedOpsParentRef,
+    RecordedOpsParentRef, UnaryOps, UnaryOpsNodeState,
+};
+use crate::graph::traversal::ForwardBreadthFirstSearch;
 use crate::tensor::ADTensor;
-use crate::ADBackendDecorator;
+use crate::{is_grad_enabled, ADBackendDecorator};
 use burn_tensor::backend::Backend;
 use burn_tensor::ops::*;
+use burn_tensor::RngState;
+use std::sync::Arc;
 
 #[derive(new, Debug)]
 struct EmbeddingBackward<B: Backend> {
@@ -17,6 +26,135 @@ impl<B: Backend> UnaryOps<B::TensorPrimitive<2>, B::TensorPrimitive<3>> for Embe
     ) -> B::TensorPrimitive<2> {
         B::embedding_backward(&state.input.value, &state.output.grad(), &self.indexes)
     }
+
+    fn name(&self) -> &'static str {
+        "embedding"
+    }
+}
+
+#[derive(new, Debug)]
+struct EmbeddingBackwardBackward<B: Backend> {
+    indexes: <B::IntegerBackend as Backend>::TensorPrimitive<2>,
+}
+
+impl<B: Backend> UnaryOps<B::TensorPrimitive<3>, B::TensorPrimitive<2>>
+    for EmbeddingBackwardBackward<B>
+{
+    fn partial(
+        &self,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<3>, B::TensorPrimitive<2>>,
+    ) -> B::TensorPrimitive<3> {
+        B::embedding(&state.output.grad(), &self.indexes)
+    }
+
+    fn name(&self) -> &'static str {
+        "embedding_backward"
+    }
+}
+
+#[derive(new)]
+struct ForwardCheckpointOps<B: Backend, const D1: usize, const D2: usize> {
+    input: ForwardNodeRef<B::TensorPrimitive<D1>>,
+    parents: Vec<ForwardRecordedOpsParentRef>,
+    function: CheckpointFunction<ADBackendDecorator<B>, D1, D2>,
+    rng_state: RngState,
+}
+
+#[derive(new)]
+struct BackwardCheckpointOps<B: Backend, const D1: usize, const D2: usize> {
+    input: BackwardNodeRef<B::TensorPrimitive<D1>>,
+    parents: Vec<RecordedOpsParentRef>,
+    segment_inputs: Vec<ForwardRecordedOpsParentRef>,
+    function: CheckpointFunction<ADBackendDecorator<B>, D1, D2>,
+    rng_state: RngState,
+}
+
+impl<B: Backend, const D1: usize, const D2: usize> std::fmt::Debug
+    for ForwardCheckpointOps<B, D1, D2>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ForwardCheckpointOps")
+            .field("input", &self.input)
+            .field("parents", &self.parents)
+            .finish()
+    }
+}
+
+impl<B: Backend, const D1: usize, const D2: usize> std::fmt::Debug
+    for BackwardCheckpointOps<B, D1, D2>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackwardCheckpointOps")
+            .field("input", &self.input)
+            .field("parents", &self.parents)
+            .finish()
+    }
+}
+
+impl<B: Backend, const D1: usize, const D2: usize> ForwardRecordedOps<B::TensorPrimitive<D2>>
+    for ForwardCheckpointOps<B, D1, D2>
+{
+    fn to_backward(
+        &self,
+        graph: &mut Forward2BackwardGraphConverter,
+    ) -> BackwardRecordedOpsBoxed<B::TensorPrimitive<D2>> {
+        let input = graph.from(&self.input);
+        let parents = self
+            .parents
+            .iter()
+            .map(|parent| parent.to_backward(graph))
+            .collect();
+
+        Box::new(BackwardCheckpointOps::<B, D1, D2>::new(
+            input,
+            parents,
+            self.parents.clone(),
+            self.function.clone(),
+            self.rng_state.clone(),
+        ))
+    }
+
+    fn forward_parents(&self) -> Vec<ForwardRecordedOpsParentRef> {
+        let mut parents = self.parents.clone();
+        parents.push(self.input.clone());
+        parents
+    }
+}
+
+impl<B: Backend, const D1: usize, const D2: usize> BackwardRecordedOps<B::TensorPrimitive<D2>>
+    for BackwardCheckpointOps<B, D1, D2>
+{
+    fn backward_step(&self, state: &BackwardNodeState<B::TensorPrimitive<D2>>) {
+        // Recompute the segment with the random state of the forward pass, so that random
+        // operations like dropout produce the same output.
+        let device = B::device(&self.input.state.value);
+        let rng_state = B::get_rng_state(device);
+        B::set_rng_state(device, self.rng_state.clone());
+        let input = ADTensor::<D1, B>::from_tensor(self.input.state.value());
+        let output = with_grad_mode(true, || (self.function)(input.clone()));
+        B::set_rng_state(device, rng_state);
+
+        // The tensors used by the segment are leaves of the recomputed graph, their gradients
+        // being propagated to their parents by the backward pass of the checkpoint.
+        let grads = output.backward_with_grad(state.grad(), &self.segment_inputs);
+
+        if let Some(grad) = grads.wrt(&input) {
+            self.input.state.update_grad(grad.clone());
+        }
+        for parent in self.parents.iter() {
+            parent.accumulate_grad(&grads);
+        }
+    }
+
+    fn backward_parents(&self) -> Vec<RecordedOpsParentRef> {
+        let mut parents = self.parents.clone();
+        parents.push(self.input.clone());
+        parents
+    }
+
+    fn name(&self) -> &'static str {
+        "checkpoint"
+    }
 }
 
 impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
@@ -40,7 +178,65 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             2,
         >,
     ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<2> {
+        // Only differentiable with respect to the output gradient, which is used to compute
+        // higher order gradients.
+        let input = output.node.clone();
         let tensor = B::embedding_backward(weights.tensor_ref(), output.tensor_ref(), indexes);
-        ADTensor::from_tensor(tensor)
+        let ops = EmbeddingBackwardBackward::<B>::new(indexes.clone());
+
+        unary_ops_wrapper(input, tensor, ops)
+    }
+
+    fn checkpoint<const D1: usize, const D2: usize>(
+        function: CheckpointFunction<ADBackendDecorator<B>, D1, D2>,
+        input: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D1>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D2> {
+        if !is_grad_enabled() {
+            return function(input.clone());
+        }
+
+        let device = B::device(input.tensor_ref());
+        let rng_state = B::get_rng_state(device);
+        let sequence = ForwardNode::<B::TensorPrimitive<D1>>::next_sequence();
+        let segment_input = ADTensor::<D1, B>::from_tensor(input.tensor());
+        let output = function(segment_input);
+
+        // The tensors used by the segment without being created by it, like module parameters,
+        // are the other inputs of the segment, receiving their gradients from the recomputed
+        // segment during the backward pass. The graph is only traversed up to them.
+        let mut parents = Vec::new();
+        ForwardBreadthFirstSearch::new(output.node.clone()).traverse(|node| {
+            if node.sequence() >= sequence {
+                return true;
+            }
+            if node.requires_grad() {
+                parents.push(node);
+            }
+            false
+        });
+
+        if parents.is_empty() && !input.node.requires_grad {
+            return ADTensor::from_constant(output.tensor());
+        }
+
+        // Only the output value is kept, the intermediate nodes of the segment are dropped.
+        let shape = output.shape;
+        let state = ADTensor::<D2, B>::node_state(output.tensor());
+        let order = parents
+            .iter()
+            .map(|parent| parent.order())
+            .fold(input.node.order, usize::max);
+        let ops = ForwardCheckpointOps::<B, D1, D2>::new(
+            input.node.clone(),
+            parents,
+            function,
+            rng_state,
+        );
+        let node = ForwardNode::new(order + 1, state, Box::new(ops));
+
+        ADTensor {
+            node: Arc::new(node),
+            shape,
+        }
     }
 }
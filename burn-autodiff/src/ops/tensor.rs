@@ -4,9 +4,12 @@ use crate::graph::node::{BackwardNode, BackwardNodeRef, BackwardNodeState, Forwa
 use crate::graph::ops::*;
 use crate::ops::unary_ops_wrapper_explicit;
 use crate::tensor::ADTensor;
-use crate::ADBackendDecorator;
+use crate::{is_grad_enabled, ADBackendDecorator};
 use burn_tensor::backend::Backend;
-use burn_tensor::{ops::*, Data, Distribution, ElementConversion, Shape, Tensor};
+use burn_tensor::{
+    ops::*, Data, Distribution, ElementConversion, Generator, QuantizationScheme, QuantizedData,
+    Shape, Tensor,
+};
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -39,6 +42,15 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         ADTensor::from_tensor(B::random(shape, distribution, device))
     }
 
+    fn random_with<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        generator: &mut Generator,
+        device: B::Device,
+    ) -> ADTensor<D, B> {
+        ADTensor::from_tensor(B::random_with(shape, distribution, generator, device))
+    }
+
     fn zeros<const D: usize>(shape: Shape<D>, device: B::Device) -> ADTensor<D, B> {
         ADTensor::from_tensor(B::zeros(shape, device))
     }
@@ -90,6 +102,12 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::bool_reshape(tensor, shape)
     }
 
+    fn bool_device<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::Device {
+        B::bool_device(tensor)
+    }
+
     fn bool_to_device<const D: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
         device: <ADBackendDecorator<B> as Backend>::Device,
@@ -121,6 +139,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 B::to_device(&state.output.grad(), self.device)
             }
+
+            fn name(&self) -> &'static str {
+                "to_device"
+            }
         }
 
         let device_old = B::device(tensor.tensor_ref());
@@ -172,6 +194,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 state.output.grad()
             }
+
+            fn name(&self) -> &'static str {
+                "add"
+            }
         }
 
         let output = B::add(lhs.tensor_ref(), rhs.tensor_ref());
@@ -198,6 +224,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 state.output.grad()
             }
+
+            fn name(&self) -> &'static str {
+                "add_scalar"
+            }
         }
 
         let output = B::add_scalar(lhs.tensor_ref(), rhs);
@@ -240,6 +270,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 B::neg(&state.output.grad())
             }
+
+            fn name(&self) -> &'static str {
+                "sub"
+            }
         }
 
         let output = B::sub(lhs.tensor_ref(), rhs.tensor_ref());
@@ -266,6 +300,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 state.output.grad()
             }
+
+            fn name(&self) -> &'static str {
+                "sub_scalar"
+            }
         }
 
         let output = B::sub_scalar(lhs.tensor_ref(), rhs);
@@ -308,6 +346,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 B::mul(&state.output.grad(), &state.left.value())
             }
+
+            fn name(&self) -> &'static str {
+                "mul"
+            }
         }
 
         let output = B::mul(lhs.tensor_ref(), rhs.tensor_ref());
@@ -334,6 +376,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 B::mul_scalar(&state.output.grad(), &self.elem)
             }
+
+            fn name(&self) -> &'static str {
+                "mul_scalar"
+            }
         }
 
         let output = B::mul_scalar(lhs.tensor_ref(), rhs);
@@ -383,6 +429,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
                 B::mul(&state.output.grad(), &value)
             }
+
+            fn name(&self) -> &'static str {
+                "div"
+            }
         }
 
         let output = B::div(lhs.tensor_ref(), rhs.tensor_ref());
@@ -412,6 +462,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
                 B::mul(&state.output.grad(), &tmp)
             }
+
+            fn name(&self) -> &'static str {
+                "div_scalar"
+            }
         }
 
         let output = B::div_scalar(lhs.tensor_ref(), rhs);
@@ -458,6 +512,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 let lhs = B::transpose(&state.left.value());
                 B::matmul(&lhs, &out_grad)
             }
+
+            fn name(&self) -> &'static str {
+                "matmul"
+            }
         }
 
         let output = B::matmul(lhs.tensor_ref(), rhs.tensor_ref());
@@ -466,6 +524,202 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         binary_ops_wrapper(lhs.node.clone(), rhs.node.clone(), output, ops)
     }
 
+    fn spmm(
+        indices: &<<ADBackendDecorator<B> as Backend>::IntegerBackend as Backend>::TensorPrimitive<
+            2,
+        >,
+        values: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<1>,
+        shape: Shape<2>,
+        dense: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<2>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<2> {
+        #[derive(new, Debug)]
+        struct SpmmBackward<B: Backend> {
+            indices: <B::IntegerBackend as Backend>::TensorPrimitive<2>,
+            values: B::TensorPrimitive<1>,
+            shape: Shape<2>,
+        }
+
+        impl<B: Backend> UnaryOps<B::TensorPrimitive<2>, B::TensorPrimitive<2>> for SpmmBackward<B> {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<2>, B::TensorPrimitive<2>>,
+            ) -> B::TensorPrimitive<2> {
+                let [num_rows, num_cols] = self.shape.dims;
+                let [nnz, _] = B::IntegerBackend::shape(&self.indices).dims;
+
+                // The transposed sparse tensor has its row and column indices swapped.
+                let rows = B::IntegerBackend::index(&self.indices, [0..nnz, 0..1]);
+                let cols = B::IntegerBackend::index(&self.indices, [0..nnz, 1..2]);
+                let indices = B::IntegerBackend::cat(&[cols, rows], 1);
+
+                B::spmm(
+                    &indices,
+                    &self.values,
+                    Shape::new([num_cols, num_rows]),
+                    &state.output.grad(),
+                )
+            }
+
+            fn name(&self) -> &'static str {
+                "spmm"
+            }
+        }
+
+        let output = B::spmm(indices, values.tensor_ref(), shape, dense.tensor_ref());
+        let ops = SpmmBackward::<B>::new(indices.clone(), values.tensor_ref().clone(), shape);
+
+        unary_ops_wrapper(dense.node.clone(), output, ops)
+    }
+
+    fn quantize<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        scheme: QuantizationScheme,
+    ) -> QuantizedData<D> {
+        B::quantize(tensor.tensor_ref(), scheme)
+    }
+
+    fn dequantize<const D: usize>(
+        data: &QuantizedData<D>,
+        device: B::Device,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        ADTensor::from_tensor(B::dequantize(data, device))
+    }
+
+    fn quantized_matmul(
+        lhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<2>,
+        rhs: &QuantizedData<2>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<2> {
+        #[derive(new, Debug)]
+        struct QuantizedMatmulBackward<B: Backend> {
+            _b: B,
+            rhs: QuantizedData<2>,
+        }
+
+        impl<B: Backend> UnaryOps<B::TensorPrimitive<2>, B::TensorPrimitive<2>>
+            for QuantizedMatmulBackward<B>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<2>, B::TensorPrimitive<2>>,
+            ) -> B::TensorPrimitive<2> {
+                let out_grad = state.output.grad();
+                let rhs = B::dequantize(&self.rhs, B::device(&out_grad));
+                B::matmul(&out_grad, &B::transpose(&rhs))
+            }
+
+            fn name(&self) -> &'static str {
+                "quantized_matmul"
+            }
+        }
+
+        let output = B::quantized_matmul(lhs.tensor_ref(), rhs);
+        let ops = QuantizedMatmulBackward::<B>::new(B::default(), rhs.clone());
+
+        unary_ops_wrapper(lhs.node.clone(), output, ops)
+    }
+
+    fn fft<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        inverse: bool,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(new, Debug)]
+        struct FftBackward<B: Backend, const D: usize> {
+            _b: B,
+            inverse: bool,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for FftBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                // The transform matrix is symmetric, so its adjoint is the transform in the
+                // other direction with the normalization swapped.
+                let grad = state.output.grad();
+                let size = (B::shape(&grad).dims[D - 1] / 2) as f64;
+                let scale = match self.inverse {
+                    true => 1.0 / size,
+                    false => size,
+                };
+
+                B::mul_scalar(&B::fft(&grad, !self.inverse), &scale.to_elem())
+            }
+
+            fn name(&self) -> &'static str {
+                "fft"
+            }
+        }
+
+        let output = B::fft(tensor.tensor_ref(), inverse);
+        let ops = FftBackward::<B, D>::new(B::default(), inverse);
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
+    fn rfft<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(new, Debug)]
+        struct RfftBackward<B: Backend, const D: usize> {
+            _b: B,
+            size: usize,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for RfftBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                let grad = state.output.grad();
+                let shape = *B::shape(&grad);
+                let num_freqs = shape.dims[D - 1] / 2;
+                let device = B::device(&grad);
+
+                // The missing frequencies are padded with zeros to apply the adjoint of the full
+                // transform, the gradient being its real part.
+                let ranges = |offset: usize| {
+                    let mut ranges = shape.dims.map(|dim| 0..dim);
+                    ranges[D - 1] = offset..offset + num_freqs;
+                    ranges
+                };
+                let mut parts = vec![
+                    B::index(&grad, ranges(0)),
+                    B::index(&grad, ranges(num_freqs)),
+                ];
+                if self.size > num_freqs {
+                    let mut padding = shape;
+                    padding.dims[D - 1] = self.size - num_freqs;
+                    let padding = B::zeros(padding, device);
+
+                    parts.insert(1, padding.clone());
+                    parts.push(padding);
+                }
+
+                let packed = B::cat(&parts, D - 1);
+                let output = B::fft(&packed, true);
+                let output = B::mul_scalar(&output, &(self.size as f64).to_elem());
+
+                let mut ranges = shape.dims.map(|dim| 0..dim);
+                ranges[D - 1] = 0..self.size;
+                B::index(&output, ranges)
+            }
+
+            fn name(&self) -> &'static str {
+                "rfft"
+            }
+        }
+
+        let size = B::shape(tensor.tensor_ref()).dims[D - 1];
+        let output = B::rfft(tensor.tensor_ref());
+        let ops = RfftBackward::<B, D>::new(B::default(), size);
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
     fn neg<const D: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
     ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
@@ -483,6 +737,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 B::neg(&state.output.grad())
             }
+
+            fn name(&self) -> &'static str {
+                "neg"
+            }
         }
 
         let output = B::neg(tensor.tensor_ref());
@@ -512,6 +770,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 B::swap_dims(&state.output.grad(), self.dim2, self.dim1)
             }
+
+            fn name(&self) -> &'static str {
+                "swap_dims"
+            }
         }
 
         let output = B::swap_dims(tensor.tensor_ref(), dim1, dim2);
@@ -556,6 +818,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
                 B::reshape(&grad, self.shape)
             }
+
+            fn name(&self) -> &'static str {
+                "reshape"
+            }
         }
 
         let shape_old = B::shape(tensor.tensor_ref());
@@ -588,6 +854,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                     &state.output.grad(),
                 )
             }
+
+            fn name(&self) -> &'static str {
+                "index"
+            }
         }
 
         let output = B::index(tensor.tensor_ref(), indexes.clone());
@@ -636,6 +906,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D1> {
                 B::index(&state.output.grad(), self.indexes.clone())
             }
+
+            fn name(&self) -> &'static str {
+                "index_assign"
+            }
         }
 
         let output = B::index_assign(tensor.tensor_ref(), indexes.clone(), value.tensor_ref());
@@ -667,6 +941,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                     B::Elem::zeros(&B::Elem::default()),
                 )
             }
+
+            fn name(&self) -> &'static str {
+                "mask_fill"
+            }
         }
 
         let output = B::mask_fill(tensor.tensor_ref(), mask, value);
@@ -748,7 +1026,26 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     fn detach<const D: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
     ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
-        ADTensor::from_tensor(B::detach(tensor.tensor_ref()))
+        // A detached tensor is a constant with its gradients enabled, which makes it a new leaf.
+        let tensor = ADTensor::from_constant(B::detach(tensor.tensor_ref()));
+        Self::require_grad(&tensor, true)
+    }
+
+    fn require_grad<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        require_grad: bool,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        match (tensor.node.requires_grad, require_grad) {
+            (true, false) => ADTensor::from_constant(tensor.tensor()),
+            (false, true) => ADTensor::from_tensor(tensor.tensor()),
+            _ => tensor.clone(),
+        }
+    }
+
+    fn is_require_grad<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> bool {
+        tensor.node.requires_grad
     }
 
     fn mean<const D: usize>(
@@ -776,6 +1073,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
                 ones.mul(&grad.unsqueeze()).into_primitive()
             }
+
+            fn name(&self) -> &'static str {
+                "mean"
+            }
         }
 
         let shape = B::shape(tensor.tensor_ref());
@@ -809,6 +1110,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
                 ones.mul(&grad.unsqueeze()).into_primitive()
             }
+
+            fn name(&self) -> &'static str {
+                "sum"
+            }
         }
 
         let shape = B::shape(tensor.tensor_ref());
@@ -844,6 +1149,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
                 B::mul(&ones, &grad)
             }
+
+            fn name(&self) -> &'static str {
+                "mean_dim"
+            }
         }
 
         let shape = B::shape(tensor.tensor_ref());
@@ -876,6 +1185,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
                 B::mul(&ones, &grad)
             }
+
+            fn name(&self) -> &'static str {
+                "sum_dim"
+            }
         }
 
         let shape = B::shape(tensor.tensor_ref());
@@ -910,6 +1223,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 let grad = state.output.grad();
                 B::from_full_precision(&grad)
             }
+
+            fn name(&self) -> &'static str {
+                "to_full_precision"
+            }
         }
 
         let output = B::to_full_precision(tensor.tensor_ref());
@@ -946,6 +1263,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 let grad = state.output.grad();
                 B::to_full_precision(&grad)
             }
+
+            fn name(&self) -> &'static str {
+                "from_full_precision"
+            }
         }
 
         let output = B::from_full_precision(tensor.tensor_ref());
@@ -989,6 +1310,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             ) -> B::TensorPrimitive<D> {
                 B::mul(&state.output.grad(), &state.output.value())
             }
+
+            fn name(&self) -> &'static str {
+                "exp"
+            }
         }
 
         let output = B::exp(tensor.tensor_ref());
@@ -1016,6 +1341,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 let value = B::div(&value.ones(), &value);
                 B::mul(&state.output.grad(), &value)
             }
+
+            fn name(&self) -> &'static str {
+                "log"
+            }
         }
 
         let output = B::log(tensor.tensor_ref());
@@ -1047,6 +1376,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 );
                 B::mul(&state.output.grad(), &value)
             }
+
+            fn name(&self) -> &'static str {
+                "powf"
+            }
         }
 
         let output = B::powf(tensor.tensor_ref(), value);
@@ -1078,6 +1411,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
                 B::mul(&state.output.grad(), &value)
             }
+
+            fn name(&self) -> &'static str {
+                "erf"
+            }
         }
 
         let output = B::erf(tensor.tensor_ref());
@@ -1119,6 +1456,16 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                     self.dim,
                 ))
             }
+
+            fn forward_parents(&self) -> Vec<ForwardRecordedOpsParentRef> {
+                self.nodes
+                    .iter()
+                    .map(|node| {
+                        let parent: ForwardRecordedOpsParentRef = node.clone();
+                        parent
+                    })
+                    .collect()
+            }
         }
 
         impl<const D: usize, B: Backend> BackwardRecordedOps<B::TensorPrimitive<D>>
@@ -1129,10 +1476,13 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 let indexes: Vec<_> = B::shape(&grad).dims.iter().map(|v| 0..*v).collect();
                 let indexes: [std::ops::Range<usize>; D] = indexes.try_into().unwrap();
 
-                self.nodes.iter().enumerate().for_each(|(i, node)| {
+                let mut start = 0;
+                self.nodes.iter().for_each(|node| {
+                    let end = start + B::shape(&node.state.value).dims[self.dim];
                     let mut indexes = indexes.clone();
-                    indexes[self.dim] = i..i + 1;
+                    indexes[self.dim] = start..end;
                     node.state.update_grad(B::index(&grad, indexes));
+                    start = end;
                 });
             }
 
@@ -1145,6 +1495,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                     })
                     .collect()
             }
+
+            fn name(&self) -> &'static str {
+                "cat"
+            }
         }
 
         let nodes: Vec<_> = tensors.iter().map(|t| t.node.clone()).collect();
@@ -1155,8 +1509,12 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
         let out = B::cat(&tensors_inner, dim);
 
+        if !nodes.iter().any(|node| node.requires_grad) || !is_grad_enabled() {
+            return ADTensor::from_constant(out);
+        }
+
         let shape = *B::shape(&out);
-        let state = crate::graph::node::ForwardNodeState::new(out);
+        let state = ADTensor::<D, B>::node_state(out);
 
         let ops = ForwardCatOps::<D, B>::new(nodes, dim);
         let ops = Box::new(ops);
@@ -1186,6 +1544,10 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 let mask = B::lower_equal_scalar(&state.output.value(), &zero);
                 B::mask_fill(&state.output.grad(), &mask, zero)
             }
+
+            fn name(&self) -> &'static str {
+                "relu"
+            }
         }
 
         let output = B::relu(tensor.tensor_ref());
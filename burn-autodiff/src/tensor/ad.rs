@@ -2,6 +2,7 @@ use crate::graph::grad::{AsNode, Grads};
 use crate::graph::{
     converter::Forward2BackwardGraphConverter,
     node::{BackwardNode, ForwardNode},
+    ops::ForwardRecordedOpsParentRef,
 };
 use crate::tensor::ADTensor;
 use burn_tensor::backend::Backend;
@@ -14,6 +15,23 @@ impl<B: Backend, const D: usize> ADTensor<D, B> {
 
         node.backward()
     }
+
+    /// Computes the gradients of the graph of the tensor, starting from the given gradient and
+    /// stopping at the leaves, which receive gradients without propagating them to their parents.
+    pub fn backward_with_grad(
+        &self,
+        grad: B::TensorPrimitive<D>,
+        leaves: &[ForwardRecordedOpsParentRef],
+    ) -> Grads {
+        let mut converter = Forward2BackwardGraphConverter::new();
+        for leaf in leaves {
+            leaf.to_backward_leaf(&mut converter);
+        }
+        let mut node = BackwardNode::from_node(&self.node, &mut converter);
+        std::mem::drop(converter);
+
+        node.backward_with_grad(grad)
+    }
 }
 
 impl<B: Backend, const D: usize> AsNode<B::TensorPrimitive<D>> for ADTensor<D, B> {
@@ -1,8 +1,8 @@
 use crate::graph::{
-    node::{ForwardNode, ForwardNodeRef, ForwardNodeState},
+    node::{ForwardNode, ForwardNodeRef, ForwardNodeState, NodeMetadata},
     ops::InitRecordedOps,
 };
-use burn_tensor::{backend::Backend, Shape};
+use burn_tensor::{backend::Backend, ElementConversion, Shape};
 
 #[derive(Debug, Clone)]
 pub struct ADTensor<const D: usize, B: Backend> {
@@ -13,7 +13,7 @@ pub struct ADTensor<const D: usize, B: Backend> {
 impl<B: Backend, const D: usize> ADTensor<D, B> {
     pub fn from_tensor(tensor: B::TensorPrimitive<D>) -> Self {
         let shape = *B::shape(&tensor);
-        let state = ForwardNodeState::new(tensor);
+        let state = Self::node_state(tensor);
         let ops = InitRecordedOps::new();
         let ops = Box::new(ops);
         let node = ForwardNode::from_root(state, ops);
@@ -21,9 +21,35 @@ impl<B: Backend, const D: usize> ADTensor<D, B> {
 
         Self { node, shape }
     }
+
+    pub fn from_constant(tensor: B::TensorPrimitive<D>) -> Self {
+        let shape = *B::shape(&tensor);
+        let state = Self::node_state(tensor);
+        let ops = InitRecordedOps::new();
+        let ops = Box::new(ops);
+        let node = ForwardNode::from_constant(state, ops);
+        let node = std::sync::Arc::new(node);
+
+        Self { node, shape }
+    }
 }
 
 impl<B: Backend, const D: usize> ADTensor<D, B> {
+    pub fn node_state(tensor: B::TensorPrimitive<D>) -> ForwardNodeState<B::TensorPrimitive<D>> {
+        let metadata = NodeMetadata::new(Self::dims, Self::norm);
+
+        ForwardNodeState::new(tensor, metadata)
+    }
+
+    fn dims(tensor: &B::TensorPrimitive<D>) -> Vec<usize> {
+        B::shape(tensor).dims.to_vec()
+    }
+
+    fn norm(tensor: &B::TensorPrimitive<D>) -> f64 {
+        let sum = B::sum(&B::powf(tensor, 2.0));
+        B::to_data(&sum).value[0].to_elem::<f64>().sqrt()
+    }
+
     pub fn tensor(&self) -> B::TensorPrimitive<D> {
         self.node.state.value()
     }
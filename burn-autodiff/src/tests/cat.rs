@@ -66,4 +66,24 @@ mod tests {
             .to_data()
             .assert_approx_eq(&grad_2_list_2.to_data(), 3);
     }
+
+    #[test]
+    fn should_diff_cat_with_different_sizes() {
+        let tensor_1 = TestADTensor::from_floats([[1.0], [2.0]]);
+        let tensor_2 = TestADTensor::from_floats([[3.0, 4.0], [5.0, 6.0]]);
+        let weights = TestADTensor::from_floats([[1.0], [2.0], [3.0]]);
+
+        let tensor_3 = TestADTensor::cat(vec![tensor_1.clone(), tensor_2.clone()], 1);
+        let grads = tensor_3.matmul(&weights).backward();
+
+        assert_eq!(tensor_3.dims(), [2, 3]);
+        assert_eq!(
+            tensor_1.grad(&grads).unwrap().to_data(),
+            Data::from([[1.0], [1.0]])
+        );
+        assert_eq!(
+            tensor_2.grad(&grads).unwrap().to_data(),
+            Data::from([[2.0, 3.0], [2.0, 3.0]])
+        );
+    }
 }
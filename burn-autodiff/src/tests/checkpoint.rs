@@ -0,0 +1,128 @@
+#[burn_tensor_testgen::testgen(ad_checkpoint)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::checkpoint, Data, Distribution};
+
+    #[test]
+    fn should_diff_checkpoint_as_segment() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [-2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, -7.0], [2.0, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1);
+        let tensor_2 = TestADTensor::from_data(data_2);
+        let weights = tensor_2.clone();
+        let segment = move |x: TestADTensor<2>| x.matmul(&weights).erf().mul(&x);
+
+        let expected = segment(tensor_1.mul_scalar(0.1)).sum();
+        let grads_expected = expected.backward();
+
+        let tensor_3 = checkpoint(segment, &tensor_1.mul_scalar(0.1));
+        let tensor_4 = tensor_3.sum();
+        let grads = tensor_4.backward();
+
+        tensor_4.to_data().assert_approx_eq(&expected.to_data(), 3);
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&tensor_1.grad(&grads_expected).unwrap().to_data(), 3);
+        tensor_2
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&tensor_2.grad(&grads_expected).unwrap().to_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_chained_checkpoints() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [-2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, -7.0], [2.0, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1);
+        let tensor_2 = TestADTensor::from_data(data_2);
+        let weights = tensor_2.clone();
+        let segment_1 = move |x: TestADTensor<2>| x.matmul(&weights).exp();
+        let weights = tensor_2.clone();
+        let segment_2 = move |x: TestADTensor<2>| x.mul(&weights).sum_dim(1);
+
+        let expected = segment_2(segment_1(tensor_1.clone()).log()).sum();
+        let grads_expected = expected.backward();
+
+        let tensor_3 = checkpoint(segment_1, &tensor_1);
+        let tensor_4 = checkpoint(segment_2, &tensor_3.log()).sum();
+        let grads = tensor_4.backward();
+
+        tensor_4.to_data().assert_approx_eq(&expected.to_data(), 3);
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&tensor_1.grad(&grads_expected).unwrap().to_data(), 3);
+        tensor_2
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&tensor_2.grad(&grads_expected).unwrap().to_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_checkpoint_capturing_intermediate_tensors() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [-2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, -7.0], [2.0, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1);
+        let tensor_2 = TestADTensor::from_data(data_2);
+        let weights = tensor_2.mul_scalar(0.1).exp();
+        let weights_segment = weights.clone();
+        let segment_1 = move |x: TestADTensor<2>| x.matmul(&weights_segment).erf();
+
+        let intermediate = segment_1(tensor_1.clone());
+        let intermediate_segment = intermediate.clone();
+        let expected = intermediate
+            .mul(&intermediate_segment.mul(&intermediate))
+            .add(&weights)
+            .sum();
+        let grads_expected = expected.backward();
+
+        // The second segment captures the output of the first one, which is also used outside of
+        // it, like the weights captured by the first segment.
+        let tensor_3 = checkpoint(segment_1, &tensor_1);
+        let tensor_3_segment = tensor_3.clone();
+        let segment_2 = move |x: TestADTensor<2>| tensor_3_segment.mul(&x);
+        let tensor_4 = tensor_3
+            .mul(&checkpoint(segment_2, &tensor_3))
+            .add(&weights)
+            .sum();
+        let grads = tensor_4.backward();
+
+        tensor_4.to_data().assert_approx_eq(&expected.to_data(), 3);
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&tensor_1.grad(&grads_expected).unwrap().to_data(), 3);
+        tensor_2
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&tensor_2.grad(&grads_expected).unwrap().to_data(), 3);
+    }
+
+    #[test]
+    fn should_recompute_checkpoint_with_same_random_state() {
+        let tensor_1 = TestADTensor::<2>::ones([4, 8]);
+
+        let tensor_2 = checkpoint(
+            |x: TestADTensor<2>| x.mul(&x.random_like(Distribution::Standard)),
+            &tensor_1,
+        );
+        let grads = tensor_2.backward();
+
+        // The gradient of x * r is r, which is also the output since x is filled with ones.
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&tensor_2.to_data(), 3);
+    }
+}
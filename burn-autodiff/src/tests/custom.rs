@@ -0,0 +1,145 @@
+#[burn_tensor_testgen::testgen(ad_custom)]
+mod tests {
+    use super::*;
+    use burn_autodiff::{custom_binary_op, custom_op, graph_to_dot, CustomBinaryOp, CustomOp};
+    use burn_tensor::{backend::Backend, Data, Tensor};
+
+    #[derive(Debug)]
+    struct StraightThroughBinarize;
+
+    impl<B: Backend> CustomOp<B, 2, 2> for StraightThroughBinarize {
+        type State = ();
+
+        fn forward(&self, input: Tensor<B, 2>) -> (Tensor<B, 2>, ()) {
+            let mask = input.greater_equal_scalar(0.0);
+            (input.zeros_like().mask_fill(&mask, 1.0), ())
+        }
+
+        fn backward(&self, grad: Tensor<B, 2>, _state: &()) -> Tensor<B, 2> {
+            grad
+        }
+    }
+
+    #[derive(Debug)]
+    struct GradientReversal {
+        lambda: f32,
+    }
+
+    impl<B: Backend> CustomOp<B, 1, 1> for GradientReversal {
+        type State = ();
+
+        fn forward(&self, input: Tensor<B, 1>) -> (Tensor<B, 1>, ()) {
+            (input, ())
+        }
+
+        fn backward(&self, grad: Tensor<B, 1>, _state: &()) -> Tensor<B, 1> {
+            grad.mul_scalar(-self.lambda)
+        }
+
+        fn name(&self) -> &'static str {
+            "gradient_reversal"
+        }
+    }
+
+    #[derive(Debug)]
+    struct FusedMulAdd;
+
+    impl<B: Backend> CustomBinaryOp<B, 2, 2, 2> for FusedMulAdd {
+        type State = (Tensor<B, 2>, Tensor<B, 2>);
+
+        fn forward(&self, lhs: Tensor<B, 2>, rhs: Tensor<B, 2>) -> (Tensor<B, 2>, Self::State) {
+            let output = lhs.mul(&rhs).add(&lhs);
+            (output, (lhs, rhs))
+        }
+
+        fn backward(
+            &self,
+            grad: Tensor<B, 2>,
+            (lhs, rhs): &Self::State,
+        ) -> (Tensor<B, 2>, Tensor<B, 2>) {
+            (grad.mul(&rhs.add_scalar(1.0)), grad.mul(lhs))
+        }
+
+        fn name(&self) -> &'static str {
+            "fused_mul_add"
+        }
+    }
+
+    #[test]
+    fn should_diff_straight_through_estimator() {
+        let data = Data::<f32, 2>::from([[0.5, -1.0], [2.0, -3.0]]);
+        let tensor_1 = TestADTensor::from_data(data);
+
+        let tensor_2 = custom_op(StraightThroughBinarize, &tensor_1).mul_scalar(3.0);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(tensor_2.to_data(), Data::from([[3.0, 0.0], [3.0, 0.0]]));
+        assert_eq!(grad_1.to_data(), Data::from([[3.0, 3.0], [3.0, 3.0]]));
+    }
+
+    #[test]
+    fn should_diff_gradient_reversal() {
+        let data = Data::<f32, 1>::from([1.0, 2.0, -3.0]);
+        let tensor_1 = TestADTensor::from_data(data);
+
+        let tensor_2 = custom_op(GradientReversal { lambda: 0.5 }, &tensor_1).powf(2.0);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        tensor_2
+            .to_data()
+            .assert_approx_eq(&Data::from([1.0, 4.0, 9.0]), 3);
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([-1.0, -2.0, 3.0]), 3);
+    }
+
+    #[test]
+    fn should_diff_custom_binary_op_with_saved_state() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[-1.0, 0.5], [2.0, 0.0]]);
+        let tensor_1 = TestADTensor::from_data(data_1);
+        let tensor_2 = TestADTensor::from_data(data_2);
+
+        let tensor_3 = custom_binary_op(FusedMulAdd, &tensor_1, &tensor_2);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        tensor_3
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 3.0], [9.0, 4.0]]), 3);
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.5], [3.0, 1.0]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0], [3.0, 4.0]]), 3);
+    }
+
+    #[test]
+    fn should_name_custom_ops_in_the_graph() {
+        let data = Data::<f32, 1>::from([1.0, 2.0, -3.0]);
+        let tensor_1 = TestADTensor::from_data(data);
+
+        let tensor_2 = custom_op(GradientReversal { lambda: 0.5 }, &tensor_1);
+        let dot = graph_to_dot(&tensor_2, None);
+
+        assert!(dot.contains("gradient_reversal\\nid: "));
+    }
+
+    #[test]
+    fn should_name_custom_ops_custom_by_default() {
+        let data = Data::<f32, 2>::from([[1.0, -2.0]]);
+        let tensor_1 = TestADTensor::from_data(data);
+
+        let tensor_2 = custom_op(StraightThroughBinarize, &tensor_1);
+        let dot = graph_to_dot(&tensor_2, None);
+
+        assert!(dot.contains("custom\\nid: "));
+    }
+}
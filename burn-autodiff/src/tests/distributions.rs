@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(ad_distributions)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{Beta, Normal};
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_normal_rsample() {
+        let loc = TestADTensor::from_floats([1.0, 2.0]);
+        let scale = TestADTensor::from_floats([0.5, 2.0]);
+        let normal = Normal::new(loc.clone(), scale.clone());
+
+        let sample = normal.rsample();
+        let grads = sample.sum().backward();
+
+        let grad_loc = loc.grad(&grads).unwrap();
+        let grad_scale = scale.grad(&grads).unwrap();
+        let eps = sample.sub(&loc).div(&scale).inner();
+
+        grad_loc
+            .to_data()
+            .assert_approx_eq(&Data::from([1.0, 1.0]), 3);
+        grad_scale.to_data().assert_approx_eq(&eps.to_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_beta_log_prob() {
+        let alpha = TestADTensor::from_floats([2.0]);
+        let beta = TestADTensor::from_floats([3.0]);
+        let distribution = Beta::new(alpha.clone(), beta.clone());
+
+        let log_prob = distribution.log_prob(&TestADTensor::from_floats([0.5]));
+        let grads = log_prob.backward();
+
+        let grad_alpha = alpha.grad(&grads).unwrap();
+        let grad_beta = beta.grad(&grads).unwrap();
+
+        grad_alpha
+            .to_data()
+            .assert_approx_eq(&Data::from([0.390187]), 3);
+        grad_beta
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.109813]), 3);
+    }
+}
@@ -0,0 +1,69 @@
+#[burn_tensor_testgen::testgen(ad_dot)]
+mod tests {
+    use super::*;
+    use burn_autodiff::graph_to_dot;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_export_nodes_and_edges() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, 7.0], [2.0, 3.0]]);
+        let tensor_1 = TestADTensor::from_data(data_1);
+        let tensor_2 = TestADTensor::from_data(data_2);
+
+        let tensor_3 = tensor_1.matmul(&tensor_2).exp();
+        let dot = graph_to_dot(&tensor_3, None);
+
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains("matmul\\nid: "));
+        assert!(dot.contains("exp\\nid: "));
+        assert!(dot.contains("shape: [2, 2]\\norder: 2"));
+        assert_eq!(dot.matches("[label=").count(), 4);
+        assert_eq!(dot.matches(" -> ").count(), 3);
+        assert!(!dot.contains("grad norm"));
+    }
+
+    #[test]
+    fn should_highlight_leaves_and_dash_constants() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, 7.0], [2.0, 3.0]]);
+        let tensor_1 = TestADTensor::from_data(data_1);
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad(false);
+
+        let tensor_3 = tensor_1.mul(&tensor_2);
+        let dot = graph_to_dot(&tensor_3, None);
+
+        assert_eq!(dot.matches("leaf\\nid: ").count(), 1);
+        assert_eq!(dot.matches("fillcolor=lightblue").count(), 1);
+        assert_eq!(dot.matches("constant\\nid: ").count(), 1);
+        assert_eq!(dot.matches("style=dashed").count(), 1);
+    }
+
+    #[test]
+    fn should_name_ops_in_snake_case() {
+        let data = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+        let tensor_1 = TestADTensor::from_data(data);
+
+        let tensor_2 = Tensor::cat(vec![tensor_1.sub_scalar(1.0), tensor_1.clone()], 0);
+        let dot = graph_to_dot(&tensor_2, None);
+
+        assert!(dot.contains("sub_scalar\\nid: "));
+        assert!(dot.contains("cat\\nid: "));
+        assert!(dot.contains("shape: [4, 2]"));
+        assert_eq!(dot.matches(" -> ").count(), 3);
+    }
+
+    #[test]
+    fn should_annotate_gradient_norms() {
+        let data = Data::<f32, 1>::from([1.0, -1.0, 2.0, 0.5]);
+        let tensor_1 = TestADTensor::from_data(data);
+
+        let tensor_2 = tensor_1.mul_scalar(2.0).sum();
+        let grads = tensor_2.backward();
+        let dot = graph_to_dot(&tensor_2, Some(&grads));
+
+        assert!(dot.contains("grad norm: 4e0"));
+        assert!(dot.contains("grad norm: 2e0"));
+        assert!(dot.contains("grad norm: 1e0"));
+    }
+}
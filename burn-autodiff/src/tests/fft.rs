@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(ad_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ComplexTensor, Data};
+
+    #[test]
+    fn should_diff_rfft() {
+        let tensor = TestADTensor::from_floats([1.0, 2.0, 3.0, 4.0]);
+
+        let output = tensor.rfft();
+        let grads = output.re().sum().add(&output.im().sum()).backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_close(&Data::from([3.0, -1.0, 1.0, 1.0]), 0.0, 1e-5);
+    }
+
+    #[test]
+    fn should_diff_fft() {
+        let re = TestADTensor::from_floats([[1.0, 2.0, 3.0, 4.0]]);
+        let im = TestADTensor::from_floats([[0.0, -1.0, 0.5, 2.0]]);
+
+        let output = ComplexTensor::new(re.clone(), im.clone()).fft();
+        let grads = output.re().sum().backward();
+
+        re.grad(&grads).unwrap().to_data().assert_close(
+            &Data::from([[4.0, 0.0, 0.0, 0.0]]),
+            0.0,
+            1e-5,
+        );
+        im.grad(&grads).unwrap().to_data().assert_close(
+            &Data::from([[0.0, 0.0, 0.0, 0.0]]),
+            0.0,
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn should_diff_ifft() {
+        let re = TestADTensor::from_floats([1.0, 2.0, 3.0, 4.0]);
+        let im = TestADTensor::from_floats([0.0, -1.0, 0.5, 2.0]);
+
+        let output = ComplexTensor::new(re.clone(), im.clone()).ifft();
+        let grads = output.im().sum().backward();
+
+        re.grad(&grads).unwrap().to_data().assert_close(
+            &Data::from([0.0, 0.0, 0.0, 0.0]),
+            0.0,
+            1e-5,
+        );
+        im.grad(&grads).unwrap().to_data().assert_close(
+            &Data::from([1.0, 0.0, 0.0, 0.0]),
+            0.0,
+            1e-5,
+        );
+    }
+}
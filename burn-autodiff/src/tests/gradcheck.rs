@@ -0,0 +1,254 @@
+#[burn_tensor_testgen::testgen(ad_gradcheck)]
+mod tests {
+    use super::*;
+    use burn_autodiff::{custom_op, gradcheck, CustomOp, GradCheckFunction};
+    use burn_tensor::{
+        activation,
+        backend::Backend,
+        module::{checkpoint, embedding},
+        BoolTensor, ComplexTensor, Data, QuantizationScheme, QuantizedTensor, Shape, SparseTensor,
+        Tensor,
+    };
+
+    const EPS: f64 = 1e-2;
+    const TOLERANCE: f64 = 1e-2;
+
+    /// Checks a function sharing the same body on the autodiff backend and its inner backend.
+    macro_rules! check {
+        ($d1:literal => $d2:literal, $inputs:expr, |$x:ident| $body:expr) => {{
+            struct Function;
+
+            impl GradCheckFunction<TestBackend, $d1, $d2> for Function {
+                fn forward(&self, $x: &[TestADTensor<$d1>]) -> TestADTensor<$d2> {
+                    $body
+                }
+
+                fn forward_inner(
+                    &self,
+                    $x: &[Tensor<TestBackend, $d1>],
+                ) -> Tensor<TestBackend, $d2> {
+                    $body
+                }
+            }
+
+            assert_gradcheck(&Function, $inputs);
+        }};
+    }
+
+    fn assert_gradcheck<F, const D1: usize, const D2: usize>(
+        function: &F,
+        inputs: &[TestADTensor<D1>],
+    ) where
+        F: GradCheckFunction<TestBackend, D1, D2>,
+    {
+        if let Err(error) = gradcheck(function, inputs, EPS, TOLERANCE) {
+            panic!("{}", error);
+        }
+    }
+
+    fn inputs() -> [TestADTensor<2>; 2] {
+        [
+            TestADTensor::from_data(Data::from([[0.5, 1.2, 0.3], [2.0, 0.8, 1.5]])),
+            TestADTensor::from_data(Data::from([[-1.1, 0.4, 2.2], [0.7, -0.6, 1.3]])),
+        ]
+    }
+
+    #[test]
+    fn should_check_unary_ops() {
+        let inputs = [inputs()[0].clone()];
+
+        check!(2 => 2, &inputs, |x| x[0].exp());
+        check!(2 => 2, &inputs, |x| x[0].log());
+        check!(2 => 2, &inputs, |x| x[0].powf(3.0));
+        check!(2 => 2, &inputs, |x| x[0].powf(0.5));
+        check!(2 => 2, &inputs, |x| x[0].erf());
+        check!(2 => 2, &inputs, |x| x[0].neg());
+        check!(2 => 2, &inputs, |x| x[0].add_scalar(2.0));
+        check!(2 => 2, &inputs, |x| x[0].sub_scalar(2.0));
+        check!(2 => 2, &inputs, |x| x[0].mul_scalar(2.0));
+        check!(2 => 2, &inputs, |x| x[0].div_scalar(2.0));
+        check!(2 => 2, &inputs, |x| x[0].to_device(x[0].device()));
+        check!(2 => 2, &inputs, |x| Tensor::from_full_precision(
+            x[0].to_full_precision().exp()
+        ));
+    }
+
+    #[test]
+    fn should_check_shape_ops() {
+        let inputs = [inputs()[0].clone()];
+
+        check!(2 => 2, &inputs, |x| x[0].transpose());
+        check!(2 => 2, &inputs, |x| x[0].swap_dims(0, 1));
+        check!(2 => 1, &inputs, |x| x[0].reshape([6]));
+        check!(2 => 3, &inputs, |x| x[0].unsqueeze::<3>());
+        check!(2 => 2, &inputs, |x| x[0].index([0..2, 1..3]));
+        check!(2 => 2, &inputs, |x| x[0].sum_dim(0).repeat(0, 3));
+    }
+
+    #[test]
+    fn should_check_reduce_ops() {
+        let inputs = [inputs()[0].clone()];
+
+        check!(2 => 1, &inputs, |x| x[0].sum());
+        check!(2 => 1, &inputs, |x| x[0].mean());
+        check!(2 => 2, &inputs, |x| x[0].sum_dim(1));
+        check!(2 => 2, &inputs, |x| x[0].mean_dim(0));
+        check!(2 => 2, &inputs, |x| x[0].var(1));
+    }
+
+    #[test]
+    fn should_check_binary_ops() {
+        let inputs = inputs();
+
+        check!(2 => 2, &inputs, |x| x[0].add(&x[1]));
+        check!(2 => 2, &inputs, |x| x[0].sub(&x[1]));
+        check!(2 => 2, &inputs, |x| x[0].mul(&x[1]));
+        check!(2 => 2, &inputs, |x| x[1].div(&x[0]));
+        check!(2 => 2, &inputs, |x| x[0].matmul(&x[1].transpose()));
+        check!(2 => 2, &inputs, |x| Tensor::cat(
+            vec![x[0].clone(), x[1].clone()],
+            1
+        ));
+        check!(2 => 2, &inputs, |x| x[0]
+            .index_assign([0..2, 1..2], &x[1].index([0..2, 2..3])));
+    }
+
+    #[test]
+    fn should_check_mask_fill() {
+        let inputs = [inputs()[1].clone()];
+
+        check!(2 => 2, &inputs, |x| {
+            let mask = BoolTensor::from_data(Data::from([[true, false, true], [false, false, true]]));
+            x[0].mask_fill(&mask, 2.0)
+        });
+    }
+
+    #[test]
+    fn should_check_activations() {
+        let inputs = [inputs()[1].clone()];
+
+        check!(2 => 2, &inputs, |x| activation::relu(&x[0]));
+        check!(2 => 2, &inputs, |x| activation::gelu(&x[0]));
+        check!(2 => 2, &inputs, |x| activation::softmax(&x[0], 1));
+        check!(2 => 2, &inputs, |x| activation::log_softmax(&x[0], 1));
+    }
+
+    #[test]
+    fn should_check_fft() {
+        let inputs = [
+            TestADTensor::from_data(Data::from([[1.0, 2.0, 3.0, 4.0], [0.5, -1.0, 0.2, 1.5]])),
+            TestADTensor::from_data(Data::from([[0.0, -1.0, 0.5, 2.0], [1.2, 0.3, -0.7, 0.1]])),
+        ];
+
+        check!(2 => 2, &inputs, |x| {
+            let output = ComplexTensor::new(x[0].clone(), x[1].clone()).fft();
+            Tensor::cat(vec![output.re().clone(), output.im().clone()], 1)
+        });
+        check!(2 => 2, &inputs, |x| {
+            let output = ComplexTensor::new(x[0].clone(), x[1].clone()).ifft();
+            Tensor::cat(vec![output.re().clone(), output.im().clone()], 1)
+        });
+        check!(2 => 2, &inputs[..1], |x| {
+            let output = x[0].rfft();
+            Tensor::cat(vec![output.re().clone(), output.im().clone()], 1)
+        });
+    }
+
+    #[test]
+    fn should_check_spmm() {
+        let inputs = [inputs()[0].transpose()];
+
+        check!(2 => 2, &inputs, |x| {
+            let indices = Tensor::from_data(Data::from([[0, 0], [1, 2], [2, 1]]));
+            let values = Tensor::from_floats([2.0, -1.0, 3.0]);
+
+            SparseTensor::new(indices, values, Shape::new([3, 3])).spmm(&x[0])
+        });
+    }
+
+    #[test]
+    fn should_check_embedding() {
+        let inputs = [inputs()[0].transpose()];
+
+        check!(2 => 3, &inputs, |x| {
+            let indexes = Tensor::from_data(Data::from([[0, 2], [1, 1]]));
+            embedding(&x[0], &indexes)
+        });
+    }
+
+    #[test]
+    fn should_check_checkpoint() {
+        let inputs = [inputs()[0].clone()];
+
+        check!(2 => 2, &inputs, |x| checkpoint(
+            |x: Tensor<_, 2>| x.exp().mul(&x),
+            &x[0]
+        ));
+    }
+
+    #[derive(Debug)]
+    struct QuantizedMatmul {
+        rhs: QuantizedTensor<TestADBackend, 2>,
+    }
+
+    impl GradCheckFunction<TestBackend, 2, 2> for QuantizedMatmul {
+        fn forward(&self, inputs: &[TestADTensor<2>]) -> TestADTensor<2> {
+            inputs[0].matmul_quantized(&self.rhs)
+        }
+
+        // The input is also quantized by the forward pass, whose output is then piecewise
+        // constant, so the backward pass is checked against the matmul with the dequantized
+        // weights it computes the gradient of.
+        fn forward_inner(&self, inputs: &[Tensor<TestBackend, 2>]) -> Tensor<TestBackend, 2> {
+            inputs[0].matmul(&self.rhs.inner().dequantize())
+        }
+    }
+
+    #[test]
+    fn should_check_quantized_matmul() {
+        let [lhs, rhs] = inputs();
+        let function = QuantizedMatmul {
+            rhs: rhs.transpose().quantize(QuantizationScheme::PerChannel(1)),
+        };
+
+        assert_gradcheck(&function, &[lhs]);
+    }
+
+    #[derive(Debug)]
+    struct WrongBackward;
+
+    impl<B: Backend> CustomOp<B, 2, 2> for WrongBackward {
+        type State = ();
+
+        fn forward(&self, input: Tensor<B, 2>) -> (Tensor<B, 2>, ()) {
+            (input, ())
+        }
+
+        fn backward(&self, grad: Tensor<B, 2>, _state: &()) -> Tensor<B, 2> {
+            grad.mul_scalar(2.0)
+        }
+    }
+
+    impl GradCheckFunction<TestBackend, 2, 2> for WrongBackward {
+        fn forward(&self, inputs: &[TestADTensor<2>]) -> TestADTensor<2> {
+            custom_op(WrongBackward, &inputs[0])
+        }
+
+        fn forward_inner(&self, inputs: &[Tensor<TestBackend, 2>]) -> Tensor<TestBackend, 2> {
+            CustomOp::<TestBackend, 2, 2>::forward(self, inputs[0].clone()).0
+        }
+    }
+
+    #[test]
+    fn should_report_wrong_backward() {
+        let inputs = [inputs()[0].clone()];
+
+        let error = gradcheck(&WrongBackward, &inputs, EPS, TOLERANCE).unwrap_err();
+
+        assert_eq!(error.discrepancies.len(), 6);
+        assert_eq!(error.discrepancies[4].input, 0);
+        assert_eq!(error.discrepancies[4].index, vec![1, 1]);
+        let discrepancy = &error.discrepancies[4];
+        assert!((discrepancy.analytical - 2.0 * discrepancy.numerical).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,118 @@
+#[burn_tensor_testgen::testgen(ad_higher_order)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, module::embedding, Data, Tensor};
+
+    // The gradients of the nested backend are tensors of the autodiff backend.
+    type TestAD2Backend = burn_autodiff::ADBackendDecorator<TestADBackend>;
+    type TestAD2Tensor<const D: usize> = Tensor<TestAD2Backend, D>;
+
+    #[test]
+    fn should_diff_twice_powf() {
+        let x = TestADTensor::from_data(Data::<f32, 1>::from([1.0, 2.0, -3.0]));
+        let x_outer = TestAD2Tensor::from_inner(x.clone());
+
+        let y = x_outer.powf(3.0);
+        let grads = y.backward();
+        let grad = x_outer.grad(&grads).unwrap();
+        let grads = grad.backward();
+        let grad_grad = x.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([3.0, 12.0, 27.0]), 3);
+        grad_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([6.0, 12.0, -18.0]), 3);
+    }
+
+    #[test]
+    fn should_diff_mixed_partials() {
+        let x = TestADTensor::from_data(Data::<f32, 1>::from([1.0, 2.0]));
+        let y = TestADTensor::from_data(Data::<f32, 1>::from([3.0, -1.0]));
+        let x_outer = TestAD2Tensor::from_inner(x.clone());
+        let y_outer = TestAD2Tensor::from_inner(y.clone());
+
+        let z = x_outer.mul(&y_outer.powf(2.0)).sum();
+        let grads = z.backward();
+        let grad_y = y_outer.grad(&grads).unwrap();
+        let grads = grad_y.sum().backward();
+
+        grad_y
+            .to_data()
+            .assert_approx_eq(&Data::from([6.0, -4.0]), 3);
+        x.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([6.0, -2.0]), 3);
+        y.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([2.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn should_compute_hessian_vector_product() {
+        let x = TestADTensor::from_data(Data::<f32, 2>::from([[1.0, 2.0]]));
+        let vector = TestADTensor::from_data(Data::<f32, 2>::from([[1.0, -1.0]]));
+        let matrix = TestAD2Tensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let x_outer = TestAD2Tensor::from_inner(x.clone());
+
+        // The hessian of x A xᵀ is A + Aᵀ.
+        let y = x_outer.matmul(&matrix).mul(&x_outer).sum();
+        let grads = y.backward();
+        let grad = x_outer.grad(&grads).unwrap();
+        let grads = grad.mul(&vector).sum().backward();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[12.0, 21.0]]), 3);
+        x.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[-3.0, -3.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_gradient_penalty_wrt_weights() {
+        let x = TestADTensor::from_data(Data::<f32, 2>::from([[1.0, 2.0]]));
+        let weights = TestADTensor::from_data(Data::<f32, 2>::from([[3.0], [4.0]]));
+        let x_outer = TestAD2Tensor::from_inner(x);
+        let weights_outer = TestAD2Tensor::from_inner(weights.clone());
+
+        let critic = x_outer.matmul(&weights_outer).sum();
+        let grads = critic.backward();
+        let grad_x = x_outer.grad(&grads).unwrap();
+        let penalty = grad_x.powf(2.0).sum();
+        let grads = penalty.backward();
+
+        penalty.to_data().assert_approx_eq(&Data::from([25.0]), 3);
+        weights
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0], [8.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_twice_embedding() {
+        let weights = TestADTensor::from_data(Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]));
+        let indexes =
+            Tensor::<<TestAD2Backend as Backend>::IntegerBackend, 2>::from_data(Data::from([
+                [0, 1],
+                [1, 1],
+            ]));
+        let weights_outer = TestAD2Tensor::from_inner(weights.clone());
+
+        let output = embedding(&weights_outer, &indexes).powf(2.0).sum();
+        let grads = output.backward();
+        let grad = weights_outer.grad(&grads).unwrap();
+        let grads = grad.sum().backward();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[2.0, 4.0], [18.0, 24.0]]), 3);
+        weights
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0], [6.0, 6.0]]), 3);
+    }
+}
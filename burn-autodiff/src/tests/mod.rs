@@ -2,11 +2,18 @@ mod add;
 mod aggregation;
 mod backward;
 mod cat;
+mod checkpoint;
 mod complex;
 mod cross_entropy;
+mod custom;
+mod distributions;
 mod div;
+mod dot;
 mod erf;
 mod exp;
+mod fft;
+mod gradcheck;
+mod higher_order;
 mod index;
 mod log;
 mod mask;
@@ -14,10 +21,13 @@ mod matmul;
 mod mul;
 mod multithread;
 mod neg;
+mod no_grad;
 mod pow;
+mod quantization;
 mod relu;
 mod reshape;
 mod softmax;
+mod spmm;
 mod sub;
 mod transpose;
 
@@ -32,20 +42,30 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_add!();
         burn_autodiff::testgen_ad_aggregation!();
         burn_autodiff::testgen_ad_cat!();
+        burn_autodiff::testgen_ad_checkpoint!();
+        burn_autodiff::testgen_ad_custom!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_distributions!();
         burn_autodiff::testgen_ad_div!();
+        burn_autodiff::testgen_ad_dot!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
+        burn_autodiff::testgen_ad_fft!();
+        burn_autodiff::testgen_ad_gradcheck!();
+        burn_autodiff::testgen_ad_higher_order!();
         burn_autodiff::testgen_ad_index!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_mask!();
         burn_autodiff::testgen_ad_matmul!();
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
+        burn_autodiff::testgen_ad_no_grad!();
         burn_autodiff::testgen_ad_powf!();
+        burn_autodiff::testgen_ad_quantized_matmul!();
         burn_autodiff::testgen_ad_relu!();
         burn_autodiff::testgen_ad_reshape!();
         burn_autodiff::testgen_ad_softmax!();
+        burn_autodiff::testgen_ad_spmm!();
         burn_autodiff::testgen_ad_sub!();
         burn_autodiff::testgen_ad_transpose!();
         burn_autodiff::testgen_module_backward!();
@@ -0,0 +1,75 @@
+#[burn_tensor_testgen::testgen(ad_no_grad)]
+mod tests {
+    use super::*;
+    use burn_autodiff::{is_grad_enabled, no_grad};
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_not_record_ops_in_no_grad_scope() {
+        let data = Data::<f32, 1>::from([1.0, 2.0, -1.0]);
+        let tensor_1 = TestADTensor::from_data(data);
+
+        let tensor_2 = no_grad(|| no_grad(|| tensor_1.exp()).mul_scalar(2.0));
+        assert!(is_grad_enabled());
+        let tensor_3 = tensor_1.mul(&tensor_2).sum();
+        let grads = tensor_3.backward();
+
+        assert!(!tensor_2.is_require_grad());
+        assert!(tensor_3.is_require_grad());
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&tensor_2.to_data(), 3);
+    }
+
+    #[test]
+    fn should_skip_ops_with_grad_disabled_inputs() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, 7.0], [2.0, 3.0]]);
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad(false);
+        let tensor_2 = TestADTensor::from_data(data_2);
+
+        let tensor_3 = tensor_1.exp().mul_scalar(2.0);
+        let tensor_4 = tensor_1.matmul(&tensor_2).sum();
+        let grads = tensor_4.backward();
+
+        assert!(!tensor_3.is_require_grad());
+        assert!(tensor_4.is_require_grad());
+        assert!(tensor_1.grad(&grads).is_none());
+        assert_eq!(
+            tensor_2.grad(&grads).unwrap().to_data(),
+            Data::from([[3.0, 3.0], [10.0, 10.0]])
+        );
+    }
+
+    #[test]
+    fn should_enable_grad_as_new_leaf() {
+        let data = Data::<f32, 1>::from([1.0, 2.0, -1.0]);
+        let tensor_1 = TestADTensor::from_data(data);
+
+        let tensor_2 = tensor_1.mul_scalar(3.0).require_grad(false);
+        let tensor_3 = tensor_2.clone().require_grad(true);
+        let tensor_4 = tensor_3.mul(&tensor_1).sum();
+        let grads = tensor_4.backward();
+
+        assert!(tensor_3.is_require_grad());
+        assert!(tensor_2.grad(&grads).is_none());
+        assert_eq!(tensor_3.grad(&grads).unwrap().to_data(), tensor_1.to_data());
+        assert_eq!(tensor_1.grad(&grads).unwrap().to_data(), tensor_2.to_data());
+    }
+
+    #[test]
+    fn should_detach_as_new_leaf() {
+        let data = Data::<f32, 1>::from([1.0, 2.0, -1.0]);
+        let tensor_1 = TestADTensor::from_data(data);
+
+        let tensor_2 = tensor_1.mul_scalar(3.0).detach();
+        let tensor_3 = tensor_2.mul(&tensor_1).sum();
+        let grads = tensor_3.backward();
+
+        assert!(tensor_2.is_require_grad());
+        assert_eq!(tensor_2.grad(&grads).unwrap().to_data(), tensor_1.to_data());
+        assert_eq!(tensor_1.grad(&grads).unwrap().to_data(), tensor_2.to_data());
+    }
+}
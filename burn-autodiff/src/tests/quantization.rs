@@ -0,0 +1,28 @@
+#[burn_tensor_testgen::testgen(ad_quantized_matmul)]
+mod tests {
+    use super::*;
+    use burn_tensor::QuantizationScheme;
+
+    #[test]
+    fn should_diff_quantized_matmul() {
+        let lhs = TestADTensor::from_floats([[1.0, 7.0], [2.0, 3.0]]);
+        let rhs = TestADTensor::from_floats([[4.0, 7.0], [2.0, 3.0]]);
+        let rhs_quantized = rhs.quantize(QuantizationScheme::PerChannel(1));
+
+        let output = lhs.matmul_quantized(&rhs_quantized);
+        let grads = output.backward();
+
+        let grad = lhs.grad(&grads).unwrap();
+        let grad_expected =
+            TestADTensor::ones([2, 2]).matmul(&rhs_quantized.dequantize().transpose());
+
+        grad.to_data()
+            .assert_close(&grad_expected.to_data(), 0.0, 1e-6);
+        grad.to_data().assert_close(
+            &burn_tensor::Data::from([[11.0, 5.0], [11.0, 5.0]]),
+            0.0,
+            0.05,
+        );
+        assert!(rhs.grad(&grads).is_none());
+    }
+}
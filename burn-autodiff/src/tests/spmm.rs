@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(ad_spmm)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Data, Shape, SparseTensor, Tensor};
+
+    #[test]
+    fn should_diff_spmm() {
+        let indices =
+            Tensor::<<TestADBackend as Backend>::IntegerBackend, 2>::from_data(Data::from([
+                [0, 0],
+                [1, 2],
+                [2, 1],
+            ]));
+        let values = TestADTensor::from_floats([2.0, -1.0, 3.0]);
+        let sparse = SparseTensor::new(indices, values, Shape::new([3, 3]));
+        let dense = TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let weights = TestADTensor::from_floats([[1.0], [2.0]]);
+
+        let output = sparse.spmm(&dense).matmul(&weights);
+        let grads = output.backward();
+
+        let grad = dense.grad(&grads).unwrap();
+        let grad_expected = sparse
+            .to_dense()
+            .transpose()
+            .matmul(&TestADTensor::ones(Shape::new([3, 1])))
+            .matmul(&weights.transpose());
+
+        assert_eq!(
+            grad.to_data(),
+            Data::from([[2.0, 4.0], [3.0, 6.0], [-1.0, -2.0]])
+        );
+        assert_eq!(grad.to_data(), grad_expected.to_data());
+    }
+}
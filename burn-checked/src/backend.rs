@@ -0,0 +1,47 @@
+use burn_tensor::backend::Backend;
+use burn_tensor::{Generator, RngState};
+
+/// Backend decorator checking the output of every operation of the inner backend.
+///
+/// Operations whose output doesn't follow their shape rule, or contains NaN or infinite values,
+/// are reported by panicking with their name, see [CheckOptions](crate::CheckOptions).
+/// Decorated by [ADBackendDecorator](https://docs.rs/burn-autodiff), the operations of the
+/// backward pass are checked as well.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CheckedBackend<B> {
+    _b: B,
+}
+
+impl<B: Backend> Backend for CheckedBackend<B> {
+    type Device = B::Device;
+    type Elem = B::Elem;
+    type FullPrecisionElem = B::FullPrecisionElem;
+    type IntegerBackend = CheckedBackend<B::IntegerBackend>;
+    type FullPrecisionBackend = CheckedBackend<B::FullPrecisionBackend>;
+    type TensorPrimitive<const D: usize> = B::TensorPrimitive<D>;
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<D>;
+
+    fn ad_enabled() -> bool {
+        false
+    }
+
+    fn name() -> String {
+        format!("checked<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn fork_rng(device: B::Device) -> Generator {
+        B::fork_rng(device)
+    }
+
+    fn get_rng_state(device: B::Device) -> RngState {
+        B::get_rng_state(device)
+    }
+
+    fn set_rng_state(device: B::Device, state: RngState) {
+        B::set_rng_state(device, state)
+    }
+}
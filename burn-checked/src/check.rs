@@ -0,0 +1,242 @@
+use burn_tensor::{backend::Backend, Data, Element, Shape};
+use std::backtrace::Backtrace;
+use std::sync::RwLock;
+
+static CHECK_OPTIONS: RwLock<CheckOptions> = RwLock::new(CheckOptions::const_default());
+
+/// Options used to check the operations of the checked backend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CheckOptions {
+    /// Report the operations producing NaN values.
+    pub nan: bool,
+    /// Report the operations producing infinite values.
+    ///
+    /// Models masking with infinite values, instead of the minimum finite value, should disable it.
+    pub inf: bool,
+    /// Report the operations whose inputs or output don't follow the shape rule of the operation.
+    pub shape: bool,
+    /// Capture a backtrace of the reported operation, locating the call in the model.
+    pub backtrace: bool,
+}
+
+impl CheckOptions {
+    const fn const_default() -> Self {
+        Self {
+            nan: true,
+            inf: true,
+            shape: true,
+            backtrace: false,
+        }
+    }
+}
+
+impl Default for CheckOptions {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Set the options used to check the operations of the checked backend.
+pub fn set_check_options(options: CheckOptions) {
+    let mut current = CHECK_OPTIONS.write().unwrap();
+    *current = options;
+}
+
+/// Returns the options used to check the operations of the checked backend.
+pub fn check_options() -> CheckOptions {
+    *CHECK_OPTIONS.read().unwrap()
+}
+
+/// Report the failure of an operation by panicking, with a backtrace when enabled.
+pub(crate) fn report(op: &str, message: String) -> ! {
+    let mut report = format!("Checked operation `{}` failed: {}", op, message);
+
+    if check_options().backtrace {
+        report += &format!("\n\nBacktrace:\n{}", Backtrace::force_capture());
+    }
+
+    panic!("{}", report)
+}
+
+/// Returns the output shape given by the rule of an operation, reporting the operation when its
+/// inputs break the rule.
+///
+/// The shape is `None` when the inputs break the rule and shapes aren't checked.
+pub(crate) fn expect_shape<const D: usize>(
+    op: &str,
+    rule: Result<Shape<D>, String>,
+) -> Option<Shape<D>> {
+    match rule {
+        Ok(shape) => Some(shape),
+        Err(message) if check_options().shape => report(op, message),
+        Err(_) => None,
+    }
+}
+
+/// Check the output of an operation, which is returned unchanged.
+pub(crate) fn check_output<B: Backend, const D: usize>(
+    op: &str,
+    tensor: B::TensorPrimitive<D>,
+    shape: Option<Shape<D>>,
+) -> B::TensorPrimitive<D> {
+    let options = check_options();
+
+    if options.shape {
+        check_shape(op, B::shape(&tensor), shape);
+    }
+
+    if options.nan || options.inf {
+        check_values(op, &B::to_data(&tensor), &options);
+    }
+
+    tensor
+}
+
+/// Check the output of an operation returning booleans, which only has a shape to check.
+pub(crate) fn check_bool_output<B: Backend, const D: usize>(
+    op: &str,
+    tensor: B::BoolTensorPrimitive<D>,
+    shape: Option<Shape<D>>,
+) -> B::BoolTensorPrimitive<D> {
+    if check_options().shape {
+        check_shape(op, B::bool_shape(&tensor), shape);
+    }
+
+    tensor
+}
+
+pub(crate) fn check_shape<const D: usize>(op: &str, actual: &Shape<D>, expected: Option<Shape<D>>) {
+    if let Some(expected) = expected {
+        if *actual != expected {
+            report(
+                op,
+                format!(
+                    "output has shape {:?}, expected {:?}",
+                    actual.dims, expected.dims
+                ),
+            );
+        }
+    }
+}
+
+fn check_values<E: Element, const D: usize>(op: &str, data: &Data<E, D>, options: &CheckOptions) {
+    let mut nan = Anomalies::new("NaN");
+    let mut inf = Anomalies::new("infinite");
+
+    for (index, value) in data.value.iter().enumerate() {
+        let value: f64 = value.to_elem();
+
+        if options.nan && value.is_nan() {
+            nan.push(index);
+        } else if options.inf && value.is_infinite() {
+            inf.push(index);
+        }
+    }
+
+    let messages: Vec<String> = [nan, inf]
+        .iter()
+        .filter_map(|anomalies| anomalies.message(&data.shape))
+        .collect();
+
+    if !messages.is_empty() {
+        report(op, format!("output contains {}", messages.join(" and ")));
+    }
+}
+
+/// Values of a kind that shouldn't be produced, tracked by their number and first position.
+struct Anomalies {
+    kind: &'static str,
+    count: usize,
+    first: Option<usize>,
+}
+
+impl Anomalies {
+    fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            count: 0,
+            first: None,
+        }
+    }
+
+    fn push(&mut self, index: usize) {
+        self.count += 1;
+        self.first.get_or_insert(index);
+    }
+
+    fn message<const D: usize>(&self, shape: &Shape<D>) -> Option<String> {
+        let first = self.first?;
+        let mut position = [0; D];
+        let mut remainder = first;
+
+        for (position, dim) in position.iter_mut().zip(shape.dims).rev() {
+            *position = remainder % dim;
+            remainder /= dim;
+        }
+
+        Some(format!(
+            "{} {} values, the first at index {:?} of shape {:?}",
+            self.count, self.kind, position, shape.dims
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::CheckedBackend;
+    use burn_autodiff::ADBackendDecorator;
+    use burn_tensor::{Data, Tensor};
+
+    type TestBackend = CheckedBackend<burn_ndarray::NdArrayBackend<f32>>;
+    type TestADBackend = ADBackendDecorator<TestBackend>;
+
+    #[test]
+    #[should_panic(expected = "Checked operation `div` failed: output contains 1 NaN values")]
+    fn should_report_nan_values() {
+        let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([0.0, 1.0]));
+
+        tensor.div(&tensor);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Checked operation `log` failed: output contains 2 infinite values, \
+                               the first at index [0, 1] of shape [2, 2]"
+    )]
+    fn should_report_infinite_values() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [0.0, 2.0]]));
+
+        tensor.log();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Checked operation `add` failed: can't broadcast shapes [2, 3] and \
+                               [2, 2]"
+    )]
+    fn should_report_invalid_broadcast() {
+        let lhs = Tensor::<TestBackend, 2>::zeros([2, 3]);
+        let rhs = Tensor::<TestBackend, 2>::zeros([2, 2]);
+
+        lhs.add(&rhs);
+    }
+
+    #[test]
+    #[should_panic(expected = "Checked operation `matmul` failed: can't multiply matrices")]
+    fn should_report_invalid_matmul() {
+        let lhs = Tensor::<TestBackend, 2>::zeros([2, 3]);
+        let rhs = Tensor::<TestBackend, 2>::zeros([2, 3]);
+
+        lhs.matmul(&rhs);
+    }
+
+    #[test]
+    #[should_panic(expected = "Checked operation `powf` failed: output contains 1 infinite values")]
+    fn should_check_the_backward_pass() {
+        let tensor = Tensor::<TestADBackend, 1>::from_data(Data::from([0.0, 4.0]));
+
+        // The square root of zero is valid, but not its gradient.
+        let output = tensor.powf(0.5).sum();
+        output.backward();
+    }
+}
@@ -0,0 +1,57 @@
+pub(crate) mod check;
+pub(crate) mod ops;
+pub(crate) mod rules;
+
+mod backend;
+pub use backend::*;
+pub use check::{check_options, set_check_options, CheckOptions};
+
+#[cfg(test)]
+mod tests {
+    type TestBackend = crate::CheckedBackend<burn_ndarray::NdArrayBackend<f32>>;
+
+    // test activation
+    burn_tensor::testgen_gelu!();
+    burn_tensor::testgen_relu!();
+    burn_tensor::testgen_softmax!();
+
+    // test distributions, except the categorical one computing the log of impossible classes,
+    // which is infinite and reported by the checks
+    burn_tensor::testgen_bernoulli!();
+    burn_tensor::testgen_beta!();
+    burn_tensor::testgen_normal!();
+
+    // test module
+    burn_tensor::testgen_module_forward!();
+
+    // test ops
+    burn_tensor::testgen_add!();
+    burn_tensor::testgen_aggregation!();
+    burn_tensor::testgen_arg!();
+    burn_tensor::testgen_close!();
+    burn_tensor::testgen_create!();
+    burn_tensor::testgen_display!();
+    burn_tensor::testgen_div!();
+    burn_tensor::testgen_erf!();
+    burn_tensor::testgen_exp!();
+    burn_tensor::testgen_fft!();
+    burn_tensor::testgen_index!();
+    burn_tensor::testgen_map_comparison!();
+    burn_tensor::testgen_mask!();
+    burn_tensor::testgen_matmul!();
+    burn_tensor::testgen_mul!();
+    burn_tensor::testgen_neg!();
+    burn_tensor::testgen_owned!();
+    burn_tensor::testgen_powf!();
+    burn_tensor::testgen_quantization!();
+    burn_tensor::testgen_random!();
+    burn_tensor::testgen_repeat!();
+    burn_tensor::testgen_reshape!();
+    burn_tensor::testgen_sparse!();
+    burn_tensor::testgen_sub!();
+    burn_tensor::testgen_transpose!();
+
+    // test stats
+    burn_tensor::testgen_stats!();
+    burn_autodiff::testgen_all!();
+}
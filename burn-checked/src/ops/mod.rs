@@ -0,0 +1,2 @@
+mod module;
+mod tensor;
@@ -0,0 +1,29 @@
+use crate::check::{check_output, expect_shape};
+use crate::{rules, CheckedBackend};
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::*;
+
+type IntTensor<B, const D: usize> = <<B as Backend>::IntegerBackend as Backend>::TensorPrimitive<D>;
+
+impl<B: Backend> ModuleOps<CheckedBackend<B>> for CheckedBackend<B> {
+    fn embedding(
+        weights: &B::TensorPrimitive<2>,
+        indexes: &IntTensor<B, 2>,
+    ) -> B::TensorPrimitive<3> {
+        let shape = expect_shape(
+            "embedding",
+            rules::embedding(B::shape(weights), B::IntegerBackend::shape(indexes)),
+        );
+        check_output::<B, 3>("embedding", B::embedding(weights, indexes), shape)
+    }
+
+    fn embedding_backward(
+        weights: &B::TensorPrimitive<2>,
+        output: &B::TensorPrimitive<3>,
+        indexes: &IntTensor<B, 2>,
+    ) -> B::TensorPrimitive<2> {
+        let shape = Some(*B::shape(weights));
+        let grad = B::embedding_backward(weights, output, indexes);
+        check_output::<B, 2>("embedding_backward", grad, shape)
+    }
+}
@@ -0,0 +1,482 @@
+use crate::check::{check_bool_output, check_output, check_shape, expect_shape};
+use crate::{rules, CheckedBackend};
+use burn_tensor::backend::Backend;
+use burn_tensor::{
+    ops::*, Data, Distribution, Generator, QuantizationScheme, QuantizedData, Shape,
+};
+use std::ops::Range;
+
+type Tensor<B, const D: usize> = <B as Backend>::TensorPrimitive<D>;
+type BoolTensor<B, const D: usize> = <B as Backend>::BoolTensorPrimitive<D>;
+type IntTensor<B, const D: usize> = <<B as Backend>::IntegerBackend as Backend>::TensorPrimitive<D>;
+type FullPrecisionTensor<B, const D: usize> =
+    <<B as Backend>::FullPrecisionBackend as Backend>::TensorPrimitive<D>;
+
+impl<B: Backend> TensorOps<CheckedBackend<B>> for CheckedBackend<B> {
+    fn from_data<const D: usize>(data: Data<B::Elem, D>, device: B::Device) -> Tensor<B, D> {
+        let shape = Some(data.shape);
+        check_output::<B, D>("from_data", B::from_data(data, device), shape)
+    }
+
+    fn from_data_bool<const D: usize>(data: Data<bool, D>, device: B::Device) -> BoolTensor<B, D> {
+        let shape = Some(data.shape);
+        check_bool_output::<B, D>("from_data_bool", B::from_data_bool(data, device), shape)
+    }
+
+    fn random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        device: B::Device,
+    ) -> Tensor<B, D> {
+        let tensor = B::random(shape, distribution, device);
+        check_output::<B, D>("random", tensor, Some(shape))
+    }
+
+    fn random_with<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        generator: &mut Generator,
+        device: B::Device,
+    ) -> Tensor<B, D> {
+        let tensor = B::random_with(shape, distribution, generator, device);
+        check_output::<B, D>("random_with", tensor, Some(shape))
+    }
+
+    fn zeros<const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+        check_output::<B, D>("zeros", B::zeros(shape, device), Some(shape))
+    }
+
+    fn ones<const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+        check_output::<B, D>("ones", B::ones(shape, device), Some(shape))
+    }
+
+    fn shape<const D: usize>(tensor: &Tensor<B, D>) -> &Shape<D> {
+        B::shape(tensor)
+    }
+
+    fn to_data<const D: usize>(tensor: &Tensor<B, D>) -> Data<B::Elem, D> {
+        B::to_data(tensor)
+    }
+
+    fn into_data<const D: usize>(tensor: Tensor<B, D>) -> Data<B::Elem, D> {
+        B::into_data(tensor)
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<B, D>) -> &Shape<D> {
+        B::bool_shape(tensor)
+    }
+
+    fn bool_to_data<const D: usize>(tensor: &BoolTensor<B, D>) -> Data<bool, D> {
+        B::bool_to_data(tensor)
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<B, D>) -> Data<bool, D> {
+        B::bool_into_data(tensor)
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<B, D>) -> B::Device {
+        B::bool_device(tensor)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: &BoolTensor<B, D>,
+        device: B::Device,
+    ) -> BoolTensor<B, D> {
+        let shape = Some(*B::bool_shape(tensor));
+        check_bool_output::<B, D>("bool_to_device", B::bool_to_device(tensor, device), shape)
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: &BoolTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<B, D2> {
+        let expected = expect_shape("bool_reshape", rules::reshape(B::bool_shape(tensor), shape));
+        check_bool_output::<B, D2>("bool_reshape", B::bool_reshape(tensor, shape), expected)
+    }
+
+    fn device<const D: usize>(tensor: &Tensor<B, D>) -> B::Device {
+        B::device(tensor)
+    }
+
+    fn to_device<const D: usize>(tensor: &Tensor<B, D>, device: B::Device) -> Tensor<B, D> {
+        let shape = Some(*B::shape(tensor));
+        check_output::<B, D>("to_device", B::to_device(tensor, device), shape)
+    }
+
+    fn arange(range: Range<usize>, device: B::Device) -> IntTensor<B, 1> {
+        let shape = Some(Shape::new([range.len()]));
+        check_output::<B::IntegerBackend, 1>("arange", B::arange(range, device), shape)
+    }
+
+    fn empty<const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+        // The values of empty tensors are arbitrary, only the shape is checked.
+        let tensor = B::empty(shape, device);
+        check_shape("empty", B::shape(&tensor), Some(shape));
+        tensor
+    }
+
+    fn repeat<const D: usize>(tensor: &Tensor<B, D>, dim: usize, times: usize) -> Tensor<B, D> {
+        let shape = expect_shape("repeat", rules::repeat(B::shape(tensor), dim, times));
+        check_output::<B, D>("repeat", B::repeat(tensor, dim, times), shape)
+    }
+
+    fn add<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("add", rules::broadcast(B::shape(lhs), B::shape(rhs)));
+        check_output::<B, D>("add", B::add(lhs, rhs), shape)
+    }
+
+    fn add_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let shape = Some(*B::shape(lhs));
+        check_output::<B, D>("add_scalar", B::add_scalar(lhs, rhs), shape)
+    }
+
+    fn sub<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("sub", rules::broadcast(B::shape(lhs), B::shape(rhs)));
+        check_output::<B, D>("sub", B::sub(lhs, rhs), shape)
+    }
+
+    fn sub_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let shape = Some(*B::shape(lhs));
+        check_output::<B, D>("sub_scalar", B::sub_scalar(lhs, rhs), shape)
+    }
+
+    fn mul<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("mul", rules::broadcast(B::shape(lhs), B::shape(rhs)));
+        check_output::<B, D>("mul", B::mul(lhs, rhs), shape)
+    }
+
+    fn mul_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let shape = Some(*B::shape(lhs));
+        check_output::<B, D>("mul_scalar", B::mul_scalar(lhs, rhs), shape)
+    }
+
+    fn div<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("div", rules::broadcast(B::shape(lhs), B::shape(rhs)));
+        check_output::<B, D>("div", B::div(lhs, rhs), shape)
+    }
+
+    fn div_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let shape = Some(*B::shape(lhs));
+        check_output::<B, D>("div_scalar", B::div_scalar(lhs, rhs), shape)
+    }
+
+    fn matmul<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("matmul", rules::matmul(B::shape(lhs), B::shape(rhs)));
+        check_output::<B, D>("matmul", B::matmul(lhs, rhs), shape)
+    }
+
+    fn spmm(
+        indices: &IntTensor<B, 2>,
+        values: &Tensor<B, 1>,
+        shape: Shape<2>,
+        dense: &Tensor<B, 2>,
+    ) -> Tensor<B, 2> {
+        let expected = expect_shape("spmm", rules::matmul(&shape, B::shape(dense)));
+        check_output::<B, 2>("spmm", B::spmm(indices, values, shape, dense), expected)
+    }
+
+    fn quantize<const D: usize>(
+        tensor: &Tensor<B, D>,
+        scheme: QuantizationScheme,
+    ) -> QuantizedData<D> {
+        B::quantize(tensor, scheme)
+    }
+
+    fn dequantize<const D: usize>(data: &QuantizedData<D>, device: B::Device) -> Tensor<B, D> {
+        let shape = Some(data.values.shape);
+        check_output::<B, D>("dequantize", B::dequantize(data, device), shape)
+    }
+
+    fn quantized_matmul(lhs: &Tensor<B, 2>, rhs: &QuantizedData<2>) -> Tensor<B, 2> {
+        let shape = expect_shape(
+            "quantized_matmul",
+            rules::matmul(B::shape(lhs), &rhs.values.shape),
+        );
+        check_output::<B, 2>("quantized_matmul", B::quantized_matmul(lhs, rhs), shape)
+    }
+
+    fn fft<const D: usize>(tensor: &Tensor<B, D>, inverse: bool) -> Tensor<B, D> {
+        let shape = Some(*B::shape(tensor));
+        check_output::<B, D>("fft", B::fft(tensor, inverse), shape)
+    }
+
+    fn rfft<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("rfft", rules::rfft(B::shape(tensor)));
+        check_output::<B, D>("rfft", B::rfft(tensor), shape)
+    }
+
+    fn neg<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(tensor));
+        check_output::<B, D>("neg", B::neg(tensor), shape)
+    }
+
+    fn transpose<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape(
+            "transpose",
+            rules::swap_dims(B::shape(tensor), D - 2, D - 1),
+        );
+        check_output::<B, D>("transpose", B::transpose(tensor), shape)
+    }
+
+    fn swap_dims<const D: usize>(tensor: &Tensor<B, D>, dim1: usize, dim2: usize) -> Tensor<B, D> {
+        let shape = expect_shape("swap_dims", rules::swap_dims(B::shape(tensor), dim1, dim2));
+        check_output::<B, D>("swap_dims", B::swap_dims(tensor, dim1, dim2), shape)
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: &Tensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> Tensor<B, D2> {
+        let expected = expect_shape("reshape", rules::reshape(B::shape(tensor), shape));
+        check_output::<B, D2>("reshape", B::reshape(tensor, shape), expected)
+    }
+
+    fn index<const D1: usize, const D2: usize>(
+        tensor: &Tensor<B, D1>,
+        indexes: [Range<usize>; D2],
+    ) -> Tensor<B, D1> {
+        let shape = expect_shape("index", rules::index(B::shape(tensor), &indexes));
+        check_output::<B, D1>("index", B::index(tensor, indexes), shape)
+    }
+
+    fn index_assign<const D1: usize, const D2: usize>(
+        tensor: &Tensor<B, D1>,
+        indexes: [Range<usize>; D2],
+        value: &Tensor<B, D1>,
+    ) -> Tensor<B, D1> {
+        let shape = expect_shape(
+            "index_assign",
+            rules::index_assign(B::shape(tensor), &indexes, B::shape(value)),
+        );
+        check_output::<B, D1>(
+            "index_assign",
+            B::index_assign(tensor, indexes, value),
+            shape,
+        )
+    }
+
+    fn mask_fill<const D: usize>(
+        tensor: &Tensor<B, D>,
+        mask: &BoolTensor<B, D>,
+        value: B::Elem,
+    ) -> Tensor<B, D> {
+        let shape = expect_shape(
+            "mask_fill",
+            rules::mask(B::shape(tensor), B::bool_shape(mask)),
+        );
+        check_output::<B, D>("mask_fill", B::mask_fill(tensor, mask, value), shape)
+    }
+
+    fn equal<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        let shape = expect_shape("equal", rules::broadcast(B::shape(lhs), B::shape(rhs)));
+        check_bool_output::<B, D>("equal", B::equal(lhs, rhs), shape)
+    }
+
+    fn equal_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        let shape = Some(*B::shape(lhs));
+        check_bool_output::<B, D>("equal_scalar", B::equal_scalar(lhs, rhs), shape)
+    }
+
+    fn greater<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        let shape = expect_shape("greater", rules::broadcast(B::shape(lhs), B::shape(rhs)));
+        check_bool_output::<B, D>("greater", B::greater(lhs, rhs), shape)
+    }
+
+    fn greater_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        let shape = Some(*B::shape(lhs));
+        check_bool_output::<B, D>("greater_scalar", B::greater_scalar(lhs, rhs), shape)
+    }
+
+    fn greater_equal<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        let shape = expect_shape(
+            "greater_equal",
+            rules::broadcast(B::shape(lhs), B::shape(rhs)),
+        );
+        check_bool_output::<B, D>("greater_equal", B::greater_equal(lhs, rhs), shape)
+    }
+
+    fn greater_equal_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        let shape = Some(*B::shape(lhs));
+        let output = B::greater_equal_scalar(lhs, rhs);
+        check_bool_output::<B, D>("greater_equal_scalar", output, shape)
+    }
+
+    fn lower<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        let shape = expect_shape("lower", rules::broadcast(B::shape(lhs), B::shape(rhs)));
+        check_bool_output::<B, D>("lower", B::lower(lhs, rhs), shape)
+    }
+
+    fn lower_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        let shape = Some(*B::shape(lhs));
+        check_bool_output::<B, D>("lower_scalar", B::lower_scalar(lhs, rhs), shape)
+    }
+
+    fn lower_equal<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        let shape = expect_shape(
+            "lower_equal",
+            rules::broadcast(B::shape(lhs), B::shape(rhs)),
+        );
+        check_bool_output::<B, D>("lower_equal", B::lower_equal(lhs, rhs), shape)
+    }
+
+    fn lower_equal_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        let shape = Some(*B::shape(lhs));
+        check_bool_output::<B, D>("lower_equal_scalar", B::lower_equal_scalar(lhs, rhs), shape)
+    }
+
+    fn detach<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        B::detach(tensor)
+    }
+
+    fn mean<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, 1> {
+        check_output::<B, 1>("mean", B::mean(tensor), Some(Shape::new([1])))
+    }
+
+    fn sum<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, 1> {
+        check_output::<B, 1>("sum", B::sum(tensor), Some(Shape::new([1])))
+    }
+
+    fn mean_dim<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+        let shape = expect_shape("mean_dim", rules::reduce_dim(B::shape(tensor), dim));
+        check_output::<B, D>("mean_dim", B::mean_dim(tensor, dim), shape)
+    }
+
+    fn sum_dim<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+        let shape = expect_shape("sum_dim", rules::reduce_dim(B::shape(tensor), dim));
+        check_output::<B, D>("sum_dim", B::sum_dim(tensor, dim), shape)
+    }
+
+    fn to_full_precision<const D: usize>(tensor: &Tensor<B, D>) -> FullPrecisionTensor<B, D> {
+        let shape = Some(*B::shape(tensor));
+        let output = B::to_full_precision(tensor);
+        check_output::<B::FullPrecisionBackend, D>("to_full_precision", output, shape)
+    }
+
+    fn from_full_precision<const D: usize>(tensor: &FullPrecisionTensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::FullPrecisionBackend::shape(tensor));
+        check_output::<B, D>("from_full_precision", B::from_full_precision(tensor), shape)
+    }
+
+    fn argmax<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        let shape = expect_shape("argmax", rules::reduce_dim(B::shape(tensor), dim));
+        check_output::<B::IntegerBackend, D>("argmax", B::argmax(tensor, dim), shape)
+    }
+
+    fn argmin<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        let shape = expect_shape("argmin", rules::reduce_dim(B::shape(tensor), dim));
+        check_output::<B::IntegerBackend, D>("argmin", B::argmin(tensor, dim), shape)
+    }
+
+    fn multinomial<const D: usize>(
+        tensor: &Tensor<B, D>,
+        num_samples: usize,
+        replacement: bool,
+        generator: &mut Generator,
+    ) -> IntTensor<B, D> {
+        let mut shape = *B::shape(tensor);
+        shape.dims[D - 1] = num_samples;
+        let output = B::multinomial(tensor, num_samples, replacement, generator);
+        check_output::<B::IntegerBackend, D>("multinomial", output, Some(shape))
+    }
+
+    fn exp<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(tensor));
+        check_output::<B, D>("exp", B::exp(tensor), shape)
+    }
+
+    fn log<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(tensor));
+        check_output::<B, D>("log", B::log(tensor), shape)
+    }
+
+    fn powf<const D: usize>(tensor: &Tensor<B, D>, value: f32) -> Tensor<B, D> {
+        let shape = Some(*B::shape(tensor));
+        check_output::<B, D>("powf", B::powf(tensor, value), shape)
+    }
+
+    fn erf<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(tensor));
+        check_output::<B, D>("erf", B::erf(tensor), shape)
+    }
+
+    fn cat<const D: usize>(tensors: &[Tensor<B, D>], dim: usize) -> Tensor<B, D> {
+        let shapes: Vec<Shape<D>> = tensors.iter().map(|tensor| *B::shape(tensor)).collect();
+        let shape = expect_shape("cat", rules::cat(&shapes, dim));
+        check_output::<B, D>("cat", B::cat(tensors, dim), shape)
+    }
+
+    fn relu<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(tensor));
+        check_output::<B, D>("relu", B::relu(tensor), shape)
+    }
+
+    fn add_owned<const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("add", rules::broadcast(B::shape(&lhs), B::shape(&rhs)));
+        check_output::<B, D>("add", B::add_owned(lhs, rhs), shape)
+    }
+
+    fn add_scalar_owned<const D: usize>(lhs: Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&lhs));
+        check_output::<B, D>("add_scalar", B::add_scalar_owned(lhs, rhs), shape)
+    }
+
+    fn sub_owned<const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("sub", rules::broadcast(B::shape(&lhs), B::shape(&rhs)));
+        check_output::<B, D>("sub", B::sub_owned(lhs, rhs), shape)
+    }
+
+    fn sub_scalar_owned<const D: usize>(lhs: Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&lhs));
+        check_output::<B, D>("sub_scalar", B::sub_scalar_owned(lhs, rhs), shape)
+    }
+
+    fn mul_owned<const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("mul", rules::broadcast(B::shape(&lhs), B::shape(&rhs)));
+        check_output::<B, D>("mul", B::mul_owned(lhs, rhs), shape)
+    }
+
+    fn mul_scalar_owned<const D: usize>(lhs: Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&lhs));
+        check_output::<B, D>("mul_scalar", B::mul_scalar_owned(lhs, rhs), shape)
+    }
+
+    fn div_owned<const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = expect_shape("div", rules::broadcast(B::shape(&lhs), B::shape(&rhs)));
+        check_output::<B, D>("div", B::div_owned(lhs, rhs), shape)
+    }
+
+    fn div_scalar_owned<const D: usize>(lhs: Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&lhs));
+        check_output::<B, D>("div_scalar", B::div_scalar_owned(lhs, rhs), shape)
+    }
+
+    fn neg_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&tensor));
+        check_output::<B, D>("neg", B::neg_owned(tensor), shape)
+    }
+
+    fn exp_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&tensor));
+        check_output::<B, D>("exp", B::exp_owned(tensor), shape)
+    }
+
+    fn log_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&tensor));
+        check_output::<B, D>("log", B::log_owned(tensor), shape)
+    }
+
+    fn erf_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&tensor));
+        check_output::<B, D>("erf", B::erf_owned(tensor), shape)
+    }
+
+    fn relu_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&tensor));
+        check_output::<B, D>("relu", B::relu_owned(tensor), shape)
+    }
+
+    fn powf_owned<const D: usize>(tensor: Tensor<B, D>, value: f32) -> Tensor<B, D> {
+        let shape = Some(*B::shape(&tensor));
+        check_output::<B, D>("powf", B::powf_owned(tensor, value), shape)
+    }
+}
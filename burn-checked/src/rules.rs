@@ -0,0 +1,216 @@
+//! Output shapes of the operations given the shapes of their inputs.
+
+use burn_tensor::Shape;
+use std::ops::Range;
+
+pub(crate) type Rule<const D: usize> = Result<Shape<D>, String>;
+
+/// Element wise operations broadcast the dimensions of size 1.
+pub(crate) fn broadcast<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>) -> Rule<D> {
+    let mut shape = *lhs;
+
+    for (dim, rhs_dim) in shape.dims.iter_mut().zip(rhs.dims) {
+        match (*dim, rhs_dim) {
+            (lhs_dim, rhs_dim) if lhs_dim == rhs_dim => {}
+            (1, rhs_dim) => *dim = rhs_dim,
+            (_, 1) => {}
+            _ => {
+                return Err(format!(
+                    "can't broadcast shapes {:?} and {:?}",
+                    lhs.dims, rhs.dims
+                ))
+            }
+        }
+    }
+
+    Ok(shape)
+}
+
+/// The mask is broadcasted to the shape of the tensor.
+pub(crate) fn mask<const D: usize>(tensor: &Shape<D>, mask: &Shape<D>) -> Rule<D> {
+    match tensor
+        .dims
+        .iter()
+        .zip(mask.dims)
+        .all(|(dim, mask_dim)| *dim == mask_dim || mask_dim == 1)
+    {
+        true => Ok(*tensor),
+        false => Err(format!(
+            "can't broadcast mask of shape {:?} to shape {:?}",
+            mask.dims, tensor.dims
+        )),
+    }
+}
+
+/// Matrices are multiplied along the last two dimensions, the other ones being broadcasted.
+pub(crate) fn matmul<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>) -> Rule<D> {
+    if D < 2 {
+        return Err(format!("can't multiply tensors of rank {}", D));
+    }
+
+    if lhs.dims[D - 1] != rhs.dims[D - 2] {
+        return Err(format!(
+            "can't multiply matrices of shapes {:?} and {:?}",
+            lhs.dims, rhs.dims
+        ));
+    }
+
+    let mut lhs_batch = *lhs;
+    let mut rhs_batch = *rhs;
+    for dim in D - 2..D {
+        lhs_batch.dims[dim] = 1;
+        rhs_batch.dims[dim] = 1;
+    }
+
+    let mut shape = broadcast(&lhs_batch, &rhs_batch)?;
+    shape.dims[D - 2] = lhs.dims[D - 2];
+    shape.dims[D - 1] = rhs.dims[D - 1];
+
+    Ok(shape)
+}
+
+/// Reductions along a dimension keep it with a size of 1.
+pub(crate) fn reduce_dim<const D: usize>(shape: &Shape<D>, dim: usize) -> Rule<D> {
+    check_dim(shape, dim)?;
+
+    let mut shape = *shape;
+    shape.dims[dim] = 1;
+
+    Ok(shape)
+}
+
+pub(crate) fn swap_dims<const D: usize>(shape: &Shape<D>, dim1: usize, dim2: usize) -> Rule<D> {
+    check_dim(shape, dim1)?;
+    check_dim(shape, dim2)?;
+
+    let mut output = *shape;
+    output.dims.swap(dim1, dim2);
+
+    Ok(output)
+}
+
+pub(crate) fn reshape<const D1: usize, const D2: usize>(
+    shape: &Shape<D1>,
+    output: Shape<D2>,
+) -> Rule<D2> {
+    match shape.num_elements() == output.num_elements() {
+        true => Ok(output),
+        false => Err(format!(
+            "can't reshape {:?} into {:?}, the number of elements differs",
+            shape.dims, output.dims
+        )),
+    }
+}
+
+/// Indexing keeps the length of the ranges, the dimensions without range being kept whole.
+pub(crate) fn index<const D1: usize, const D2: usize>(
+    shape: &Shape<D1>,
+    indexes: &[Range<usize>; D2],
+) -> Rule<D1> {
+    if D2 > D1 {
+        return Err(format!(
+            "can't index {} dimensions of a rank {} tensor",
+            D2, D1
+        ));
+    }
+
+    let mut output = *shape;
+
+    for (dim, range) in indexes.iter().enumerate() {
+        if range.start > range.end || range.end > shape.dims[dim] {
+            return Err(format!(
+                "range {:?} is out of bounds for dimension {} of shape {:?}",
+                range, dim, shape.dims
+            ));
+        }
+
+        output.dims[dim] = range.end - range.start;
+    }
+
+    Ok(output)
+}
+
+/// The assigned value has the shape of the indexed tensor, and the output the shape of the
+/// tensor.
+pub(crate) fn index_assign<const D1: usize, const D2: usize>(
+    shape: &Shape<D1>,
+    indexes: &[Range<usize>; D2],
+    value: &Shape<D1>,
+) -> Rule<D1> {
+    let indexed = index(shape, indexes)?;
+
+    match indexed == *value {
+        true => Ok(*shape),
+        false => Err(format!(
+            "can't assign a value of shape {:?} to indexes of shape {:?}",
+            value.dims, indexed.dims
+        )),
+    }
+}
+
+/// Only dimensions of size 1 can be repeated.
+pub(crate) fn repeat<const D: usize>(shape: &Shape<D>, dim: usize, times: usize) -> Rule<D> {
+    check_dim(shape, dim)?;
+
+    if shape.dims[dim] != 1 {
+        return Err(format!(
+            "can't repeat dimension {} of shape {:?}, its size isn't 1",
+            dim, shape.dims
+        ));
+    }
+
+    let mut output = *shape;
+    output.dims[dim] = times;
+
+    Ok(output)
+}
+
+/// Tensors are concatenated along a dimension, the other ones being equal.
+pub(crate) fn cat<const D: usize>(shapes: &[Shape<D>], dim: usize) -> Rule<D> {
+    let first = shapes
+        .first()
+        .ok_or_else(|| "can't concatenate an empty list of tensors".to_string())?;
+    check_dim(first, dim)?;
+
+    let mut output = *first;
+    output.dims[dim] = 0;
+
+    for shape in shapes {
+        if (0..D).any(|i| i != dim && shape.dims[i] != first.dims[i]) {
+            return Err(format!(
+                "can't concatenate shapes {:?} and {:?} along dimension {}",
+                first.dims, shape.dims, dim
+            ));
+        }
+
+        output.dims[dim] += shape.dims[dim];
+    }
+
+    Ok(output)
+}
+
+/// Each index is replaced by the row of the weights it selects.
+pub(crate) fn embedding(weights: &Shape<2>, indexes: &Shape<2>) -> Rule<3> {
+    let [batch_size, seq_length] = indexes.dims;
+
+    Ok(Shape::new([batch_size, seq_length, weights.dims[1]]))
+}
+
+/// The last dimension holds the real parts followed by the imaginary parts of the
+/// `size / 2 + 1` non redundant frequencies.
+pub(crate) fn rfft<const D: usize>(shape: &Shape<D>) -> Rule<D> {
+    let mut output = *shape;
+    output.dims[D - 1] = 2 * (shape.dims[D - 1] / 2 + 1);
+
+    Ok(output)
+}
+
+fn check_dim<const D: usize>(shape: &Shape<D>, dim: usize) -> Result<(), String> {
+    match dim < D {
+        true => Ok(()),
+        false => Err(format!(
+            "dimension {} is out of bounds for shape {:?}",
+            dim, shape.dims
+        )),
+    }
+}
@@ -20,6 +20,7 @@ pub(crate) fn module_derive_impl(ast: &syn::DeriveInput) -> TokenStream {
     let load_fn = param.gen_load_fn();
     let inner_fn = param.gen_inner_fn();
     let detach_fn = param.gen_detach_fn();
+    let visit_fn = param.gen_visit_fn();
     let generics_names_except_backend = generics_names_except_backend(&ast.generics);
 
     let gen = quote! {
@@ -29,6 +30,7 @@ pub(crate) fn module_derive_impl(ast: &syn::DeriveInput) -> TokenStream {
             #devices_fn
             #to_device_fn
             #detach_fn
+            #visit_fn
 
             #state_fn
             #load_fn
@@ -159,6 +159,22 @@ impl Param {
         }
     }
 
+    pub fn gen_visit_fn(&self) -> TokenStream {
+        let mut body = quote! {};
+        for field in self.fields_param.iter() {
+            let name = field.ident();
+            body.extend(quote! {
+                self.#name.visit(visitor);
+            });
+        }
+
+        quote! {
+            fn visit<V: burn::module::ModuleVisitor<Self::Backend>>(&self, visitor: &mut V) {
+                #body
+            }
+        }
+    }
+
     pub fn gen_inner_fn(&self) -> TokenStream {
         let mut body = quote! {};
         let mut names = Vec::new();
@@ -174,9 +190,15 @@ impl Param {
             let name = field.ident();
             names.push(name.clone());
 
-            body.extend(quote! {
-                let #name = self.#name.clone();
-            });
+            // The marker of the backend changes to the inner backend.
+            match field.is_phantom_data() {
+                true => body.extend(quote! {
+                    let #name = std::marker::PhantomData;
+                }),
+                false => body.extend(quote! {
+                    let #name = self.#name.clone();
+                }),
+            }
         }
 
         quote! {
@@ -77,6 +77,15 @@ impl FieldTypeAnalyzer {
     pub fn is_param(&self) -> bool {
         self.is_of_type(&["Param", "burn::Param"])
     }
+
+    pub fn is_phantom_data(&self) -> bool {
+        self.is_of_type(&[
+            "PhantomData",
+            "marker::PhantomData",
+            "std::marker::PhantomData",
+            "core::marker::PhantomData",
+        ])
+    }
 }
 
 pub(crate) fn parse_fields(ast: &syn::DeriveInput) -> Vec<Field> {
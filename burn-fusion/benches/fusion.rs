@@ -0,0 +1,91 @@
+use burn_fusion::FusionBackend;
+use burn_ndarray::NdArrayBackend;
+use burn_tensor::{activation, backend::Backend, Distribution, Tensor};
+use std::time::{Duration, Instant};
+
+const NUM_RUNS: u32 = 10;
+
+fn main() {
+    println!(
+        "{:<16} {:>12} {:>12} {:>8}",
+        "chain", "ndarray", "fusion", "speedup"
+    );
+
+    bench(
+        "gelu",
+        gelu::<NdArrayBackend<f32>>(),
+        gelu::<FusionBackend<NdArrayBackend<f32>>>(),
+    );
+    bench(
+        "layer norm",
+        layer_norm::<NdArrayBackend<f32>>(),
+        layer_norm::<FusionBackend<NdArrayBackend<f32>>>(),
+    );
+    bench(
+        "sgd momentum",
+        sgd_momentum::<NdArrayBackend<f32>>(),
+        sgd_momentum::<FusionBackend<NdArrayBackend<f32>>>(),
+    );
+}
+
+fn gelu<B: Backend>() -> impl Fn() {
+    let x = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+
+    move || {
+        activation::gelu(&x).into_data();
+    }
+}
+
+fn layer_norm<B: Backend>() -> impl Fn() {
+    let x = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+    let gamma = Tensor::<B, 2>::random([1, 1024], Distribution::Standard);
+    let beta = Tensor::<B, 2>::random([1, 1024], Distribution::Standard);
+
+    move || {
+        let mean = x.mean_dim(1);
+        let centered = x.sub(&mean);
+        let var = centered.powf(2.0).mean_dim(1);
+        let std = var.add_scalar(1e-5).powf(0.5);
+
+        centered.div(&std).mul(&gamma).add(&beta).into_data();
+    }
+}
+
+fn sgd_momentum<B: Backend>() -> impl Fn() {
+    let param = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+    let grad = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+    let velocity = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+
+    move || {
+        let velocity = grad.mul_scalar(0.9).add(&velocity.mul_scalar(0.1));
+        let param = param.sub(&velocity.mul_scalar(0.01));
+
+        param.into_data();
+        velocity.into_data();
+    }
+}
+
+fn bench(name: &str, ndarray: impl Fn(), fusion: impl Fn()) {
+    let ndarray = time(ndarray);
+    let fusion = time(fusion);
+
+    println!(
+        "{:<16} {:>12?} {:>12?} {:>7.2}x",
+        name,
+        ndarray,
+        fusion,
+        ndarray.as_secs_f64() / fusion.as_secs_f64()
+    );
+}
+
+fn time(func: impl Fn()) -> Duration {
+    // Warm up the allocator before timing.
+    func();
+
+    let start = Instant::now();
+    for _ in 0..NUM_RUNS {
+        func();
+    }
+
+    start.elapsed() / NUM_RUNS
+}
@@ -0,0 +1,46 @@
+use crate::tensor::FusionTensor;
+use burn_tensor::backend::Backend;
+use burn_tensor::{Generator, RngState};
+
+/// Backend decorator recording chains of element wise operations lazily.
+///
+/// The recorded operations are executed as a single loop over the data of the inner backend
+/// when another operation, or the data of the tensor, is needed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FusionBackend<B> {
+    _b: B,
+}
+
+impl<B: Backend> Backend for FusionBackend<B> {
+    type Device = B::Device;
+    type Elem = B::Elem;
+    type FullPrecisionElem = B::FullPrecisionElem;
+    type IntegerBackend = B::IntegerBackend;
+    type FullPrecisionBackend = FusionBackend<B::FullPrecisionBackend>;
+    type TensorPrimitive<const D: usize> = FusionTensor<B, D>;
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<D>;
+
+    fn ad_enabled() -> bool {
+        false
+    }
+
+    fn name() -> String {
+        format!("fusion<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn fork_rng(device: B::Device) -> Generator {
+        B::fork_rng(device)
+    }
+
+    fn get_rng_state(device: B::Device) -> RngState {
+        B::get_rng_state(device)
+    }
+
+    fn set_rng_state(device: B::Device, state: RngState) {
+        B::set_rng_state(device, state)
+    }
+}
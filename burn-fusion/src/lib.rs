@@ -0,0 +1,14 @@
+pub(crate) mod ops;
+pub(crate) mod program;
+pub(crate) mod tensor;
+
+mod backend;
+pub use backend::*;
+
+#[cfg(test)]
+mod tests {
+    type TestBackend = crate::FusionBackend<burn_ndarray::NdArrayBackend<f32>>;
+
+    burn_tensor::testgen_all!();
+    burn_autodiff::testgen_all!();
+}
@@ -0,0 +1,25 @@
+use crate::tensor::FusionTensor;
+use crate::FusionBackend;
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::*;
+
+impl<B: Backend> ModuleOps<FusionBackend<B>> for FusionBackend<B> {
+    fn embedding(
+        weights: &FusionTensor<B, 2>,
+        indexes: &<B::IntegerBackend as Backend>::TensorPrimitive<2>,
+    ) -> FusionTensor<B, 3> {
+        FusionTensor::from_tensor(B::embedding(&weights.tensor(), indexes))
+    }
+
+    fn embedding_backward(
+        weights: &FusionTensor<B, 2>,
+        output: &FusionTensor<B, 3>,
+        indexes: &<B::IntegerBackend as Backend>::TensorPrimitive<2>,
+    ) -> FusionTensor<B, 2> {
+        FusionTensor::from_tensor(B::embedding_backward(
+            &weights.tensor(),
+            &output.tensor(),
+            indexes,
+        ))
+    }
+}
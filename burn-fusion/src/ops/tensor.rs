@@ -0,0 +1,411 @@
+use crate::program::Instruction;
+use crate::tensor::FusionTensor;
+use crate::FusionBackend;
+use burn_tensor::backend::Backend;
+use burn_tensor::{
+    ops::*, Data, Distribution, ElementConversion, Generator, QuantizationScheme, QuantizedData,
+    Shape,
+};
+use std::ops::Range;
+
+type IntTensor<B, const D: usize> = <<B as Backend>::IntegerBackend as Backend>::TensorPrimitive<D>;
+
+impl<B: Backend, const D: usize> std::ops::Add<FusionTensor<B, D>> for FusionTensor<B, D> {
+    type Output = FusionTensor<B, D>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        FusionBackend::add(&self, &rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> Zeros for FusionTensor<B, D> {
+    fn zeros(&self) -> Self {
+        FusionBackend::zeros(self.shape, self.device())
+    }
+}
+
+impl<B: Backend, const D: usize> Ones for FusionTensor<B, D> {
+    fn ones(&self) -> Self {
+        FusionBackend::ones(self.shape, self.device())
+    }
+}
+
+impl<B: Backend> TensorOps<FusionBackend<B>> for FusionBackend<B> {
+    fn from_data<const D: usize>(data: Data<B::Elem, D>, device: B::Device) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::from_data(data, device))
+    }
+
+    fn from_data_bool<const D: usize>(
+        data: Data<bool, D>,
+        device: B::Device,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::from_data_bool(data, device)
+    }
+
+    fn random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        device: B::Device,
+    ) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::random(shape, distribution, device))
+    }
+
+    fn random_with<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        generator: &mut Generator,
+        device: B::Device,
+    ) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::random_with(shape, distribution, generator, device))
+    }
+
+    fn zeros<const D: usize>(shape: Shape<D>, device: B::Device) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::zeros(shape, device))
+    }
+
+    fn ones<const D: usize>(shape: Shape<D>, device: B::Device) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::ones(shape, device))
+    }
+
+    fn shape<const D: usize>(tensor: &FusionTensor<B, D>) -> &Shape<D> {
+        &tensor.shape
+    }
+
+    fn to_data<const D: usize>(tensor: &FusionTensor<B, D>) -> Data<B::Elem, D> {
+        B::into_data(tensor.tensor())
+    }
+
+    fn into_data<const D: usize>(tensor: FusionTensor<B, D>) -> Data<B::Elem, D> {
+        tensor.into_data()
+    }
+
+    fn bool_shape<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> &Shape<D> {
+        B::bool_shape(tensor)
+    }
+
+    fn bool_to_data<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> Data<bool, D> {
+        B::bool_to_data(tensor)
+    }
+
+    fn bool_into_data<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> Data<bool, D> {
+        B::bool_into_data(tensor)
+    }
+
+    fn bool_device<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> B::Device {
+        B::bool_device(tensor)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: &B::BoolTensorPrimitive<D>,
+        device: B::Device,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::bool_to_device(tensor, device)
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: &B::BoolTensorPrimitive<D1>,
+        shape: Shape<D2>,
+    ) -> B::BoolTensorPrimitive<D2> {
+        B::bool_reshape(tensor, shape)
+    }
+
+    fn device<const D: usize>(tensor: &FusionTensor<B, D>) -> B::Device {
+        tensor.device()
+    }
+
+    fn to_device<const D: usize>(
+        tensor: &FusionTensor<B, D>,
+        device: B::Device,
+    ) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::to_device(&tensor.tensor(), device))
+    }
+
+    fn arange(range: Range<usize>, device: B::Device) -> IntTensor<B, 1> {
+        B::arange(range, device)
+    }
+
+    fn empty<const D: usize>(shape: Shape<D>, device: B::Device) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::empty(shape, device))
+    }
+
+    fn repeat<const D: usize>(
+        tensor: &FusionTensor<B, D>,
+        dim: usize,
+        times: usize,
+    ) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::repeat(&tensor.tensor(), dim, times))
+    }
+
+    fn add<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> FusionTensor<B, D> {
+        lhs.binary(rhs, Instruction::Add)
+    }
+
+    fn add_scalar<const D: usize>(lhs: &FusionTensor<B, D>, rhs: &B::Elem) -> FusionTensor<B, D> {
+        lhs.unary(Instruction::AddScalar(rhs.to_elem()))
+    }
+
+    fn sub<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> FusionTensor<B, D> {
+        lhs.binary(rhs, Instruction::Sub)
+    }
+
+    fn sub_scalar<const D: usize>(lhs: &FusionTensor<B, D>, rhs: &B::Elem) -> FusionTensor<B, D> {
+        lhs.unary(Instruction::SubScalar(rhs.to_elem()))
+    }
+
+    fn mul<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> FusionTensor<B, D> {
+        lhs.binary(rhs, Instruction::Mul)
+    }
+
+    fn mul_scalar<const D: usize>(lhs: &FusionTensor<B, D>, rhs: &B::Elem) -> FusionTensor<B, D> {
+        lhs.unary(Instruction::MulScalar(rhs.to_elem()))
+    }
+
+    fn div<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> FusionTensor<B, D> {
+        lhs.binary(rhs, Instruction::Div)
+    }
+
+    fn div_scalar<const D: usize>(lhs: &FusionTensor<B, D>, rhs: &B::Elem) -> FusionTensor<B, D> {
+        lhs.unary(Instruction::DivScalar(rhs.to_elem()))
+    }
+
+    fn matmul<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::matmul(&lhs.tensor(), &rhs.tensor()))
+    }
+
+    fn spmm(
+        indices: &IntTensor<B, 2>,
+        values: &FusionTensor<B, 1>,
+        shape: Shape<2>,
+        dense: &FusionTensor<B, 2>,
+    ) -> FusionTensor<B, 2> {
+        FusionTensor::from_tensor(B::spmm(indices, &values.tensor(), shape, &dense.tensor()))
+    }
+
+    fn quantize<const D: usize>(
+        tensor: &FusionTensor<B, D>,
+        scheme: QuantizationScheme,
+    ) -> QuantizedData<D> {
+        B::quantize(&tensor.tensor(), scheme)
+    }
+
+    fn dequantize<const D: usize>(
+        data: &QuantizedData<D>,
+        device: B::Device,
+    ) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::dequantize(data, device))
+    }
+
+    fn quantized_matmul(lhs: &FusionTensor<B, 2>, rhs: &QuantizedData<2>) -> FusionTensor<B, 2> {
+        FusionTensor::from_tensor(B::quantized_matmul(&lhs.tensor(), rhs))
+    }
+
+    fn fft<const D: usize>(tensor: &FusionTensor<B, D>, inverse: bool) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::fft(&tensor.tensor(), inverse))
+    }
+
+    fn rfft<const D: usize>(tensor: &FusionTensor<B, D>) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::rfft(&tensor.tensor()))
+    }
+
+    fn neg<const D: usize>(tensor: &FusionTensor<B, D>) -> FusionTensor<B, D> {
+        tensor.unary(Instruction::Neg)
+    }
+
+    fn swap_dims<const D: usize>(
+        tensor: &FusionTensor<B, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::swap_dims(&tensor.tensor(), dim1, dim2))
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: &FusionTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> FusionTensor<B, D2> {
+        FusionTensor::from_tensor(B::reshape(&tensor.tensor(), shape))
+    }
+
+    fn index<const D1: usize, const D2: usize>(
+        tensor: &FusionTensor<B, D1>,
+        indexes: [Range<usize>; D2],
+    ) -> FusionTensor<B, D1> {
+        FusionTensor::from_tensor(B::index(&tensor.tensor(), indexes))
+    }
+
+    fn index_assign<const D1: usize, const D2: usize>(
+        tensor: &FusionTensor<B, D1>,
+        indexes: [Range<usize>; D2],
+        value: &FusionTensor<B, D1>,
+    ) -> FusionTensor<B, D1> {
+        FusionTensor::from_tensor(B::index_assign(&tensor.tensor(), indexes, &value.tensor()))
+    }
+
+    fn mask_fill<const D: usize>(
+        tensor: &FusionTensor<B, D>,
+        mask: &B::BoolTensorPrimitive<D>,
+        value: B::Elem,
+    ) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::mask_fill(&tensor.tensor(), mask, value))
+    }
+
+    fn equal<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::equal(&lhs.tensor(), &rhs.tensor())
+    }
+
+    fn equal_scalar<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &B::Elem,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::equal_scalar(&lhs.tensor(), rhs)
+    }
+
+    fn greater<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::greater(&lhs.tensor(), &rhs.tensor())
+    }
+
+    fn greater_scalar<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &B::Elem,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::greater_scalar(&lhs.tensor(), rhs)
+    }
+
+    fn greater_equal<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::greater_equal(&lhs.tensor(), &rhs.tensor())
+    }
+
+    fn greater_equal_scalar<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &B::Elem,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::greater_equal_scalar(&lhs.tensor(), rhs)
+    }
+
+    fn lower<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::lower(&lhs.tensor(), &rhs.tensor())
+    }
+
+    fn lower_scalar<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &B::Elem,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::lower_scalar(&lhs.tensor(), rhs)
+    }
+
+    fn lower_equal<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &FusionTensor<B, D>,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::lower_equal(&lhs.tensor(), &rhs.tensor())
+    }
+
+    fn lower_equal_scalar<const D: usize>(
+        lhs: &FusionTensor<B, D>,
+        rhs: &B::Elem,
+    ) -> B::BoolTensorPrimitive<D> {
+        B::lower_equal_scalar(&lhs.tensor(), rhs)
+    }
+
+    fn detach<const D: usize>(tensor: &FusionTensor<B, D>) -> FusionTensor<B, D> {
+        tensor.clone()
+    }
+
+    fn mean<const D: usize>(tensor: &FusionTensor<B, D>) -> FusionTensor<B, 1> {
+        FusionTensor::from_tensor(B::mean(&tensor.tensor()))
+    }
+
+    fn sum<const D: usize>(tensor: &FusionTensor<B, D>) -> FusionTensor<B, 1> {
+        FusionTensor::from_tensor(B::sum(&tensor.tensor()))
+    }
+
+    fn mean_dim<const D: usize>(tensor: &FusionTensor<B, D>, dim: usize) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::mean_dim(&tensor.tensor(), dim))
+    }
+
+    fn sum_dim<const D: usize>(tensor: &FusionTensor<B, D>, dim: usize) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::sum_dim(&tensor.tensor(), dim))
+    }
+
+    fn to_full_precision<const D: usize>(
+        tensor: &FusionTensor<B, D>,
+    ) -> FusionTensor<B::FullPrecisionBackend, D> {
+        FusionTensor::from_tensor(B::to_full_precision(&tensor.tensor()))
+    }
+
+    fn from_full_precision<const D: usize>(
+        tensor: &FusionTensor<B::FullPrecisionBackend, D>,
+    ) -> FusionTensor<B, D> {
+        FusionTensor::from_tensor(B::from_full_precision(&tensor.tensor()))
+    }
+
+    fn argmax<const D: usize>(tensor: &FusionTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::argmax(&tensor.tensor(), dim)
+    }
+
+    fn argmin<const D: usize>(tensor: &FusionTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::argmin(&tensor.tensor(), dim)
+    }
+
+    fn multinomial<const D: usize>(
+        tensor: &FusionTensor<B, D>,
+        num_samples: usize,
+        replacement: bool,
+        generator: &mut Generator,
+    ) -> IntTensor<B, D> {
+        B::multinomial(&tensor.tensor(), num_samples, replacement, generator)
+    }
+
+    fn exp<const D: usize>(tensor: &FusionTensor<B, D>) -> FusionTensor<B, D> {
+        tensor.unary(Instruction::Exp)
+    }
+
+    fn log<const D: usize>(tensor: &FusionTensor<B, D>) -> FusionTensor<B, D> {
+        tensor.unary(Instruction::Log)
+    }
+
+    fn powf<const D: usize>(tensor: &FusionTensor<B, D>, value: f32) -> FusionTensor<B, D> {
+        tensor.unary(Instruction::Powf(value))
+    }
+
+    fn erf<const D: usize>(tensor: &FusionTensor<B, D>) -> FusionTensor<B, D> {
+        tensor.unary(Instruction::Erf)
+    }
+
+    fn cat<const D: usize>(tensors: &[FusionTensor<B, D>], dim: usize) -> FusionTensor<B, D> {
+        let tensors: Vec<_> = tensors.iter().map(|tensor| tensor.tensor()).collect();
+
+        FusionTensor::from_tensor(B::cat(&tensors, dim))
+    }
+
+    fn relu<const D: usize>(tensor: &FusionTensor<B, D>) -> FusionTensor<B, D> {
+        tensor.unary(Instruction::Relu)
+    }
+}
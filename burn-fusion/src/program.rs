@@ -0,0 +1,597 @@
+use crate::tensor::FusionTensor;
+use burn_tensor::{
+    backend::Backend, Data, Element, ElementConversion, ElementPrecision, Precision, Shape,
+};
+use num_traits::ToPrimitive;
+use std::borrow::Cow;
+use std::ops::{Add, Div, Neg, Range, Sub};
+
+/// Number of elements computed by each instruction before moving to the next one, small enough
+/// to keep the intermediate values in cache.
+const BLOCK_SIZE: usize = 1024;
+
+/// Element wise operation of a fused program.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddScalar(f64),
+    SubScalar(f64),
+    MulScalar(f64),
+    DivScalar(f64),
+    Neg,
+    Exp,
+    Log,
+    Powf(f32),
+    Erf,
+    Relu,
+}
+
+/// Value computed by a fused program, from one of its inputs or from the values of previous
+/// nodes.
+#[derive(Clone, Copy, Debug)]
+enum Node {
+    Input(usize),
+    Unary(Instruction, usize),
+    Binary(Instruction, usize, usize),
+}
+
+/// Graph of element wise operations over executed tensors.
+///
+/// Each value is computed once: the nodes computing the same instruction with the same operands
+/// are merged, so the work shared by both operands of a binary operation isn't duplicated.
+#[derive(Clone, Debug)]
+pub struct Program<B: Backend, const D: usize> {
+    inputs: Vec<FusionTensor<B, D>>,
+    nodes: Vec<Node>,
+    output: usize,
+}
+
+impl<B: Backend, const D: usize> Program<B, D> {
+    /// Create a program loading an executed tensor.
+    pub fn load(tensor: FusionTensor<B, D>) -> Self {
+        Self {
+            inputs: vec![tensor],
+            nodes: vec![Node::Input(0)],
+            output: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Apply a unary instruction to the output of the program.
+    pub fn unary(&mut self, instruction: Instruction) {
+        self.output = self.push(Node::Unary(instruction, self.output));
+    }
+
+    /// Apply a binary instruction to the outputs of both programs, sharing their inputs and the
+    /// values they both compute.
+    pub fn binary(&mut self, other: Program<B, D>, instruction: Instruction) {
+        let lhs = self.output;
+        let rhs = self.append(other);
+
+        self.output = self.push(Node::Binary(instruction, lhs, rhs));
+    }
+
+    /// Append the nodes of the other program, returning the node of its output.
+    fn append(&mut self, other: Program<B, D>) -> usize {
+        let inputs: Vec<usize> = other
+            .inputs
+            .into_iter()
+            .map(|input| {
+                match self
+                    .inputs
+                    .iter()
+                    .position(|current| current.is_same(&input))
+                {
+                    Some(index) => index,
+                    None => {
+                        self.inputs.push(input);
+                        self.inputs.len() - 1
+                    }
+                }
+            })
+            .collect();
+        let mut nodes = Vec::with_capacity(other.nodes.len());
+
+        for node in other.nodes {
+            let node = match node {
+                Node::Input(index) => Node::Input(inputs[index]),
+                Node::Unary(instruction, input) => Node::Unary(instruction, nodes[input]),
+                Node::Binary(instruction, lhs, rhs) => {
+                    Node::Binary(instruction, nodes[lhs], nodes[rhs])
+                }
+            };
+            nodes.push(self.push(node));
+        }
+
+        nodes[other.output]
+    }
+
+    /// Returns the position of the node, which is only added when no node computes the same
+    /// value.
+    fn push(&mut self, node: Node) -> usize {
+        match self.nodes.iter().position(|current| current.is_same(&node)) {
+            Some(position) => position,
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Execute the nodes in a single pass over the elements of the inputs.
+    ///
+    /// Values are computed with single precision, unless the elements need more. Half precision
+    /// values are rounded after each instruction, so that the result is the same as executing
+    /// the operations one by one.
+    pub fn execute(&self, shape: Shape<D>, device: B::Device) -> B::TensorPrimitive<D> {
+        B::from_data(self.execute_data(shape), device)
+    }
+
+    /// Execute the nodes, returning the data of the output without creating a tensor of the inner
+    /// backend.
+    pub fn execute_data(&self, shape: Shape<D>) -> Data<B::Elem, D> {
+        match B::Elem::precision() {
+            Precision::Full | Precision::Half => self.execute_with::<f32>(shape),
+            Precision::Double | Precision::Other => self.execute_with::<f64>(shape),
+        }
+    }
+
+    fn execute_with<V: Value>(&self, shape: Shape<D>) -> Data<B::Elem, D> {
+        let tensors: Vec<_> = self.inputs.iter().map(|input| input.tensor()).collect();
+        // The elements of the inner tensors are read in place when they are contiguous.
+        let inputs: Vec<_> = tensors
+            .iter()
+            .map(|tensor| {
+                let values = match B::as_slice(tensor) {
+                    Some(values) => Cow::Borrowed(values),
+                    None => Cow::Owned(B::to_data(tensor).value),
+                };
+                ProgramInput::new(values, B::shape(tensor), &shape)
+            })
+            .collect();
+        let round = B::Elem::precision() == Precision::Half;
+        let mut executor = Executor::<V>::new(&self.nodes, self.output);
+        let num_elements = shape.num_elements();
+        let mut output = Vec::with_capacity(num_elements);
+
+        for start in (0..num_elements).step_by(BLOCK_SIZE) {
+            let range = start..usize::min(start + BLOCK_SIZE, num_elements);
+            let values = executor.execute(&inputs, range, round);
+
+            output.extend(values.iter().map(|value| B::Elem::from_elem(*value)));
+        }
+
+        Data::new(output, shape)
+    }
+}
+
+impl Node {
+    /// Returns whether both nodes compute the same value, comparing the bits of their scalars.
+    fn is_same(&self, other: &Node) -> bool {
+        match (self, other) {
+            (Node::Input(lhs), Node::Input(rhs)) => lhs == rhs,
+            (Node::Unary(lhs, lhs_input), Node::Unary(rhs, rhs_input)) => {
+                lhs.is_same(rhs) && lhs_input == rhs_input
+            }
+            (Node::Binary(lhs, lhs_lhs, lhs_rhs), Node::Binary(rhs, rhs_lhs, rhs_rhs)) => {
+                lhs.is_same(rhs) && lhs_lhs == rhs_lhs && lhs_rhs == rhs_rhs
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Instruction {
+    fn is_same(&self, other: &Instruction) -> bool {
+        match (self, other) {
+            (Self::AddScalar(lhs), Self::AddScalar(rhs))
+            | (Self::SubScalar(lhs), Self::SubScalar(rhs))
+            | (Self::MulScalar(lhs), Self::MulScalar(rhs))
+            | (Self::DivScalar(lhs), Self::DivScalar(rhs)) => lhs.to_bits() == rhs.to_bits(),
+            (Self::Powf(lhs), Self::Powf(rhs)) => lhs.to_bits() == rhs.to_bits(),
+            _ => self == other,
+        }
+    }
+
+    fn unary<V: Value>(&self, values: &mut [V]) {
+        match self {
+            Self::AddScalar(rhs) => unary_scalar(values, rhs, |value, rhs| value + rhs),
+            Self::SubScalar(rhs) => unary_scalar(values, rhs, |value, rhs| value - rhs),
+            Self::MulScalar(rhs) => unary_scalar(values, rhs, |value, rhs| value * rhs),
+            Self::DivScalar(rhs) => unary_scalar(values, rhs, |value, rhs| value / rhs),
+            Self::Neg => unary(values, |value| -value),
+            Self::Exp => unary(values, V::exp),
+            Self::Log => unary(values, V::ln),
+            Self::Powf(exponent) => unary(values, |value| value.powf(*exponent)),
+            Self::Erf => unary(values, V::erf),
+            Self::Relu => {
+                let zero = V::from_f64(0.0);
+                unary(values, |value| match value <= zero {
+                    true => zero,
+                    false => value,
+                })
+            }
+            Self::Add | Self::Sub | Self::Mul | Self::Div => {
+                unreachable!("{:?} is a binary instruction", self)
+            }
+        }
+    }
+
+    fn binary<V: Value>(&self, lhs: &mut [V], rhs: &[V]) {
+        match self {
+            Self::Add => binary(lhs, rhs, |lhs, rhs| lhs + rhs),
+            Self::Sub => binary(lhs, rhs, |lhs, rhs| lhs - rhs),
+            Self::Mul => binary(lhs, rhs, |lhs, rhs| lhs * rhs),
+            Self::Div => binary(lhs, rhs, |lhs, rhs| lhs / rhs),
+            _ => unreachable!("{:?} is a unary instruction", self),
+        }
+    }
+}
+
+fn unary<V: Value, F: Fn(V) -> V>(values: &mut [V], func: F) {
+    values.iter_mut().for_each(|value| *value = func(*value));
+}
+
+fn unary_scalar<V: Value, F: Fn(V, V) -> V>(values: &mut [V], rhs: &f64, func: F) {
+    let rhs = V::from_f64(*rhs);
+    unary(values, |value| func(value, rhs));
+}
+
+fn binary<V: Value, F: Fn(V, V) -> V>(lhs: &mut [V], rhs: &[V], func: F) {
+    lhs.iter_mut()
+        .zip(rhs.iter())
+        .for_each(|(lhs, rhs)| *lhs = func(*lhs, *rhs));
+}
+
+/// Computes the nodes of a program block by block, keeping the values of each node until its
+/// last use.
+struct Executor<'a, V> {
+    nodes: &'a [Node],
+    output: usize,
+    /// Position of the last node using the value of each node.
+    last_uses: Vec<usize>,
+    values: Vec<Vec<V>>,
+    released: Vec<Vec<V>>,
+}
+
+impl<'a, V: Value> Executor<'a, V> {
+    fn new(nodes: &'a [Node], output: usize) -> Self {
+        let mut last_uses: Vec<usize> = (0..nodes.len()).collect();
+
+        for (position, node) in nodes.iter().enumerate() {
+            match node {
+                Node::Input(_) => {}
+                Node::Unary(_, input) => last_uses[*input] = position,
+                Node::Binary(_, lhs, rhs) => {
+                    last_uses[*lhs] = position;
+                    last_uses[*rhs] = position;
+                }
+            }
+        }
+        // The output is kept until all the nodes are executed.
+        last_uses[output] = nodes.len();
+
+        Self {
+            nodes,
+            output,
+            last_uses,
+            values: vec![Vec::new(); nodes.len()],
+            released: Vec::new(),
+        }
+    }
+
+    /// Returns the values of the output for the range of elements, rounding the values computed
+    /// by each instruction to the elements of the inputs when `round` is set.
+    fn execute<E: Element>(
+        &mut self,
+        inputs: &[ProgramInput<E>],
+        range: Range<usize>,
+        round: bool,
+    ) -> &[V] {
+        if let Some(values) = self.released_output() {
+            self.released.push(values);
+        }
+
+        for (position, node) in self.nodes.iter().enumerate() {
+            let values = match *node {
+                Node::Input(index) => {
+                    let mut values = self.released.pop().unwrap_or_default();
+                    values.clear();
+                    inputs[index].load(range.clone(), &mut values);
+                    self.values[position] = values;
+                    continue;
+                }
+                Node::Unary(instruction, input) => {
+                    let mut values = self.operand(input, position);
+                    instruction.unary(&mut values);
+                    values
+                }
+                Node::Binary(instruction, lhs, rhs) => {
+                    let mut values = self.operand(lhs, position);
+                    instruction.binary(&mut values, &self.values[rhs]);
+                    values
+                }
+            };
+            self.values[position] = values;
+
+            if round {
+                unary(&mut self.values[position], |value| {
+                    V::from_primitive(E::from_elem(value))
+                });
+            }
+
+            self.release(node, position);
+        }
+
+        &self.values[self.output]
+    }
+
+    /// Returns the values of the input of an instruction, to be updated in place. They are moved
+    /// when the instruction is their last use, and copied otherwise.
+    fn operand(&mut self, input: usize, position: usize) -> Vec<V> {
+        if self.last_uses[input] == position && !self.is_operand_twice(position) {
+            return std::mem::take(&mut self.values[input]);
+        }
+
+        let mut values = self.released.pop().unwrap_or_default();
+        values.clear();
+        values.extend_from_slice(&self.values[input]);
+        values
+    }
+
+    fn is_operand_twice(&self, position: usize) -> bool {
+        matches!(self.nodes[position], Node::Binary(_, lhs, rhs) if lhs == rhs)
+    }
+
+    /// Release the values of the inputs of the node used for the last time.
+    fn release(&mut self, node: &Node, position: usize) {
+        let inputs = match *node {
+            Node::Input(_) => [None, None],
+            Node::Unary(_, input) => [Some(input), None],
+            Node::Binary(_, lhs, rhs) => [Some(lhs), Some(rhs)],
+        };
+
+        for input in inputs.into_iter().flatten() {
+            if self.last_uses[input] == position && !self.values[input].is_empty() {
+                let values = std::mem::take(&mut self.values[input]);
+                self.released.push(values);
+            }
+        }
+    }
+
+    fn released_output(&mut self) -> Option<Vec<V>> {
+        match self.values[self.output].is_empty() {
+            true => None,
+            false => Some(std::mem::take(&mut self.values[self.output])),
+        }
+    }
+}
+
+struct ProgramInput<'a, E: Clone> {
+    values: Cow<'a, [E]>,
+    broadcast: Option<Broadcast>,
+}
+
+/// Maps the indexes of the output to the indexes of a broadcasted input.
+struct Broadcast {
+    dims: Vec<usize>,
+    /// Strides of the input along each dimension of the output, null for broadcasted dimensions.
+    strides: Vec<usize>,
+}
+
+impl<'a, E: ToPrimitive + Copy> ProgramInput<'a, E> {
+    fn new<const D: usize>(values: Cow<'a, [E]>, input: &Shape<D>, shape: &Shape<D>) -> Self {
+        let broadcast = match input == shape {
+            true => None,
+            false => {
+                let mut strides = vec![0; D];
+                let mut stride = 1;
+
+                for i in (0..D).rev() {
+                    if input.dims[i] != 1 {
+                        strides[i] = stride;
+                    }
+                    stride *= input.dims[i];
+                }
+
+                Some(Broadcast {
+                    dims: shape.dims.to_vec(),
+                    strides,
+                })
+            }
+        };
+
+        Self { values, broadcast }
+    }
+
+    fn load<V: Value>(&self, range: Range<usize>, output: &mut Vec<V>) {
+        let broadcast = match &self.broadcast {
+            Some(broadcast) => broadcast,
+            None => {
+                output.extend(
+                    self.values[range]
+                        .iter()
+                        .map(|value| V::from_primitive(*value)),
+                );
+                return;
+            }
+        };
+
+        // The index of the first element is computed once, the next ones are incremented by
+        // runs along the last dimension.
+        let last = broadcast.dims.len() - 1;
+        let mut index = vec![0; broadcast.dims.len()];
+        let mut remainder = range.start;
+        for (i, dim) in broadcast.dims.iter().enumerate().rev() {
+            index[i] = remainder % dim;
+            remainder /= dim;
+        }
+        let mut offset: usize = index
+            .iter()
+            .zip(broadcast.strides.iter())
+            .map(|(index, stride)| index * stride)
+            .sum();
+        let mut position = range.start;
+
+        while position < range.end {
+            let run = usize::min(broadcast.dims[last] - index[last], range.end - position);
+
+            match broadcast.strides[last] {
+                0 => {
+                    let value = V::from_primitive(self.values[offset]);
+                    output.resize(output.len() + run, value);
+                }
+                _ => output.extend(
+                    self.values[offset..offset + run]
+                        .iter()
+                        .map(|value| V::from_primitive(*value)),
+                ),
+            }
+
+            position += run;
+            index[last] += run;
+            offset += run * broadcast.strides[last];
+
+            for i in (0..=last).rev() {
+                if index[i] < broadcast.dims[i] {
+                    break;
+                }
+
+                offset -= broadcast.strides[i] * broadcast.dims[i];
+                index[i] = 0;
+
+                if i > 0 {
+                    index[i - 1] += 1;
+                    offset += broadcast.strides[i - 1];
+                }
+            }
+        }
+    }
+}
+
+/// Floating point type used to compute the values of a program.
+trait Value:
+    Element + Add<Output = Self> + Sub<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    /// Converts an element without going through [ElementConversion], whose conversions are
+    /// dynamically dispatched.
+    fn from_primitive<E: ToPrimitive>(elem: E) -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, exponent: f32) -> Self;
+    fn erf(self) -> Self;
+}
+
+impl Value for f32 {
+    fn from_primitive<E: ToPrimitive>(elem: E) -> Self {
+        elem.to_f32().unwrap()
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn exp(self) -> Self {
+        f32::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        f32::ln(self)
+    }
+
+    fn powf(self, exponent: f32) -> Self {
+        f32::powf(self, exponent)
+    }
+
+    fn erf(self) -> Self {
+        libm::erff(self)
+    }
+}
+
+impl Value for f64 {
+    fn from_primitive<E: ToPrimitive>(elem: E) -> Self {
+        elem.to_f64().unwrap()
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+
+    fn powf(self, exponent: f32) -> Self {
+        f64::powf(self, exponent as f64)
+    }
+
+    fn erf(self) -> Self {
+        libm::erf(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::f16;
+
+    #[test]
+    fn should_round_half_precision_values_after_each_instruction() {
+        let values = [f16::from_f32(1.0)];
+        let shape = Shape::new([1]);
+        let inputs = [ProgramInput::new(
+            Cow::Borrowed(&values[..]),
+            &shape,
+            &shape,
+        )];
+        let nodes = [
+            Node::Input(0),
+            Node::Unary(Instruction::AddScalar(1e-4), 0),
+            Node::Unary(Instruction::SubScalar(1.0), 1),
+        ];
+
+        let execute = |round| Executor::<f32>::new(&nodes, 2).execute(&inputs, 0..1, round)[0];
+
+        assert_eq!(execute(true), 0.0);
+        assert_ne!(execute(false), 0.0);
+    }
+
+    #[test]
+    fn should_keep_values_used_by_many_nodes() {
+        let values = [1.0f32, 2.0, 3.0];
+        let shape = Shape::new([3]);
+        let inputs = [ProgramInput::new(
+            Cow::Borrowed(&values[..]),
+            &shape,
+            &shape,
+        )];
+        // (x + 1) * exp(x + 1) + (x + 1)
+        let nodes = [
+            Node::Input(0),
+            Node::Unary(Instruction::AddScalar(1.0), 0),
+            Node::Unary(Instruction::Exp, 1),
+            Node::Binary(Instruction::Mul, 1, 2),
+            Node::Binary(Instruction::Add, 3, 1),
+        ];
+        let mut executor = Executor::<f32>::new(&nodes, 4);
+
+        let output = executor.execute(&inputs, 0..3, false).to_vec();
+
+        let expected: Vec<f32> = values
+            .iter()
+            .map(|x| (x + 1.0) * (x + 1.0).exp() + (x + 1.0))
+            .collect();
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::program::{Instruction, Program};
+use burn_tensor::{backend::Backend, Data, Shape};
+use std::sync::{Arc, Mutex};
+
+/// Programs longer than this are executed before being fused with other operations, which
+/// bounds the work duplicated when the same lazy tensor is used by many operations.
+const MAX_INSTRUCTIONS: usize = 64;
+
+#[derive(Debug, Clone)]
+pub struct FusionTensor<B: Backend, const D: usize> {
+    pub shape: Shape<D>,
+    node: Arc<FusionNode<B, D>>,
+}
+
+#[derive(Debug)]
+struct FusionNode<B: Backend, const D: usize> {
+    state: Mutex<FusionState<B, D>>,
+    device: B::Device,
+}
+
+#[derive(Debug)]
+enum FusionState<B: Backend, const D: usize> {
+    Lazy(Program<B, D>),
+    Executed(B::TensorPrimitive<D>),
+}
+
+impl<B: Backend, const D: usize> FusionTensor<B, D> {
+    pub fn from_tensor(tensor: B::TensorPrimitive<D>) -> Self {
+        let shape = *B::shape(&tensor);
+        let device = B::device(&tensor);
+
+        Self::from_state(FusionState::Executed(tensor), shape, device)
+    }
+
+    fn from_state(state: FusionState<B, D>, shape: Shape<D>, device: B::Device) -> Self {
+        let node = FusionNode {
+            state: Mutex::new(state),
+            device,
+        };
+
+        Self {
+            shape,
+            node: Arc::new(node),
+        }
+    }
+
+    /// Returns the tensor of the inner backend, executing the recorded operations if needed.
+    pub fn tensor(&self) -> B::TensorPrimitive<D> {
+        let mut state = self.node.state.lock().unwrap();
+
+        if let FusionState::Lazy(program) = &*state {
+            let tensor = program.execute(self.shape, self.node.device);
+            // The inputs of the program are released once it is executed.
+            *state = FusionState::Executed(tensor);
+        }
+
+        match &*state {
+            FusionState::Executed(tensor) => tensor.clone(),
+            FusionState::Lazy(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the data of the tensor. When no other tensor uses its result, the recorded
+    /// operations write their output directly to the data, without creating a tensor of the inner
+    /// backend only to read it back.
+    pub fn into_data(self) -> Data<B::Elem, D> {
+        let shape = self.shape;
+
+        match Arc::try_unwrap(self.node) {
+            Ok(node) => match node.state.into_inner().unwrap() {
+                FusionState::Lazy(program) => program.execute_data(shape),
+                FusionState::Executed(tensor) => B::into_data(tensor),
+            },
+            Err(node) => B::into_data(Self { shape, node }.tensor()),
+        }
+    }
+
+    pub fn device(&self) -> B::Device {
+        self.node.device
+    }
+
+    /// Returns whether the tensor has operations that are recorded but not executed yet.
+    pub fn is_lazy(&self) -> bool {
+        matches!(*self.node.state.lock().unwrap(), FusionState::Lazy(_))
+    }
+
+    pub fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.node, &other.node)
+    }
+
+    pub fn unary(&self, instruction: Instruction) -> Self {
+        let mut program = self.program();
+        program.unary(instruction);
+
+        Self::from_state(FusionState::Lazy(program), self.shape, self.device())
+    }
+
+    pub fn binary(&self, other: &Self, instruction: Instruction) -> Self {
+        let shape = broadcast_shape(&self.shape, &other.shape);
+        // Broadcasted operands are executed first, so that their operations are computed once
+        // for each of their elements instead of once for each element of the output.
+        let operand = |tensor: &Self| match tensor.shape == shape {
+            true => tensor.program(),
+            false => tensor.executed_program(),
+        };
+
+        let mut program = operand(self);
+        program.binary(operand(other), instruction);
+
+        Self::from_state(FusionState::Lazy(program), shape, self.device())
+    }
+
+    /// Returns the program computing the tensor, which only loads it when it's already
+    /// executed.
+    fn program(&self) -> Program<B, D> {
+        if let FusionState::Lazy(program) = &*self.node.state.lock().unwrap() {
+            if program.len() < MAX_INSTRUCTIONS {
+                return program.clone();
+            }
+        }
+
+        self.executed_program()
+    }
+
+    fn executed_program(&self) -> Program<B, D> {
+        self.tensor();
+        Program::load(self.clone())
+    }
+}
+
+fn broadcast_shape<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>) -> Shape<D> {
+    let mut shape = *lhs;
+
+    for (dim, rhs_dim) in shape.dims.iter_mut().zip(rhs.dims) {
+        match (*dim, rhs_dim) {
+            (lhs_dim, rhs_dim) if lhs_dim == rhs_dim => {}
+            (1, rhs_dim) => *dim = rhs_dim,
+            (_, 1) => {}
+            _ => panic!("Can't broadcast shapes {:?} and {:?}", lhs.dims, rhs.dims),
+        }
+    }
+
+    shape
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FusionState;
+    use crate::FusionBackend;
+    use burn_tensor::{Data, Tensor};
+
+    type TestBackend = FusionBackend<burn_ndarray::NdArrayBackend<f32>>;
+
+    #[test]
+    fn should_record_element_wise_ops_lazily() {
+        let x = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+        let y = x.mul_scalar(2.0).add(&x).exp().log();
+
+        assert!(!x.into_primitive().is_lazy());
+        assert!(y.clone().into_primitive().is_lazy());
+        y.into_data()
+            .assert_approx_eq(&Data::from([[3.0, 6.0], [9.0, 12.0]]), 3);
+    }
+
+    #[test]
+    fn should_execute_before_other_ops() {
+        let x = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+        let y = x.add_scalar(1.0).sum_dim(1);
+
+        assert!(!y.into_primitive().is_lazy());
+    }
+
+    #[test]
+    fn should_record_shared_operations_once() {
+        let x = Tensor::<TestBackend, 1>::from_data(Data::from([0.0, 1.0]));
+        let shared = x.add_scalar(1.0).exp();
+
+        let output = shared.mul(&shared.neg()).into_primitive();
+
+        // Input, AddScalar, Exp, Neg and Mul.
+        match &*output.node.state.lock().unwrap() {
+            FusionState::Lazy(program) => assert_eq!(program.len(), 5),
+            FusionState::Executed(_) => panic!("The output should be lazy"),
+        }
+        Tensor::<TestBackend, 1>::from_primitive(output)
+            .into_data()
+            .assert_approx_eq(&Data::from([-(2.0f32.exp()), -(4.0f32.exp())]), 3);
+    }
+
+    #[test]
+    fn should_broadcast_inputs() {
+        let lhs = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0], [2.0]]));
+        let rhs = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0]]));
+
+        let output = lhs.sub(&rhs).neg();
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 1.0, 2.0], [-1.0, 0.0, 1.0]])
+        );
+    }
+
+    #[test]
+    fn should_bound_the_program_length() {
+        let mut x = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, -1.0]));
+
+        for _ in 0..200 {
+            x = x.mul(&x);
+        }
+
+        assert_eq!(x.into_data(), Data::from([1.0, 1.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shapes_cannot_be_broadcasted() {
+        let lhs = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0]));
+        let rhs = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 3.0]));
+
+        lhs.add(&rhs);
+    }
+}
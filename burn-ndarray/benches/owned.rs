@@ -0,0 +1,72 @@
+use burn_ndarray::NdArrayBackend;
+use burn_tensor::{activation, Distribution, Tensor};
+use std::time::{Duration, Instant};
+
+type B = NdArrayBackend<f32>;
+
+const NUM_RUNS: u32 = 10;
+
+fn main() {
+    let param = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+    let grad = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+    let velocity = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+    let (learning_rate, momentum) = (0.01, 0.9);
+
+    println!(
+        "{:<16} {:>12} {:>12} {:>8}",
+        "step", "borrowed", "owned", "speedup"
+    );
+
+    bench(
+        "sgd",
+        || param.sub(&grad.mul_scalar(learning_rate)),
+        || param.clone() - grad.clone() * learning_rate,
+    );
+    bench(
+        "sgd momentum",
+        || {
+            let velocity = grad.add(&velocity.mul_scalar(momentum));
+            param.sub(&velocity.mul_scalar(learning_rate))
+        },
+        || {
+            let velocity = velocity.clone() * momentum + grad.clone();
+            param.clone() - velocity * learning_rate
+        },
+    );
+    bench(
+        "activations",
+        || {
+            let x = activation::relu(&param.mul_scalar(2.0));
+            x.add_scalar(1.0).log().exp().neg()
+        },
+        || {
+            let x = activation::relu_owned(param.clone() * 2.0);
+            -(x + 1.0).log_owned().exp_owned()
+        },
+    );
+}
+
+fn bench<R>(name: &str, borrowed: impl Fn() -> R, owned: impl Fn() -> R) {
+    let borrowed = time(borrowed);
+    let owned = time(owned);
+
+    println!(
+        "{:<16} {:>12?} {:>12?} {:>7.2}x",
+        name,
+        borrowed,
+        owned,
+        borrowed.as_secs_f64() / owned.as_secs_f64()
+    );
+}
+
+fn time<R>(func: impl Fn() -> R) -> Duration {
+    // Warm up the allocator before timing.
+    func();
+
+    let start = Instant::now();
+    for _ in 0..NUM_RUNS {
+        func();
+    }
+
+    start.elapsed() / NUM_RUNS
+}
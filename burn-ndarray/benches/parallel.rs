@@ -0,0 +1,65 @@
+use burn_ndarray::{set_parallel_options, NdArrayBackend, ParallelOptions};
+use burn_tensor::{backend::Backend, module::embedding, Data, Distribution, Shape, Tensor};
+use std::time::{Duration, Instant};
+
+type B = NdArrayBackend<f32>;
+
+const NUM_RUNS: u32 = 10;
+
+fn main() {
+    let lhs = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+    let rhs = Tensor::<B, 2>::random([1024, 1024], Distribution::Standard);
+    let weights = Tensor::<B, 2>::random([10_000, 512], Distribution::Standard);
+    let indexes = (0..64 * 256).map(|i| (i * 7919) % 10_000).collect();
+    let indexes = Tensor::<<B as Backend>::IntegerBackend, 2>::from_data(Data::new(
+        indexes,
+        Shape::new([64, 256]),
+    ));
+
+    println!(
+        "Number of threads used by the parallel kernels: {}",
+        std::thread::available_parallelism().map_or(1, |num| num.get())
+    );
+    println!(
+        "{:<12} {:>12} {:>12} {:>8}",
+        "op", "serial", "parallel", "speedup"
+    );
+
+    bench("add", || lhs.add(&rhs));
+    bench("exp", || lhs.exp());
+    bench("sum_dim", || lhs.sum_dim(1));
+    bench("mean_dim", || lhs.mean_dim(0));
+    bench("argmax", || lhs.argmax(0));
+    bench("embedding", || embedding(&weights, &indexes));
+}
+
+fn bench<R>(name: &str, func: impl Fn() -> R) {
+    set_parallel_options(ParallelOptions {
+        num_threads: 1,
+        ..Default::default()
+    });
+    let serial = time(&func);
+
+    set_parallel_options(ParallelOptions::default());
+    let parallel = time(&func);
+
+    println!(
+        "{:<12} {:>12?} {:>12?} {:>7.2}x",
+        name,
+        serial,
+        parallel,
+        serial.as_secs_f64() / parallel.as_secs_f64()
+    );
+}
+
+fn time<R>(func: &impl Fn() -> R) -> Duration {
+    // Warm up the thread pool and the allocator before timing.
+    func();
+
+    let start = Instant::now();
+    for _ in 0..NUM_RUNS {
+        func();
+    }
+
+    start.elapsed() / NUM_RUNS
+}
@@ -1,11 +1,16 @@
 use super::element::NdArrayElement;
 use super::NdArrayTensor;
 use burn_tensor::backend::Backend;
-use rand::rngs::StdRng;
-use rand::SeedableRng;
+use burn_tensor::{Generator, RngState};
 use std::sync::Mutex;
 
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+/// The generator of the CPU device, seeded from the operating system unless a seed is set.
+static GENERATOR: Mutex<Option<Generator>> = Mutex::new(None);
+
+pub(crate) fn with_generator<T, F: FnOnce(&mut Generator) -> T>(func: F) -> T {
+    let mut generator = GENERATOR.lock().unwrap();
+    func(generator.get_or_insert_with(Generator::from_entropy))
+}
 
 #[derive(Clone, Copy, Debug)]
 pub enum NdArrayDevice {
@@ -41,8 +46,20 @@ impl<E: NdArrayElement> Backend for NdArrayBackend<E> {
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
-        let mut seed = SEED.lock().unwrap();
-        *seed = Some(rng);
+        let mut generator = GENERATOR.lock().unwrap();
+        *generator = Some(Generator::new(seed));
+    }
+
+    fn fork_rng(_device: NdArrayDevice) -> Generator {
+        with_generator(|generator| generator.fork())
+    }
+
+    fn get_rng_state(_device: NdArrayDevice) -> RngState {
+        with_generator(|generator| generator.state())
+    }
+
+    fn set_rng_state(_device: NdArrayDevice, state: RngState) {
+        let mut generator = GENERATOR.lock().unwrap();
+        *generator = Some(Generator::from_state(&state));
     }
 }
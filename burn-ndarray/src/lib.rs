@@ -1,6 +1,3 @@
-#[macro_use]
-extern crate derive_new;
-
 #[cfg(any(
     feature = "blas-netlib",
     feature = "blas-openblas",
@@ -11,9 +8,11 @@ extern crate blas_src;
 mod backend;
 mod element;
 mod ops;
+mod parallel;
 mod tensor;
 
 pub use backend::*;
+pub use parallel::{parallel_options, set_parallel_options, ParallelOptions};
 pub(crate) use tensor::*;
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
+use crate::parallel::run_parallel;
 use crate::{element::NdArrayElement, tensor::NdArrayTensor, NdArrayBackend};
 use burn_tensor::{ops::*, Shape};
+use ndarray::{Array2, ArrayViewMut1, Ix2, Zip};
 use std::ops::Add;
 
 impl<E: NdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E> {
@@ -10,20 +12,39 @@ impl<E: NdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         let [batch_size, seq_length] = indexes.shape.dims;
         let [_n_embedding, d_model] = weights.shape.dims;
 
-        let mut tensors = Vec::with_capacity(batch_size * seq_length);
-
-        for index in NdArrayBackend::reshape(indexes, Shape::new([batch_size * seq_length]))
+        let weights = weights.array.view().into_dimensionality::<Ix2>().unwrap();
+        let indexes = indexes
             .array
-            .iter()
-        {
-            let index = *index as usize;
-            tensors.push(NdArrayBackend::index(
-                weights,
-                [index..index + 1, 0..d_model],
-            ));
+            .view()
+            .into_shape(batch_size * seq_length)
+            .unwrap();
+        let gather = |parallel: bool| {
+            let mut output = Array2::zeros((batch_size * seq_length, d_model));
+            let zip = Zip::from(output.rows_mut()).and(&indexes);
+            let copy_row =
+                |mut row: ArrayViewMut1<E>, index: &i64| row.assign(&weights.row(*index as usize));
+
+            match parallel {
+                true => zip.par_for_each(copy_row),
+                false => zip.for_each(copy_row),
+            };
+            output
+        };
+
+        let output = run_parallel(
+            batch_size * seq_length * d_model,
+            || gather(false),
+            || gather(true),
+        );
+
+        NdArrayTensor {
+            array: output
+                .into_shape((batch_size, seq_length, d_model))
+                .unwrap()
+                .into_dyn()
+                .into_shared(),
+            shape: Shape::new([batch_size, seq_length, d_model]),
         }
-        let embedding = NdArrayBackend::cat(&tensors, 0);
-        NdArrayBackend::reshape(&embedding, Shape::new([batch_size, seq_length, d_model]))
     }
 
     fn embedding_backward(
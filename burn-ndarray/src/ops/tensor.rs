@@ -1,14 +1,16 @@
 use std::cmp::Ordering;
 use std::ops::Range;
 
+use crate::parallel::{run_parallel, run_parallel_with};
 use crate::tensor::BatchMatrix;
 use crate::{element::NdArrayElement, tensor::NdArrayTensor, NdArrayBackend};
-use crate::{to_nd_array_tensor, NdArrayDevice, SEED};
-use burn_tensor::Distribution;
+use crate::{to_nd_array_tensor, with_generator, NdArrayDevice};
+use burn_tensor::ops::fft_slice;
 use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape};
-use ndarray::{Axis, Dim, IxDyn, SliceInfoElem};
-use rand::rngs::StdRng;
-use rand::SeedableRng;
+use burn_tensor::{Complex32, Distribution, QuantizationScheme, QuantizedData};
+use ndarray::{
+    Array2, ArrayD, ArrayView1, ArrayViewD, Axis, Dim, Ix2, IxDyn, Slice, SliceInfoElem, Zip,
+};
 
 macro_rules! keepdim {
     (
@@ -52,14 +54,7 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         distribution: Distribution<E>,
         device: NdArrayDevice,
     ) -> NdArrayTensor<E, D> {
-        let mut seed = SEED.lock().unwrap();
-        let mut rng: StdRng = match seed.as_ref() {
-            Some(rng) => rng.clone(),
-            None => StdRng::from_entropy(),
-        };
-        let tensor = Self::from_data(Data::random(shape, distribution, &mut rng), device);
-        *seed = Some(rng);
-        tensor
+        with_generator(|generator| Self::random_with(shape, distribution, generator, device))
     }
 
     fn shape<const D: usize>(
@@ -71,17 +66,29 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     fn to_data<const D: usize>(
         tensor: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
     ) -> Data<<NdArrayBackend<E> as Backend>::Elem, D> {
-        let values = tensor.array.iter().map(Clone::clone).collect();
+        let values = match tensor.array.as_slice() {
+            Some(values) => values.to_vec(),
+            None => tensor.array.iter().map(Clone::clone).collect(),
+        };
         Data::new(values, tensor.shape)
     }
 
     fn into_data<const D: usize>(
         tensor: <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
     ) -> Data<<NdArrayBackend<E> as Backend>::Elem, D> {
-        let values = tensor.array.into_iter().collect();
+        let values = match tensor.array.as_slice() {
+            Some(values) => values.to_vec(),
+            None => tensor.array.into_iter().collect(),
+        };
         Data::new(values, tensor.shape)
     }
 
+    fn as_slice<const D: usize>(
+        tensor: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
+    ) -> Option<&[<NdArrayBackend<E> as Backend>::Elem]> {
+        tensor.array.as_slice()
+    }
+
     fn bool_shape<const D: usize>(
         tensor: &<NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> &Shape<D> {
@@ -91,17 +98,27 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     fn bool_to_data<const D: usize>(
         tensor: &<NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> Data<bool, D> {
-        let values = tensor.array.iter().map(Clone::clone).collect();
+        let values = match tensor.array.as_slice() {
+            Some(values) => values.to_vec(),
+            None => tensor.array.iter().map(Clone::clone).collect(),
+        };
         Data::new(values, tensor.shape)
     }
 
     fn bool_into_data<const D: usize>(
         tensor: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> Data<bool, D> {
-        let values = tensor.array.into_iter().collect();
+        let values = match tensor.array.as_slice() {
+            Some(values) => values.to_vec(),
+            None => tensor.array.into_iter().collect(),
+        };
         Data::new(values, tensor.shape)
     }
 
+    fn bool_device<const D: usize>(_tensor: &NdArrayTensor<bool, D>) -> NdArrayDevice {
+        NdArrayDevice::Cpu
+    }
+
     fn bool_to_device<const D: usize>(
         tensor: &NdArrayTensor<bool, D>,
         _device: NdArrayDevice,
@@ -146,88 +163,60 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
-        let array = &lhs.array + &rhs.array;
-        let array = array.into_shared();
-        let shape = lhs.shape.higher(&rhs.shape);
-
-        NdArrayTensor { array, shape }
+        map_elem_pair(lhs, rhs, |lhs, rhs| lhs + rhs)
     }
 
     fn add_scalar<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &E,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
-        let array = &lhs.array + *rhs;
-        let array = array.into_shared();
-        let shape = lhs.shape;
-
-        NdArrayTensor { array, shape }
+        let rhs = *rhs;
+        map_elem(lhs, |lhs| lhs + rhs)
     }
 
     fn sub<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
-        let array = &lhs.array - &rhs.array;
-        let array = array.into_shared();
-        let shape = lhs.shape.higher(&rhs.shape);
-
-        NdArrayTensor { array, shape }
+        map_elem_pair(lhs, rhs, |lhs, rhs| lhs - rhs)
     }
 
     fn sub_scalar<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &E,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
-        let array = &lhs.array - *rhs;
-        let array = array.into_shared();
-        let shape = lhs.shape;
-
-        NdArrayTensor { array, shape }
+        let rhs = *rhs;
+        map_elem(lhs, |lhs| lhs - rhs)
     }
 
     fn mul<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
-        let array = &lhs.array * &rhs.array;
-        let array = array.into_shared();
-        let shape = lhs.shape.higher(&rhs.shape);
-
-        NdArrayTensor { array, shape }
+        map_elem_pair(lhs, rhs, |lhs, rhs| lhs * rhs)
     }
 
     fn mul_scalar<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &E,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
-        let array = &lhs.array * *rhs;
-        let array = array.into_shared();
-        let shape = lhs.shape;
-
-        NdArrayTensor { array, shape }
+        let rhs = *rhs;
+        map_elem(lhs, |lhs| lhs * rhs)
     }
 
     fn div<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
-        let array = &lhs.array / &rhs.array;
-        let array = array.into_shared();
-        let shape = lhs.shape.higher(&rhs.shape);
-
-        NdArrayTensor { array, shape }
+        map_elem_pair(lhs, rhs, |lhs, rhs| lhs / rhs)
     }
 
     fn div_scalar<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &E,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
-        let array = &lhs.array / *rhs;
-        let array = array.into_shared();
-        let shape = lhs.shape;
-
-        NdArrayTensor { array, shape }
+        let rhs = *rhs;
+        map_elem(lhs, |lhs| lhs / rhs)
     }
 
     fn matmul<const D: usize>(
@@ -236,9 +225,99 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
         let batch_self = BatchMatrix::from_ndarray(lhs.array.clone(), lhs.shape);
         let batch_other = BatchMatrix::from_ndarray(rhs.array.clone(), rhs.shape);
-        let output = batch_self.matmul(batch_other);
 
-        NdArrayTensor::from_bmatrix(output)
+        batch_self.matmul(batch_other)
+    }
+
+    fn spmm(
+        indices: &NdArrayTensor<i64, 2>,
+        values: &NdArrayTensor<E, 1>,
+        shape: Shape<2>,
+        dense: &NdArrayTensor<E, 2>,
+    ) -> NdArrayTensor<E, 2> {
+        let [num_rows, _] = shape.dims;
+        let [_, num_cols] = dense.shape.dims;
+
+        let dense_array = dense.array.view().into_dimensionality::<Ix2>().unwrap();
+        let mut output = Array2::<E>::zeros((num_rows, num_cols));
+
+        for (index, value) in indices.array.rows().into_iter().zip(values.array.iter()) {
+            let (row, col) = (index[0] as usize, index[1] as usize);
+            output
+                .row_mut(row)
+                .scaled_add(*value, &dense_array.row(col));
+        }
+
+        NdArrayTensor {
+            array: output.into_shared().into_dyn(),
+            shape: Shape::new([num_rows, num_cols]),
+        }
+    }
+
+    fn quantized_matmul(lhs: &NdArrayTensor<E, 2>, rhs: &QuantizedData<2>) -> NdArrayTensor<E, 2> {
+        let [num_rows, _] = lhs.shape.dims;
+        let [d_inner, num_cols] = rhs.values.shape.dims;
+        let params = &rhs.params;
+
+        // The int8 path requires the parameters of the rhs to be shared along its columns.
+        let (rhs_scale, rhs_zero_point) = match params.scheme {
+            QuantizationScheme::PerTensor => (
+                vec![params.scale[0]; num_cols],
+                vec![params.zero_point[0]; num_cols],
+            ),
+            QuantizationScheme::PerChannel(1) => (params.scale.clone(), params.zero_point.clone()),
+            QuantizationScheme::PerChannel(_) => {
+                let rhs = Self::dequantize(rhs, NdArrayDevice::Cpu);
+                return Self::matmul(lhs, &rhs);
+            }
+        };
+
+        // The lhs is quantized on the fly with a single scale and zero-point.
+        let lhs = QuantizedData::quantize(&Self::to_data(lhs), QuantizationScheme::PerTensor);
+        let lhs_scale = lhs.params.scale[0];
+        let lhs_zero_point = lhs.params.zero_point[0] as i64;
+
+        let lhs = Array2::from_shape_fn((num_rows, d_inner), |(i, k)| {
+            lhs.values.value[i * d_inner + k] as i64 - lhs_zero_point
+        });
+        let rhs = Array2::from_shape_fn((d_inner, num_cols), |(k, j)| {
+            rhs.values.value[k * num_cols + j] as i64 - rhs_zero_point[j] as i64
+        });
+
+        // Accumulated in i64, since each product can reach 2^16 and an i32 sum would silently wrap
+        // for large inner dimensions.
+        let output = lhs.dot(&rhs);
+        let output = Array2::from_shape_fn((num_rows, num_cols), |(i, j)| {
+            (lhs_scale * rhs_scale[j] * output[[i, j]] as f32).to_elem::<E>()
+        });
+
+        NdArrayTensor {
+            array: output.into_shared().into_dyn(),
+            shape: Shape::new([num_rows, num_cols]),
+        }
+    }
+
+    fn fft<const D: usize>(tensor: &NdArrayTensor<E, D>, inverse: bool) -> NdArrayTensor<E, D> {
+        let axis = Axis(D - 1);
+        let size = tensor.shape.dims[D - 1] / 2;
+        let (re, im) = tensor.array.view().split_at(axis, size);
+        let mut values = Zip::from(&re)
+            .and(&im)
+            .map_collect(|re, im| Complex32::new(re.to_elem(), im.to_elem()));
+
+        fft_lanes(&mut values, axis, inverse);
+        pack_complex(values.view(), axis)
+    }
+
+    fn rfft<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let axis = Axis(D - 1);
+        let size = tensor.shape.dims[D - 1];
+        let mut values = tensor
+            .array
+            .map(|value| Complex32::new(value.to_elem(), 0.0));
+
+        fft_lanes(&mut values, axis, false);
+        pack_complex(values.slice_axis(axis, Slice::from(0..size / 2 + 1)), axis)
     }
 
     fn neg<const D: usize>(
@@ -314,16 +393,18 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         mask: &NdArrayTensor<bool, D>,
         value: E,
     ) -> NdArrayTensor<E, D> {
-        let elem = E::default();
-        let mask_mul = mask.array.mapv(|x| match x {
-            true => E::zeros(&elem),
-            false => E::ones(&elem),
-        });
-        let mask_add = mask.array.mapv(|x| match x {
+        // Selecting the values instead of multiplying by the mask keeps the infinite values.
+        let mask = mask.array.broadcast(tensor.array.dim()).unwrap();
+        let select = |elem: &E, masked: &bool| match masked {
             true => value,
-            false => E::zeros(&elem),
-        });
-        let array = (tensor.array.clone() * mask_mul) + mask_add;
+            false => *elem,
+        };
+        let array = run_parallel(
+            tensor.array.len(),
+            || Zip::from(&tensor.array).and(&mask).map_collect(select),
+            || Zip::from(&tensor.array).and(&mask).par_map_collect(select),
+        )
+        .into_shared();
 
         NdArrayTensor {
             array,
@@ -342,12 +423,8 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     }
 
     fn equal_scalar<const D: usize>(lhs: &NdArrayTensor<E, D>, rhs: &E) -> NdArrayTensor<bool, D> {
-        let array = lhs.array.mapv(|a| a == *rhs).into_shared();
-
-        NdArrayTensor {
-            shape: lhs.shape,
-            array,
-        }
+        let rhs = *rhs;
+        map_elem(lhs, |lhs| lhs == rhs)
     }
 
     fn greater<const D: usize>(
@@ -363,12 +440,8 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         lhs: &NdArrayTensor<E, D>,
         rhs: &E,
     ) -> NdArrayTensor<bool, D> {
-        let array = lhs.array.mapv(|a| a > *rhs).into_shared();
-
-        NdArrayTensor {
-            shape: lhs.shape,
-            array,
-        }
+        let rhs = *rhs;
+        map_elem(lhs, |lhs| lhs > rhs)
     }
 
     fn greater_equal<const D: usize>(
@@ -384,12 +457,8 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         lhs: &NdArrayTensor<E, D>,
         rhs: &E,
     ) -> NdArrayTensor<bool, D> {
-        let array = lhs.array.mapv(|a| a >= *rhs).into_shared();
-
-        NdArrayTensor {
-            shape: lhs.shape,
-            array,
-        }
+        let rhs = *rhs;
+        map_elem(lhs, |lhs| lhs >= rhs)
     }
 
     fn lower<const D: usize>(
@@ -402,12 +471,8 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     }
 
     fn lower_scalar<const D: usize>(lhs: &NdArrayTensor<E, D>, rhs: &E) -> NdArrayTensor<bool, D> {
-        let array = lhs.array.mapv(|a| a < *rhs).into_shared();
-
-        NdArrayTensor {
-            shape: lhs.shape,
-            array,
-        }
+        let rhs = *rhs;
+        map_elem(lhs, |lhs| lhs < rhs)
     }
 
     fn lower_equal<const D: usize>(
@@ -423,12 +488,8 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         lhs: &NdArrayTensor<E, D>,
         rhs: &E,
     ) -> NdArrayTensor<bool, D> {
-        let array = lhs.array.mapv(|a| a <= *rhs).into_shared();
-
-        NdArrayTensor {
-            shape: lhs.shape,
-            array,
-        }
+        let rhs = *rhs;
+        map_elem(lhs, |lhs| lhs <= rhs)
     }
 
     fn detach<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
@@ -470,21 +531,11 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     }
 
     fn to_full_precision<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<f32, D> {
-        let array = tensor.array.mapv(|a| a.to_elem()).into_shared();
-
-        NdArrayTensor {
-            shape: tensor.shape,
-            array,
-        }
+        map_elem(tensor, |a| a.to_elem())
     }
 
     fn from_full_precision<const D: usize>(tensor: &NdArrayTensor<f32, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv(|a| a.to_elem()).into_shared();
-
-        NdArrayTensor {
-            shape: tensor.shape,
-            array,
-        }
+        map_elem(tensor, |a| a.to_elem())
     }
 
     fn argmax<const D: usize>(tensor: &NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<i64, D> {
@@ -496,39 +547,24 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     }
 
     fn exp<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv(|a| a.exp_elem()).into_shared();
-        let shape = tensor.shape;
-
-        NdArrayTensor { array, shape }
+        map_elem(tensor, |a| a.exp_elem())
     }
 
     fn log<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv(|a| a.log_elem()).into_shared();
-        let shape = tensor.shape;
-
-        NdArrayTensor { array, shape }
+        map_elem(tensor, |a| a.log_elem())
     }
 
     fn powf<const D: usize>(tensor: &NdArrayTensor<E, D>, value: f32) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv(|a| a.pow_elem(value)).into_shared();
-        let shape = tensor.shape;
-
-        NdArrayTensor { array, shape }
+        map_elem(tensor, |a| a.pow_elem(value))
     }
 
     fn erf<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor
-            .array
-            .mapv(|a| libm::erf(a.to_f64().unwrap()).to_elem())
-            .into_shared();
-        let shape = tensor.shape;
-
-        NdArrayTensor { array, shape }
+        map_elem(tensor, |a| libm::erf(a.to_f64().unwrap()).to_elem())
     }
 
     fn cat<const D: usize>(tensors: &[NdArrayTensor<E, D>], dim: usize) -> NdArrayTensor<E, D> {
         let mut shape = tensors.get(0).unwrap().shape;
-        shape.dims[dim] = tensors.len();
+        shape.dims[dim] = tensors.iter().map(|t| t.shape.dims[dim]).sum();
 
         let arrays: Vec<ndarray::ArrayView<E, IxDyn>> =
             tensors.iter().map(|t| t.array.view()).collect();
@@ -545,6 +581,83 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
 
         Self::mask_fill(tensor, &mask, zero)
     }
+
+    fn add_owned<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        map_elem_pair_owned(lhs, rhs, |lhs, rhs| lhs + rhs)
+    }
+
+    fn add_scalar_owned<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: &E) -> NdArrayTensor<E, D> {
+        let rhs = *rhs;
+        map_elem_owned(lhs, |lhs| lhs + rhs)
+    }
+
+    fn sub_owned<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        map_elem_pair_owned(lhs, rhs, |lhs, rhs| lhs - rhs)
+    }
+
+    fn sub_scalar_owned<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: &E) -> NdArrayTensor<E, D> {
+        let rhs = *rhs;
+        map_elem_owned(lhs, |lhs| lhs - rhs)
+    }
+
+    fn mul_owned<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        map_elem_pair_owned(lhs, rhs, |lhs, rhs| lhs * rhs)
+    }
+
+    fn mul_scalar_owned<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: &E) -> NdArrayTensor<E, D> {
+        let rhs = *rhs;
+        map_elem_owned(lhs, |lhs| lhs * rhs)
+    }
+
+    fn div_owned<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        map_elem_pair_owned(lhs, rhs, |lhs, rhs| lhs / rhs)
+    }
+
+    fn div_scalar_owned<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: &E) -> NdArrayTensor<E, D> {
+        let rhs = *rhs;
+        map_elem_owned(lhs, |lhs| lhs / rhs)
+    }
+
+    fn neg_owned<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let minus_one = (-1f32).to_elem::<E>();
+        map_elem_owned(tensor, |a| a * minus_one)
+    }
+
+    fn exp_owned<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        map_elem_owned(tensor, |a| a.exp_elem())
+    }
+
+    fn log_owned<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        map_elem_owned(tensor, |a| a.log_elem())
+    }
+
+    fn powf_owned<const D: usize>(tensor: NdArrayTensor<E, D>, value: f32) -> NdArrayTensor<E, D> {
+        map_elem_owned(tensor, |a| a.pow_elem(value))
+    }
+
+    fn erf_owned<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        map_elem_owned(tensor, |a| libm::erf(a.to_f64().unwrap()).to_elem())
+    }
+
+    fn relu_owned<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let zero = 0.to_elem();
+        map_elem_owned(tensor, |a| match a <= zero {
+            true => zero,
+            false => a,
+        })
+    }
 }
 
 fn to_slice_args<const D1: usize, const D2: usize>(
@@ -573,7 +686,8 @@ fn mean_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
     tensor: &NdArrayTensor<E, D1>,
     dim: usize,
 ) -> NdArrayTensor<E, D2> {
-    let array = tensor.array.mean_axis(Axis(dim)).unwrap().into_shared();
+    let size: E = (tensor.shape.dims[dim] as i64).to_elem();
+    let array = reduce_lanes(tensor, dim, |lane| lane.sum() / size).into_shared();
     let shape = tensor.shape.remove_dim(dim);
 
     NdArrayTensor { array, shape }
@@ -583,7 +697,7 @@ fn sum_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
     tensor: &NdArrayTensor<E, D1>,
     dim: usize,
 ) -> NdArrayTensor<E, D2> {
-    let array = tensor.array.sum_axis(Axis(dim)).into_shared();
+    let array = reduce_lanes(tensor, dim, |lane| lane.sum()).into_shared();
     let shape = tensor.shape.remove_dim(dim);
 
     NdArrayTensor { array, shape }
@@ -595,38 +709,31 @@ fn arg<E: NdArrayElement, F, const D: usize>(
     cmp: F,
 ) -> NdArrayTensor<i64, D>
 where
-    F: Fn(&f64, &f64) -> Ordering,
+    F: Fn(&f64, &f64) -> Ordering + Send + Sync,
 {
-    let batch_size = tensor.shape.dims[dim];
-
-    let mut data = NdArrayBackend::to_data::<D>(tensor);
-    let mut start = 0;
-    let mut end = tensor.shape.dims[dim];
-    let mut output = Vec::new();
-
-    while end <= data.value.len() {
-        let data_dim = &mut data.value[start..end];
-        let mut sorted: Vec<f64> = data_dim.iter().map(|a| a.to_elem()).collect();
-        sorted.sort_by(&cmp);
-
-        let max = sorted[0];
-
-        let data_dim = &mut data.value[start..end];
-        let mut index: i64 = 0;
-        for elem in data_dim {
-            let as_float: f64 = elem.to_elem();
-            if as_float == max {
-                break;
+    // The first index is kept when several elements are equal.
+    let array = reduce_lanes(tensor, dim, |lane| {
+        let mut index = 0;
+        let mut best: f64 = lane[0].to_elem();
+
+        for (i, elem) in lane.iter().enumerate().skip(1) {
+            let elem: f64 = elem.to_elem();
+            if cmp(&elem, &best) == Ordering::Less {
+                best = elem;
+                index = i;
             }
-            index += 1;
         }
-        output.push(index);
-        start += batch_size;
-        end += batch_size;
-    }
+
+        index as i64
+    });
+
     let mut shape = tensor.shape;
     shape.dims[dim] = 1;
-    NdArrayTensor::from_data(Data::new(output, shape))
+
+    NdArrayTensor {
+        array: array.insert_axis(Axis(dim)).into_shared(),
+        shape,
+    }
 }
 
 fn cmp_max(a: &f64, b: &f64) -> Ordering {
@@ -646,3 +753,221 @@ fn cmp_min(a: &f64, b: &f64) -> Ordering {
     }
     Ordering::Equal
 }
+
+fn fft_lanes(values: &mut ArrayD<Complex32>, axis: Axis, inverse: bool) {
+    for mut lane in values.lanes_mut(axis) {
+        match lane.as_slice_mut() {
+            Some(lane) => fft_slice(lane, inverse),
+            None => {
+                let mut buffer = lane.to_vec();
+                fft_slice(&mut buffer, inverse);
+                lane.assign(&ArrayView1::from(&buffer));
+            }
+        }
+    }
+}
+
+/// Concatenate the real parts followed by the imaginary parts along the axis.
+fn pack_complex<E: NdArrayElement, const D: usize>(
+    values: ArrayViewD<Complex32>,
+    axis: Axis,
+) -> NdArrayTensor<E, D> {
+    let re = values.map(|value| value.re.to_elem::<E>());
+    let im = values.map(|value| value.im.to_elem::<E>());
+    let array = ndarray::concatenate(axis, &[re.view(), im.view()]).unwrap();
+    let shape = Shape::new(array.shape().try_into().unwrap());
+
+    NdArrayTensor {
+        array: array.into_shared(),
+        shape,
+    }
+}
+
+/// Applies the function on each element, in parallel for large tensors.
+fn map_elem<E, O, F, const D: usize>(tensor: &NdArrayTensor<E, D>, func: F) -> NdArrayTensor<O, D>
+where
+    E: Copy + Send + Sync,
+    O: Copy + Send,
+    F: Fn(E) -> O + Send + Sync,
+{
+    let array = run_parallel(
+        tensor.array.len(),
+        || tensor.array.mapv(&func),
+        || Zip::from(&tensor.array).par_map_collect(|elem| func(*elem)),
+    );
+
+    NdArrayTensor {
+        array: array.into_shared(),
+        shape: tensor.shape,
+    }
+}
+
+/// Same as [map_elem], but reuses the memory of the tensor when it isn't shared.
+fn map_elem_owned<E, F, const D: usize>(tensor: NdArrayTensor<E, D>, func: F) -> NdArrayTensor<E, D>
+where
+    E: NdArrayElement,
+    F: Fn(E) -> E + Send + Sync,
+{
+    let shape = tensor.shape;
+
+    match tensor.array.try_into_owned_nocopy() {
+        Ok(array) => {
+            let array = run_parallel_with(
+                array.len(),
+                array,
+                |mut array| {
+                    array.mapv_inplace(&func);
+                    array
+                },
+                |mut array| {
+                    array.par_mapv_inplace(&func);
+                    array
+                },
+            );
+
+            NdArrayTensor {
+                array: array.into_shared(),
+                shape,
+            }
+        }
+        Err(array) => map_elem(&NdArrayTensor { array, shape }, func),
+    }
+}
+
+/// Applies the function on each pair of elements after broadcasting the tensors to the same
+/// shape, in parallel for large tensors.
+fn map_elem_pair<E, F, const D: usize>(
+    lhs: &NdArrayTensor<E, D>,
+    rhs: &NdArrayTensor<E, D>,
+    func: F,
+) -> NdArrayTensor<E, D>
+where
+    E: NdArrayElement,
+    F: Fn(E, E) -> E + Send + Sync,
+{
+    let shape = broadcast_shape(&lhs.shape, &rhs.shape);
+    let (lhs, rhs) = match (
+        lhs.array.broadcast(shape.dims.as_slice()),
+        rhs.array.broadcast(shape.dims.as_slice()),
+    ) {
+        (Some(lhs), Some(rhs)) => (lhs, rhs),
+        _ => panic!(
+            "Can't broadcast shapes {:?} and {:?}",
+            lhs.shape.dims, rhs.shape.dims
+        ),
+    };
+    let func = |lhs: &E, rhs: &E| func(*lhs, *rhs);
+
+    let array = run_parallel(
+        shape.num_elements(),
+        || Zip::from(&lhs).and(&rhs).map_collect(func),
+        || Zip::from(&lhs).and(&rhs).par_map_collect(func),
+    );
+
+    NdArrayTensor {
+        array: array.into_shared(),
+        shape,
+    }
+}
+
+/// Returns the shape of the tensors broadcasted together, where a dimension of size one takes the
+/// size of the other tensor.
+fn broadcast_shape<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>) -> Shape<D> {
+    let mut shape = *lhs;
+    for (dim, rhs_dim) in shape.dims.iter_mut().zip(rhs.dims) {
+        if *dim == 1 {
+            *dim = rhs_dim;
+        }
+    }
+
+    shape
+}
+
+/// Same as [map_elem_pair], but writes the output into the memory of one of the tensors when it
+/// isn't shared and already has the broadcasted shape.
+fn map_elem_pair_owned<E, F, const D: usize>(
+    lhs: NdArrayTensor<E, D>,
+    rhs: NdArrayTensor<E, D>,
+    func: F,
+) -> NdArrayTensor<E, D>
+where
+    E: NdArrayElement,
+    F: Fn(E, E) -> E + Send + Sync,
+{
+    let shape = broadcast_shape(&lhs.shape, &rhs.shape);
+
+    let lhs = match lhs.shape == shape {
+        true => match lhs.array.try_into_owned_nocopy() {
+            Ok(array) => return assign_elem_pair(array, &rhs, shape, func),
+            Err(array) => NdArrayTensor { array, shape },
+        },
+        false => lhs,
+    };
+    let rhs = match rhs.shape == shape {
+        true => match rhs.array.try_into_owned_nocopy() {
+            Ok(array) => {
+                return assign_elem_pair(array, &lhs, shape, |rhs, lhs| func(lhs, rhs));
+            }
+            Err(array) => NdArrayTensor { array, shape },
+        },
+        false => rhs,
+    };
+
+    map_elem_pair(&lhs, &rhs, func)
+}
+
+/// Assigns to each element of the output the function applied on itself and the element of
+/// the broadcasted tensor.
+fn assign_elem_pair<E, F, const D: usize>(
+    mut output: ArrayD<E>,
+    tensor: &NdArrayTensor<E, D>,
+    shape: Shape<D>,
+    func: F,
+) -> NdArrayTensor<E, D>
+where
+    E: NdArrayElement,
+    F: Fn(E, E) -> E + Send + Sync,
+{
+    let array = tensor
+        .array
+        .broadcast(shape.dims.as_slice())
+        .unwrap_or_else(|| {
+            panic!(
+                "Can't broadcast shapes {:?} and {:?}",
+                shape.dims, tensor.shape.dims
+            )
+        });
+    let func = |output: &mut E, elem: &E| *output = func(*output, *elem);
+
+    run_parallel_with(
+        shape.num_elements(),
+        &mut output,
+        |output| Zip::from(output).and(&array).for_each(func),
+        |output| Zip::from(output).and(&array).par_for_each(func),
+    );
+
+    NdArrayTensor {
+        array: output.into_shared(),
+        shape,
+    }
+}
+
+/// Reduces each lane along the dimension to a single value, in parallel for large tensors.
+fn reduce_lanes<E, O, F, const D: usize>(
+    tensor: &NdArrayTensor<E, D>,
+    dim: usize,
+    func: F,
+) -> ArrayD<O>
+where
+    E: NdArrayElement,
+    O: Copy + Send,
+    F: Fn(ArrayView1<E>) -> O + Send + Sync,
+{
+    let lanes = tensor.array.lanes(Axis(dim));
+
+    run_parallel(
+        tensor.array.len(),
+        || Zip::from(lanes.clone()).map_collect(&func),
+        || Zip::from(lanes.clone()).par_map_collect(&func),
+    )
+}
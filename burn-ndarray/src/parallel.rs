@@ -0,0 +1,150 @@
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::{Arc, RwLock};
+
+static PARALLEL_OPTIONS: RwLock<ParallelOptions> = RwLock::new(ParallelOptions::const_default());
+// The pool is stored with the number of threads it was requested with, so that it is built again
+// under the lock when the options change, even while another thread is using the old pool.
+static THREAD_POOL: RwLock<Option<(usize, Arc<ThreadPool>)>> = RwLock::new(None);
+
+/// Options used to parallelize the kernels of the ndarray backend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParallelOptions {
+    /// Number of threads used by the kernels, `0` uses one thread per core and `1` disables
+    /// parallelism.
+    pub num_threads: usize,
+    /// Number of elements below which the kernels stay serial, since distributing small
+    /// workloads costs more than it saves.
+    pub threshold: usize,
+}
+
+impl ParallelOptions {
+    const fn const_default() -> Self {
+        Self {
+            num_threads: 0,
+            threshold: 1 << 15,
+        }
+    }
+}
+
+impl Default for ParallelOptions {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Set the options used to parallelize the kernels of the ndarray backend.
+///
+/// The kernels already running keep the options they started with, the following ones use the
+/// new options.
+pub fn set_parallel_options(options: ParallelOptions) {
+    *PARALLEL_OPTIONS.write().unwrap() = options;
+}
+
+/// Returns the options used to parallelize the kernels of the ndarray backend.
+pub fn parallel_options() -> ParallelOptions {
+    *PARALLEL_OPTIONS.read().unwrap()
+}
+
+/// Run the parallel kernel on the thread pool when the number of elements reaches the
+/// threshold, otherwise run the serial kernel on the current thread.
+pub(crate) fn run_parallel<R, S, P>(num_elements: usize, serial: S, parallel: P) -> R
+where
+    R: Send,
+    S: FnOnce() -> R,
+    P: FnOnce() -> R + Send,
+{
+    run_parallel_with(num_elements, (), |_| serial(), |_| parallel())
+}
+
+/// Same as [run_parallel], but moves the input into the kernel that runs.
+pub(crate) fn run_parallel_with<T, R, S, P>(
+    num_elements: usize,
+    input: T,
+    serial: S,
+    parallel: P,
+) -> R
+where
+    T: Send,
+    R: Send,
+    S: FnOnce(T) -> R,
+    P: FnOnce(T) -> R + Send,
+{
+    let options = parallel_options();
+
+    if options.num_threads == 1 || num_elements < options.threshold {
+        return serial(input);
+    }
+
+    thread_pool(options.num_threads).install(|| parallel(input))
+}
+
+fn thread_pool(num_threads: usize) -> Arc<ThreadPool> {
+    if let Some((num_threads_pool, pool)) = THREAD_POOL.read().unwrap().as_ref() {
+        if *num_threads_pool == num_threads {
+            return pool.clone();
+        }
+    }
+
+    let mut current = THREAD_POOL.write().unwrap();
+
+    // Another thread may have built the pool while waiting for the lock.
+    if let Some((num_threads_pool, pool)) = current.as_ref() {
+        if *num_threads_pool == num_threads {
+            return pool.clone();
+        }
+    }
+
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(|index| format!("burn-ndarray-{}", index))
+        .build()
+        .unwrap();
+    let pool = Arc::new(pool);
+    *current = Some((num_threads, pool.clone()));
+
+    pool
+}
+
+#[cfg(test)]
+mod tests {
+    use super::thread_pool;
+    use crate::NdArrayBackend;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    type TestTensor<const D: usize> = Tensor<NdArrayBackend<f32>, D>;
+
+    #[test]
+    fn should_match_serial_results_above_threshold() {
+        let [rows, cols] = [256, 512];
+        let values: Vec<f32> = (0..rows * cols).map(|i| (i % 7) as f32).collect();
+        let tensor =
+            TestTensor::<2>::from_data(Data::new(values.clone(), Shape::new([rows, cols])));
+
+        let sum = tensor.add(&tensor).sum_dim(1).to_data();
+        let argmax = tensor.argmax(1).to_data();
+
+        let sum_expected: Vec<f32> = values
+            .chunks(cols)
+            .map(|row| 2.0 * row.iter().sum::<f32>())
+            .collect();
+        let argmax_expected: Vec<i64> = values
+            .chunks(cols)
+            .map(|row| {
+                let max = row.iter().cloned().fold(f32::MIN, f32::max);
+                row.iter().position(|value| *value == max).unwrap() as i64
+            })
+            .collect();
+        assert_eq!(sum, Data::new(sum_expected, Shape::new([rows, 1])));
+        assert_eq!(argmax, Data::new(argmax_expected, Shape::new([rows, 1])));
+    }
+
+    #[test]
+    fn should_build_the_thread_pool_again_when_the_number_of_threads_changes() {
+        let pool = thread_pool(2);
+        let pool_other = thread_pool(3);
+
+        assert_eq!(pool.current_num_threads(), 2);
+        assert_eq!(pool_other.current_num_threads(), 3);
+        assert_eq!(thread_pool(3).current_num_threads(), 3);
+    }
+}
@@ -1,6 +1,9 @@
 use super::{element::NdArrayElement, NdArrayBackend};
-use burn_tensor::{ops::TensorOps, Data, Shape};
-use ndarray::{s, ArcArray, Array, Axis, Dim, Ix2, Ix3, IxDyn};
+use crate::parallel::run_parallel;
+use burn_tensor::{ops::TensorOps, Data, ElementValue, Shape};
+use ndarray::linalg::general_mat_mul;
+use ndarray::parallel::prelude::*;
+use ndarray::{ArcArray, Array, Array3, ArrayView2, ArrayViewMut2, Axis, Dim, IxDyn};
 
 #[derive(Debug, Clone)]
 pub struct NdArrayTensor<E, const D: usize> {
@@ -34,10 +37,13 @@ mod utils {
     }
 }
 
-#[derive(new)]
+/// Matrices batched along the leading dimensions of a tensor.
+///
+/// Each matrix is accessed through a strided view of the original array, so no data is copied
+/// before the multiplication, even when the array isn't contiguous.
 pub(crate) struct BatchMatrix<E, const D: usize> {
-    pub arrays: Vec<ArcArray<E, Ix2>>,
-    pub shape: Shape<D>,
+    array: ArcArray<E, IxDyn>,
+    shape: Shape<D>,
 }
 
 impl<E, const D: usize> BatchMatrix<E, D>
@@ -45,85 +51,108 @@ where
     E: NdArrayElement,
 {
     pub fn from_ndarray(array: ArcArray<E, IxDyn>, shape: Shape<D>) -> Self {
-        let mut arrays = Vec::new();
-        if D < 2 {
-            let array = array.reshape((1, shape.dims[0]));
-            arrays.push(array);
-        } else {
-            let batch_size = batch_size(&shape);
-            let size0 = shape.dims[D - 2];
-            let size1 = shape.dims[D - 1];
-            let array_global = array.reshape((batch_size, size0, size1));
-            for b in 0..batch_size {
-                let array = array_global.slice(s!(b, .., ..));
-                let array = array.into_owned().into_shared();
-                arrays.push(array);
-            }
-        }
+        // A vector is multiplied as a matrix with a single row.
+        let array = match D {
+            1 => array.insert_axis(Axis(0)),
+            _ => array,
+        };
 
-        Self { arrays, shape }
+        Self { array, shape }
     }
 
-    pub fn matmul(self, other: BatchMatrix<E, D>) -> Self {
-        let require_broadcast = self.arrays.len() != other.arrays.len();
-        if require_broadcast {
-            return self.matmul_broadcast(other);
-        }
+    pub fn matmul(self, other: BatchMatrix<E, D>) -> NdArrayTensor<E, D> {
+        let batch_dims = broadcast_batch_dims(self.batch_dims(), other.batch_dims())
+            .unwrap_or_else(|| panic!("Invalid broadcast => {:?} , {:?}", self.shape, other.shape));
+        let batch_size = batch_dims.iter().product::<usize>();
+        let [num_rows, size] = self.matrix_dims();
+        let [_, num_cols] = other.matrix_dims();
+
+        let multiply = |(batch, mut output): (usize, ArrayViewMut2<E>)| {
+            let index = unravel_index(batch, &batch_dims);
+            let lhs = self.matrix(&index);
+            let rhs = other.matrix(&index);
+
+            general_mat_mul(
+                ElementValue::one(),
+                &lhs,
+                &rhs,
+                ElementValue::zero(),
+                &mut output,
+            );
+        };
+        let compute = |parallel: bool| {
+            let mut output = Array3::zeros((batch_size, num_rows, num_cols));
+            let matrices = output.axis_iter_mut(Axis(0));
 
-        let self_iter = self.arrays.iter();
-        let other_iter = other.arrays.iter();
+            match parallel {
+                true => matrices.into_par_iter().enumerate().for_each(multiply),
+                false => matrices.enumerate().for_each(multiply),
+            };
+            output
+        };
 
-        let arrays = self_iter
-            .zip(other_iter)
-            .map(|(lhs, rhs)| lhs.dot(rhs))
-            .map(|output| output.into_shared())
-            .collect();
+        let output = run_parallel(
+            batch_size * num_rows * num_cols * size,
+            || compute(false),
+            || compute(true),
+        );
 
         let mut shape = self.shape;
-        shape.dims[D - 1] = other.shape.dims[D - 1];
+        shape.dims[..batch_dims.len()].copy_from_slice(&batch_dims);
+        shape.dims[D - 1] = num_cols;
 
-        Self::new(arrays, shape)
+        NdArrayTensor {
+            array: output.into_shape(IxDyn(&shape.dims)).unwrap().into_shared(),
+            shape,
+        }
     }
 
-    fn matmul_broadcast(self, other: BatchMatrix<E, D>) -> Self {
-        let valid_broadcast = self.arrays.len() == 1 || other.arrays.len() == 1;
-        if !valid_broadcast {
-            panic!("Invalid broadcast => {:?} , {:?}", self.shape, other.shape);
-        }
-        let batch_size = usize::max(self.arrays.len(), other.arrays.len());
-        let mut arrays = Vec::with_capacity(batch_size);
-
-        for batch in 0..batch_size {
-            let self_tensor = if self.arrays.len() == 1 {
-                &self.arrays[0]
-            } else {
-                &self.arrays[batch]
-            };
+    fn batch_dims(&self) -> &[usize] {
+        &self.array.shape()[..self.array.ndim() - 2]
+    }
 
-            let other_tensor = if other.arrays.len() == 1 {
-                &other.arrays[0]
-            } else {
-                &other.arrays[batch]
-            };
+    fn matrix_dims(&self) -> [usize; 2] {
+        let dims = self.array.shape();
+        [dims[dims.len() - 2], dims[dims.len() - 1]]
+    }
 
-            let tensor = self_tensor.dot(other_tensor);
-            arrays.push(tensor.into_shared());
-        }
+    /// Returns a view of the matrix at the given batch index, broadcasting the batch dimensions
+    /// of size one.
+    fn matrix(&self, index: &[usize]) -> ArrayView2<'_, E> {
+        let mut view = self.array.view();
 
-        let mut shape = self.shape;
-        shape.dims[D - 1] = other.shape.dims[D - 1];
+        for (size, index) in self.batch_dims().iter().zip(index) {
+            let index = match size {
+                1 => 0,
+                _ => *index,
+            };
+            view = view.index_axis_move(Axis(0), index);
+        }
 
-        Self::new(arrays, shape)
+        view.into_dimensionality().unwrap()
     }
 }
 
-fn batch_size<const D: usize>(shape: &Shape<D>) -> usize {
-    let mut num_batch = 1;
-    for i in 0..D - 2 {
-        num_batch *= shape.dims[i];
+fn broadcast_batch_dims(lhs: &[usize], rhs: &[usize]) -> Option<Vec<usize>> {
+    lhs.iter()
+        .zip(rhs)
+        .map(|(lhs, rhs)| match (lhs, rhs) {
+            (lhs, rhs) if lhs == rhs => Some(*lhs),
+            (1, size) | (size, 1) => Some(*size),
+            _ => None,
+        })
+        .collect()
+}
+
+fn unravel_index(mut index: usize, dims: &[usize]) -> Vec<usize> {
+    let mut indices = vec![0; dims.len()];
+
+    for (i, size) in dims.iter().enumerate().rev() {
+        indices[i] = index % size;
+        index /= size;
     }
 
-    num_batch
+    indices
 }
 
 #[macro_export(local_inner_macros)]
@@ -175,41 +204,13 @@ macro_rules! to_nd_array_tensor {
     }};
 }
 
-impl<E, const D: usize> NdArrayTensor<E, D>
-where
-    E: Default + Clone,
-{
-    pub(crate) fn from_bmatrix(bmatrix: BatchMatrix<E, D>) -> NdArrayTensor<E, D> {
-        let shape = bmatrix.shape;
-        let to_array = |data: BatchMatrix<E, D>| {
-            let dims = data.shape.dims;
-            let mut array: Array<E, Ix3> = Array::default((0, dims[D - 2], dims[D - 1]));
-
-            for item in data.arrays {
-                array.push(Axis(0), item.view()).unwrap();
-            }
-
-            array.into_shared()
-        };
-
-        match D {
-            1 => to_nd_array_tensor!(1, shape, to_array(bmatrix)),
-            2 => to_nd_array_tensor!(2, shape, to_array(bmatrix)),
-            3 => to_nd_array_tensor!(3, shape, to_array(bmatrix)),
-            4 => to_nd_array_tensor!(4, shape, to_array(bmatrix)),
-            5 => to_nd_array_tensor!(5, shape, to_array(bmatrix)),
-            6 => to_nd_array_tensor!(6, shape, to_array(bmatrix)),
-            _ => panic!(""),
-        }
-    }
-}
 impl<E, const D: usize> NdArrayTensor<E, D>
 where
     E: Default + Clone,
 {
     pub fn from_data(data: Data<E, D>) -> NdArrayTensor<E, D> {
         let shape = data.shape;
-        let to_array = |data: Data<E, D>| Array::from_iter(data.value.into_iter()).into_shared();
+        let to_array = |data: Data<E, D>| Array::from_vec(data.value).into_shared();
 
         match D {
             1 => to_nd_array_tensor!(1, shape, to_array(data)),
@@ -227,14 +228,49 @@ where
 mod tests {
     use super::*;
     use burn_tensor::Distribution;
-    use rand::{rngs::StdRng, SeedableRng};
+    use burn_tensor::Generator;
+
+    #[test]
+    fn should_reuse_memory_of_unshared_tensors() {
+        let lhs = NdArrayTensor::<f32, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let rhs = NdArrayTensor::<f32, 2>::from_data(Data::from([[1.0, 1.0]]));
+        let ptr = lhs.array.as_ptr();
+
+        let output = NdArrayBackend::exp_owned(NdArrayBackend::sub_owned(lhs, rhs));
+
+        assert_eq!(output.array.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn should_copy_shared_tensors() {
+        let lhs = NdArrayTensor::<f32, 1>::from_data(Data::from([1.0, 2.0]));
+
+        let output = NdArrayBackend::mul_scalar_owned(lhs.clone(), &2.0);
+
+        assert_ne!(output.array.as_ptr(), lhs.array.as_ptr());
+        assert_eq!(lhs.into_data(), Data::from([1.0, 2.0]));
+    }
+
+    #[test]
+    fn should_keep_infinite_values_outside_the_mask() {
+        let tensor = NdArrayTensor::<f32, 2>::from_data(Data::from([
+            [f32::NEG_INFINITY, 1.0],
+            [f32::INFINITY, 2.0],
+        ]));
+        let mask = NdArrayTensor::<bool, 2>::from_data(Data::from([[false, true], [true, false]]));
+
+        let output = NdArrayBackend::mask_fill(&tensor, &mask, 0.0);
+
+        let data_expected = Data::from([[f32::NEG_INFINITY, 0.0], [0.0, 2.0]]);
+        assert_eq!(output.into_data(), data_expected);
+    }
 
     #[test]
     fn should_support_into_and_from_data_1d() {
         let data_expected = Data::<f32, 1>::random(
             Shape::new([3]),
             Distribution::Standard,
-            &mut StdRng::from_entropy(),
+            &mut Generator::from_entropy(),
         );
         let tensor = NdArrayTensor::from_data(data_expected.clone());
 
@@ -248,7 +284,7 @@ mod tests {
         let data_expected = Data::<f32, 2>::random(
             Shape::new([2, 3]),
             Distribution::Standard,
-            &mut StdRng::from_entropy(),
+            &mut Generator::from_entropy(),
         );
         let tensor = NdArrayTensor::from_data(data_expected.clone());
 
@@ -262,7 +298,7 @@ mod tests {
         let data_expected = Data::<f32, 3>::random(
             Shape::new([2, 3, 4]),
             Distribution::Standard,
-            &mut StdRng::from_entropy(),
+            &mut Generator::from_entropy(),
         );
         let tensor = NdArrayTensor::from_data(data_expected.clone());
 
@@ -276,7 +312,7 @@ mod tests {
         let data_expected = Data::<f32, 4>::random(
             Shape::new([2, 3, 4, 2]),
             Distribution::Standard,
-            &mut StdRng::from_entropy(),
+            &mut Generator::from_entropy(),
         );
         let tensor = NdArrayTensor::from_data(data_expected.clone());
 
@@ -0,0 +1,18 @@
+//! The generator of a device is shared by the whole process, so restoring its state is tested in
+//! its own test binary where no other test draws from it concurrently.
+use burn_ndarray::{NdArrayBackend, NdArrayDevice};
+use burn_tensor::{backend::Backend, Distribution, Tensor};
+
+type TestBackend = NdArrayBackend<f32>;
+
+#[test]
+fn should_restore_the_rng_state_of_the_device() {
+    let device = NdArrayDevice::Cpu;
+    let state = TestBackend::get_rng_state(device);
+    let tensor_1 = Tensor::<TestBackend, 1>::random([8], Distribution::Standard);
+
+    TestBackend::set_rng_state(device, state);
+    let tensor_2 = Tensor::<TestBackend, 1>::random([8], Distribution::Standard);
+
+    assert_eq!(tensor_1.into_data(), tensor_2.into_data());
+}
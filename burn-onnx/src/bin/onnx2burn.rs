@@ -0,0 +1,39 @@
+//! Generate the source code of a module and its state from an ONNX model.
+//!
+//! Usage: `onnx2burn <model.onnx> <ModuleName> [output directory]`, writing the files
+//! `module_name.rs` and `module_name.json.gz`.
+use burn_onnx::import;
+use burn_onnx::proto::ModelProto;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.len() < 3 {
+        eprintln!("Usage: onnx2burn <model.onnx> <ModuleName> [output directory]");
+        std::process::exit(1);
+    }
+
+    let name = &args[2];
+    let directory = args.get(3).map(String::as_str).unwrap_or(".");
+    let file = format!("{}/{}", directory, snake_case(name));
+
+    let model = ModelProto::load(&args[1])?;
+    let module = import(&model, name)?;
+    module.save(&format!("{}.rs", file), &format!("{}.json.gz", file))?;
+
+    println!("Generated {}.rs and {}.json.gz", file, file);
+    Ok(())
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+
+    for (position, char) in name.chars().enumerate() {
+        if char.is_ascii_uppercase() && position > 0 {
+            snake_case.push('_');
+        }
+        snake_case.push(char.to_ascii_lowercase());
+    }
+
+    snake_case
+}
@@ -0,0 +1,124 @@
+use crate::proto::{DataType, TensorProto};
+use burn_tensor::f16;
+use burn_tracing::ElemType;
+
+pub(crate) fn data_type(elem: ElemType) -> DataType {
+    match elem {
+        ElemType::Float16 => DataType::Float16,
+        ElemType::Float32 => DataType::Float,
+        ElemType::Float64 => DataType::Double,
+        ElemType::Int8 => DataType::Int8,
+        ElemType::Int16 => DataType::Int16,
+        ElemType::Int32 => DataType::Int32,
+        ElemType::Int64 => DataType::Int64,
+        ElemType::UInt8 => DataType::Uint8,
+        ElemType::Bool => DataType::Bool,
+    }
+}
+
+/// Create a tensor with the values converted to the element type, stored as raw little endian
+/// bytes.
+pub(crate) fn tensor_proto(
+    name: String,
+    elem: ElemType,
+    dims: &[usize],
+    values: &[f64],
+) -> TensorProto {
+    let mut raw_data = Vec::new();
+
+    for value in values {
+        let value = *value;
+
+        match elem {
+            ElemType::Float16 => raw_data.extend(f16::from_f64(value).to_le_bytes()),
+            ElemType::Float32 => raw_data.extend((value as f32).to_le_bytes()),
+            ElemType::Float64 => raw_data.extend(value.to_le_bytes()),
+            ElemType::Int8 => raw_data.extend((value as i8).to_le_bytes()),
+            ElemType::Int16 => raw_data.extend((value as i16).to_le_bytes()),
+            ElemType::Int32 => raw_data.extend((value as i32).to_le_bytes()),
+            ElemType::Int64 => raw_data.extend((value as i64).to_le_bytes()),
+            ElemType::UInt8 => raw_data.push(value as u8),
+            ElemType::Bool => raw_data.push((value != 0.0) as u8),
+        }
+    }
+
+    TensorProto {
+        dims: dims.iter().map(|dim| *dim as i64).collect(),
+        data_type: data_type(elem) as i32,
+        name,
+        raw_data,
+        ..Default::default()
+    }
+}
+
+/// Returns the values of a tensor, either stored as raw little endian bytes or in the field of
+/// its element type.
+pub(crate) fn values(tensor: &TensorProto) -> Result<Vec<f64>, String> {
+    let data_type = DataType::from_i32(tensor.data_type).unwrap_or(DataType::Undefined);
+
+    if !tensor.raw_data.is_empty() {
+        return raw_values(data_type, &tensor.raw_data);
+    }
+
+    let values = match data_type {
+        DataType::Float => tensor
+            .float_data
+            .iter()
+            .map(|value| *value as f64)
+            .collect(),
+        DataType::Double => tensor.double_data.clone(),
+        DataType::Int64 => tensor
+            .int64_data
+            .iter()
+            .map(|value| *value as f64)
+            .collect(),
+        // Half precision floats are stored as their bits.
+        DataType::Float16 => tensor
+            .int32_data
+            .iter()
+            .map(|value| f16::from_bits(*value as u16).to_f64())
+            .collect(),
+        DataType::Int32
+        | DataType::Int16
+        | DataType::Int8
+        | DataType::Uint16
+        | DataType::Uint8
+        | DataType::Bool => tensor
+            .int32_data
+            .iter()
+            .map(|value| *value as f64)
+            .collect(),
+        _ => return Err(format!("Unsupported data type {:?}", data_type)),
+    };
+
+    Ok(values)
+}
+
+fn raw_values(data_type: DataType, raw_data: &[u8]) -> Result<Vec<f64>, String> {
+    macro_rules! decode {
+        ($ty:ty) => {
+            raw_data
+                .chunks_exact(std::mem::size_of::<$ty>())
+                .map(|bytes| <$ty>::from_le_bytes(bytes.try_into().unwrap()) as f64)
+                .collect()
+        };
+    }
+
+    let values = match data_type {
+        DataType::Float => decode!(f32),
+        DataType::Double => decode!(f64),
+        DataType::Int8 => decode!(i8),
+        DataType::Int16 => decode!(i16),
+        DataType::Int32 => decode!(i32),
+        DataType::Int64 => decode!(i64),
+        DataType::Uint8 | DataType::Bool => decode!(u8),
+        DataType::Uint16 => decode!(u16),
+        DataType::Float16 => raw_data
+            .chunks_exact(2)
+            .map(|bytes| f16::from_le_bytes([bytes[0], bytes[1]]).to_f64())
+            .collect(),
+        _ => return Err(format!("Unsupported data type {:?}", data_type)),
+    };
+
+    Ok(values)
+}
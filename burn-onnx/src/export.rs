@@ -0,0 +1,665 @@
+use crate::data::{data_type, tensor_proto};
+use crate::proto::*;
+use burn::module::{Module, ModuleVisitor, ParamId, State};
+use burn_tensor::backend::Backend;
+use burn_tensor::{Element, ElementConversion, Tensor};
+use burn_tracing::{trace, ElemType, Graph, NodeId, Op, RandomDistribution, TracingBackend};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum ExportError {
+    UnsupportedOp(String),
+    MissingParam(String),
+}
+
+impl std::fmt::Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut message = "Export error => ".to_string();
+
+        match self {
+            Self::UnsupportedOp(err) => {
+                message += format!("Unsupported operation: {}", err).as_str();
+            }
+            Self::MissingParam(err) => {
+                message += format!("Missing parameter: {}", err).as_str();
+            }
+        };
+
+        f.write_str(message.as_str())
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+/// Export the forward pass of a module to an ONNX model, traced with a sample input.
+///
+/// The weights of the module are written as initializers named by the ids of their parameters.
+/// The model has a single input `input_0` and output `output_0`, see [to_onnx] to export models
+/// traced with other inputs.
+///
+/// Composite operations, such as gelu, softmax and layer norm, are traced as the tensor
+/// operations computing them, so they are decomposed into the ONNX nodes of those operations
+/// (`Exp`, `ReduceSum`, `Div`, ...) instead of being exported as `Softmax` or
+/// `LayerNormalization` nodes.
+pub fn export<B, M, F, const D1: usize, const D2: usize>(
+    module: &M,
+    input: Tensor<TracingBackend<B>, D1>,
+    forward: F,
+) -> Result<ModelProto, ExportError>
+where
+    B: Backend,
+    M: Module<Backend = TracingBackend<B>>,
+    F: FnOnce(&M, Tensor<TracingBackend<B>, D1>) -> Tensor<TracingBackend<B>, D2>,
+{
+    let graph = trace(module, input, forward);
+
+    // The params are visited rather than taken from the state, which keeps quantized params
+    // without their dequantized values.
+    let mut params = ParamValues::default();
+    module.visit(&mut params);
+
+    build(&graph, &params.values)
+}
+
+/// Convert a traced graph to an ONNX model, the weights of its parameters being taken from the
+/// [state](Module::state) of the module.
+///
+/// The state of quantized params doesn't hold their dequantized values, see [export] to export
+/// modules with quantized params.
+pub fn to_onnx<E: Element>(graph: &Graph, state: &State<E>) -> Result<ModelProto, ExportError> {
+    let mut params = HashMap::new();
+    collect_params(state, &mut params);
+
+    build(graph, &params)
+}
+
+fn build(graph: &Graph, params: &HashMap<String, Vec<f64>>) -> Result<ModelProto, ExportError> {
+    let mut builder = GraphBuilder::new(graph);
+    for id in 0..graph.nodes.len() {
+        builder.export(id, params)?;
+    }
+
+    Ok(ModelProto {
+        ir_version: IR_VERSION,
+        opset_import: vec![OperatorSetIdProto {
+            domain: String::new(),
+            version: OPSET_VERSION,
+        }],
+        producer_name: "burn".to_string(),
+        producer_version: env!("CARGO_PKG_VERSION").to_string(),
+        graph: Some(builder.build()),
+        ..Default::default()
+    })
+}
+
+/// Collect the values of the parameters of a module by their id.
+#[derive(Default)]
+struct ParamValues {
+    values: HashMap<String, Vec<f64>>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamValues {
+    fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let values = tensor
+            .to_data()
+            .value
+            .iter()
+            .map(|value| value.to_elem())
+            .collect();
+        self.values.insert(id.to_string(), values);
+    }
+}
+
+/// Collect the values of the parameters of a module state by their id.
+fn collect_params<E: Element>(state: &State<E>, params: &mut HashMap<String, Vec<f64>>) {
+    if let State::StateNamed(named) = state {
+        if let (Some(State::ParamId(id)), Some(State::Data(data))) =
+            (named.get("id"), named.get("data"))
+        {
+            let values = data.value.iter().map(|value| value.to_elem()).collect();
+            params.insert(id.to_string(), values);
+        }
+
+        for value in named.values.values() {
+            collect_params(value, params);
+        }
+    }
+}
+
+struct GraphBuilder<'a> {
+    graph: &'a Graph,
+    names: Vec<String>,
+    nodes: Vec<NodeProto>,
+    initializers: Vec<TensorProto>,
+}
+
+impl<'a> GraphBuilder<'a> {
+    fn new(graph: &'a Graph) -> Self {
+        let mut names: Vec<String> = graph
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(id, node)| match &node.op {
+                Op::Param { id } => id.to_string(),
+                _ => format!("node_{}", id),
+            })
+            .collect();
+
+        for (position, id) in graph.inputs.iter().enumerate() {
+            names[*id] = format!("input_{}", position);
+        }
+
+        Self {
+            graph,
+            names,
+            nodes: Vec::new(),
+            initializers: Vec::new(),
+        }
+    }
+
+    fn export(
+        &mut self,
+        id: NodeId,
+        params: &HashMap<String, Vec<f64>>,
+    ) -> Result<(), ExportError> {
+        let node = &self.graph.nodes[id];
+        let output = self.names[id].clone();
+        let inputs: Vec<String> = node
+            .inputs
+            .iter()
+            .map(|input| self.names[*input].clone())
+            .collect();
+        let rank = node.shape.len();
+
+        match &node.op {
+            Op::Input => {}
+            Op::Param { id } => {
+                let values = params
+                    .get(&id.to_string())
+                    .ok_or_else(|| ExportError::MissingParam(id.to_string()))?;
+                self.initializer(output, node.elem, &node.shape, values);
+            }
+            Op::Constant { values } => {
+                self.initializer(output, node.elem, &node.shape, values);
+            }
+            Op::Empty => self.fill(id, output, 0.0),
+            Op::Fill { value } => self.fill(id, output, *value),
+            Op::Random { distribution } => self.random(id, output, distribution)?,
+            Op::Arange { start, end } => {
+                let values: Vec<f64> = (*start..*end).map(|value| value as f64).collect();
+                self.initializer(output, node.elem, &node.shape, &values);
+            }
+            Op::Repeat { dim, times } => {
+                let mut repeats = vec![1; rank];
+                repeats[*dim] = *times as i64;
+                let repeats = self.ints(format!("{}_repeats", output), &repeats);
+                self.node("Tile", vec![inputs[0].clone(), repeats], output, Vec::new());
+            }
+            Op::Add => self.node("Add", inputs, output, Vec::new()),
+            Op::Sub => self.node("Sub", inputs, output, Vec::new()),
+            Op::Mul => self.node("Mul", inputs, output, Vec::new()),
+            Op::Div => self.node("Div", inputs, output, Vec::new()),
+            Op::AddScalar { value } => self.scalar_op("Add", id, inputs, output, *value),
+            Op::SubScalar { value } => self.scalar_op("Sub", id, inputs, output, *value),
+            Op::MulScalar { value } => self.scalar_op("Mul", id, inputs, output, *value),
+            Op::DivScalar { value } => self.scalar_op("Div", id, inputs, output, *value),
+            Op::Powf { value } => self.scalar_op("Pow", id, inputs, output, *value as f64),
+            Op::MatMul => self.node("MatMul", inputs, output, Vec::new()),
+            Op::Neg => self.node("Neg", inputs, output, Vec::new()),
+            Op::Exp => self.node("Exp", inputs, output, Vec::new()),
+            Op::Log => self.node("Log", inputs, output, Vec::new()),
+            Op::Erf => self.node("Erf", inputs, output, Vec::new()),
+            Op::Relu => self.node("Relu", inputs, output, Vec::new()),
+            Op::SwapDims { dim1, dim2 } => {
+                let mut perm: Vec<i64> = (0..rank as i64).collect();
+                perm.swap(*dim1, *dim2);
+                let attributes = vec![AttributeProto::ints("perm", perm)];
+                self.node("Transpose", inputs, output, attributes);
+            }
+            Op::Reshape => {
+                let shape: Vec<i64> = node.shape.iter().map(|dim| *dim as i64).collect();
+                let shape = self.ints(format!("{}_shape", output), &shape);
+                self.node(
+                    "Reshape",
+                    vec![inputs[0].clone(), shape],
+                    output,
+                    Vec::new(),
+                );
+            }
+            Op::Index { ranges } => {
+                let starts: Vec<i64> = ranges.iter().map(|range| range.start as i64).collect();
+                let ends: Vec<i64> = ranges.iter().map(|range| range.end as i64).collect();
+                let axes: Vec<i64> = (0..ranges.len() as i64).collect();
+                let inputs = vec![
+                    inputs[0].clone(),
+                    self.ints(format!("{}_starts", output), &starts),
+                    self.ints(format!("{}_ends", output), &ends),
+                    self.ints(format!("{}_axes", output), &axes),
+                ];
+                self.node("Slice", inputs, output, Vec::new());
+            }
+            Op::MaskFill { value } => {
+                let value = self.scalar(format!("{}_value", output), node.elem, *value);
+                let inputs = vec![inputs[1].clone(), value, inputs[0].clone()];
+                self.node("Where", inputs, output, Vec::new());
+            }
+            Op::Mean => self.reduce_all("ReduceMean", inputs, output),
+            Op::Sum => self.reduce_all("ReduceSum", inputs, output),
+            Op::MeanDim { dim } => {
+                let attributes = vec![
+                    AttributeProto::ints("axes", vec![*dim as i64]),
+                    AttributeProto::int("keepdims", 1),
+                ];
+                self.node("ReduceMean", inputs, output, attributes);
+            }
+            Op::SumDim { dim } => {
+                let axes = self.ints(format!("{}_axes", output), &[*dim as i64]);
+                let attributes = vec![AttributeProto::int("keepdims", 1)];
+                self.node(
+                    "ReduceSum",
+                    vec![inputs[0].clone(), axes],
+                    output,
+                    attributes,
+                );
+            }
+            Op::Cast => {
+                let attributes = vec![AttributeProto::int("to", data_type(node.elem) as i64)];
+                self.node("Cast", inputs, output, attributes);
+            }
+            Op::ArgMax { dim } => self.arg("ArgMax", id, inputs, output, *dim),
+            Op::ArgMin { dim } => self.arg("ArgMin", id, inputs, output, *dim),
+            Op::Cat { dim } => {
+                let attributes = vec![AttributeProto::int("axis", *dim as i64)];
+                self.node("Concat", inputs, output, attributes);
+            }
+            Op::Embedding => {
+                let attributes = vec![AttributeProto::int("axis", 0)];
+                self.node("Gather", inputs, output, attributes);
+            }
+            op => return Err(ExportError::UnsupportedOp(format!("{:?}", op))),
+        };
+
+        Ok(())
+    }
+
+    fn build(mut self) -> GraphProto {
+        let input = self
+            .graph
+            .inputs
+            .iter()
+            .map(|id| self.value_info(self.names[*id].clone(), *id))
+            .collect();
+        let mut output = Vec::new();
+
+        for (position, id) in self.graph.outputs.iter().enumerate() {
+            let name = format!("output_{}", position);
+            let inputs = vec![self.names[*id].clone()];
+
+            self.node("Identity", inputs, name.clone(), Vec::new());
+            output.push(self.value_info(name, *id));
+        }
+
+        GraphProto {
+            node: self.nodes,
+            name: "burn".to_string(),
+            initializer: self.initializers,
+            input,
+            output,
+            ..Default::default()
+        }
+    }
+
+    fn node(
+        &mut self,
+        op_type: &str,
+        inputs: Vec<String>,
+        output: String,
+        attributes: Vec<AttributeProto>,
+    ) {
+        self.nodes.push(NodeProto {
+            input: inputs,
+            output: vec![output.clone()],
+            name: output,
+            op_type: op_type.to_string(),
+            attribute: attributes,
+            ..Default::default()
+        });
+    }
+
+    fn initializer(&mut self, name: String, elem: ElemType, dims: &[usize], values: &[f64]) {
+        self.initializers
+            .push(tensor_proto(name, elem, dims, values));
+    }
+
+    /// Add a vector of integers, used as the shape or the axes of operations.
+    fn ints(&mut self, name: String, values: &[i64]) -> String {
+        let values: Vec<f64> = values.iter().map(|value| *value as f64).collect();
+        self.initializer(name.clone(), ElemType::Int64, &[values.len()], &values);
+
+        name
+    }
+
+    fn scalar(&mut self, name: String, elem: ElemType, value: f64) -> String {
+        self.initializer(name.clone(), elem, &[], &[value]);
+
+        name
+    }
+
+    fn scalar_op(
+        &mut self,
+        op_type: &str,
+        id: NodeId,
+        inputs: Vec<String>,
+        output: String,
+        value: f64,
+    ) {
+        let elem = self.graph.nodes[id].elem;
+        let value = self.scalar(format!("{}_value", output), elem, value);
+
+        self.node(op_type, vec![inputs[0].clone(), value], output, Vec::new());
+    }
+
+    fn fill(&mut self, id: NodeId, output: String, value: f64) {
+        let node = &self.graph.nodes[id];
+        let value = tensor_proto(String::new(), node.elem, &[1], &[value]);
+        let shape: Vec<i64> = node.shape.iter().map(|dim| *dim as i64).collect();
+        let shape = self.ints(format!("{}_shape", output), &shape);
+        let attributes = vec![AttributeProto::tensor("value", value)];
+
+        self.node("ConstantOfShape", vec![shape], output, attributes);
+    }
+
+    fn random(
+        &mut self,
+        id: NodeId,
+        output: String,
+        distribution: &RandomDistribution,
+    ) -> Result<(), ExportError> {
+        let node = &self.graph.nodes[id];
+        let mut attributes = vec![
+            AttributeProto::ints("shape", node.shape.iter().map(|dim| *dim as i64).collect()),
+            AttributeProto::int("dtype", data_type(node.elem) as i64),
+        ];
+
+        let op_type = match distribution {
+            RandomDistribution::Standard => {
+                attributes.push(AttributeProto::float("low", 0.0));
+                attributes.push(AttributeProto::float("high", 1.0));
+                "RandomUniform"
+            }
+            RandomDistribution::Uniform { low, high } => {
+                attributes.push(AttributeProto::float("low", *low as f32));
+                attributes.push(AttributeProto::float("high", *high as f32));
+                "RandomUniform"
+            }
+            RandomDistribution::Normal { mean, std } => {
+                attributes.push(AttributeProto::float("mean", *mean as f32));
+                attributes.push(AttributeProto::float("scale", *std as f32));
+                "RandomNormal"
+            }
+            distribution => {
+                return Err(ExportError::UnsupportedOp(format!(
+                    "Random {{ distribution: {:?} }}",
+                    distribution
+                )))
+            }
+        };
+
+        self.node(op_type, Vec::new(), output, attributes);
+
+        Ok(())
+    }
+
+    /// Reductions of all the elements have a shape of `[1]`.
+    fn reduce_all(&mut self, op_type: &str, inputs: Vec<String>, output: String) {
+        let reduced = format!("{}_reduced", output);
+        let attributes = vec![AttributeProto::int("keepdims", 1)];
+        self.node(op_type, inputs, reduced.clone(), attributes);
+
+        let shape = self.ints(format!("{}_shape", output), &[1]);
+        self.node("Reshape", vec![reduced, shape], output, Vec::new());
+    }
+
+    /// The indexes are converted from int64 to the element type of the node.
+    fn arg(&mut self, op_type: &str, id: NodeId, inputs: Vec<String>, output: String, dim: usize) {
+        let elem = self.graph.nodes[id].elem;
+        let attributes = vec![
+            AttributeProto::int("axis", dim as i64),
+            AttributeProto::int("keepdims", 1),
+        ];
+
+        if elem == ElemType::Int64 {
+            self.node(op_type, inputs, output, attributes);
+            return;
+        }
+
+        let indexes = format!("{}_indexes", output);
+        self.node(op_type, inputs, indexes.clone(), attributes);
+        let attributes = vec![AttributeProto::int("to", data_type(elem) as i64)];
+        self.node("Cast", vec![indexes], output, attributes);
+    }
+
+    fn value_info(&self, name: String, id: NodeId) -> ValueInfoProto {
+        let node = &self.graph.nodes[id];
+        let dim = node
+            .shape
+            .iter()
+            .map(|dim| DimensionProto {
+                dim_value: Some(*dim as i64),
+                dim_param: None,
+            })
+            .collect();
+
+        ValueInfoProto {
+            name,
+            r#type: Some(TypeProto {
+                tensor_type: Some(TensorTypeProto {
+                    elem_type: data_type(node.elem) as i32,
+                    shape: Some(TensorShapeProto { dim }),
+                }),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::module::Param;
+    use burn::nn;
+    use burn_tensor::activation::{gelu, softmax};
+    use burn_tensor::{Data, QuantizationScheme};
+    use burn_tracing::Tracer;
+    use prost::Message;
+
+    type TestBackend = TracingBackend<burn_ndarray::NdArrayBackend<f32>>;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear: Param<nn::Linear<B>>,
+        norm: Param<nn::LayerNorm<B>>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new() -> Self {
+            Self {
+                linear: Param::new(nn::Linear::new(&nn::LinearConfig::new(4, 3))),
+                norm: Param::new(nn::LayerNorm::new(&nn::LayerNormConfig::new(3))),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self.linear.forward(input);
+            let x = self.norm.forward(gelu(&x));
+
+            softmax(&x, 1)
+        }
+    }
+
+    fn op_types(model: &ModelProto) -> Vec<&str> {
+        let graph = model.graph.as_ref().unwrap();
+
+        graph
+            .node
+            .iter()
+            .map(|node| node.op_type.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn should_export_weights_as_initializers() {
+        let model = Model::<TestBackend>::new();
+        let input = Tensor::<TestBackend, 2>::zeros([2, 4]);
+
+        let onnx = export(&model, input, |model, input| model.forward(input)).unwrap();
+
+        let graph = onnx.graph.as_ref().unwrap();
+        let mut params = HashMap::new();
+        collect_params(&model.state(), &mut params);
+        assert_eq!(params.len(), 4);
+
+        for (id, values) in params {
+            let initializer = graph
+                .initializer
+                .iter()
+                .find(|initializer| initializer.name == id)
+                .unwrap();
+            let expected: Vec<u8> = values
+                .iter()
+                .flat_map(|value| (*value as f32).to_le_bytes())
+                .collect();
+
+            assert_eq!(initializer.data_type, DataType::Float as i32);
+            assert_eq!(initializer.raw_data, expected);
+        }
+    }
+
+    #[test]
+    fn should_export_quantized_weights_as_dequantized_initializers() {
+        let weight = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, -2.0], [0.5, 4.0]]));
+        let weight = Param::new(weight.quantize(QuantizationScheme::PerTensor));
+        let input = Tensor::<TestBackend, 2>::zeros([3, 2]);
+
+        let onnx = export(&weight, input, |weight, input| {
+            input.matmul_quantized(weight)
+        })
+        .unwrap();
+
+        let mut params = ParamValues::default();
+        weight.visit(&mut params);
+        let (id, values) = params.values.into_iter().next().unwrap();
+        let initializer = &onnx.graph.as_ref().unwrap().initializer[0];
+        let expected: Vec<u8> = values
+            .iter()
+            .flat_map(|value| (*value as f32).to_le_bytes())
+            .collect();
+        let dequantized = weight.dequantize().to_data().value;
+        assert_eq!(
+            values,
+            dequantized
+                .iter()
+                .map(|value| *value as f64)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(initializer.name, id);
+        assert_eq!(initializer.raw_data, expected);
+        assert_eq!(op_types(&onnx), vec!["MatMul", "Identity"]);
+    }
+
+    #[test]
+    fn should_export_composite_ops_as_their_tensor_ops() {
+        let model = Model::<TestBackend>::new();
+        let input = Tensor::<TestBackend, 2>::zeros([2, 4]);
+
+        let onnx = export(&model, input, |model, input| model.forward(input)).unwrap();
+
+        let op_types = op_types(&onnx);
+        assert_eq!(op_types[..3], ["Reshape", "MatMul", "Reshape"]);
+        for op_type in ["Erf", "ReduceMean", "Pow", "Exp", "ReduceSum", "Log", "Sub"] {
+            assert!(op_types.contains(&op_type), "{} isn't exported", op_type);
+        }
+        for op_type in ["Softmax", "LogSoftmax", "LayerNormalization", "Gelu"] {
+            assert!(!op_types.contains(&op_type), "{} is exported", op_type);
+        }
+        assert_eq!(op_types.last(), Some(&"Identity"));
+
+        let graph = onnx.graph.as_ref().unwrap();
+        let output = graph.output[0].r#type.as_ref().unwrap();
+        let shape = output.tensor_type.as_ref().unwrap().shape.as_ref().unwrap();
+        let dims: Vec<_> = shape.dim.iter().map(|dim| dim.dim_value.unwrap()).collect();
+        assert_eq!(graph.input[0].name, "input_0");
+        assert_eq!(graph.output[0].name, "output_0");
+        assert_eq!(dims, vec![2, 3]);
+        assert_eq!(onnx.opset_import[0].version, OPSET_VERSION);
+    }
+
+    #[test]
+    fn should_export_swap_dims_as_transpose() {
+        let model = Model::<TestBackend>::new();
+        let input = Tensor::<TestBackend, 2>::zeros([4, 2]);
+
+        let onnx = export(&model, input, |model, input| {
+            model.forward(input.swap_dims(0, 1))
+        })
+        .unwrap();
+
+        let graph = onnx.graph.as_ref().unwrap();
+        let transpose = &graph.node[0];
+        assert_eq!(transpose.op_type, "Transpose");
+        assert_eq!(transpose.input, vec!["input_0"]);
+        assert_eq!(transpose.attribute("perm").unwrap().ints, vec![1, 0]);
+    }
+
+    #[test]
+    fn should_export_embedding_as_gather() {
+        let embedding = nn::Embedding::<TestBackend>::new(&nn::EmbeddingConfig::new(10, 4));
+        let indexes = Tensor::from_data(Data::from([[1, 2, 3]]));
+
+        let tracer = Tracer::start();
+        tracer.register(&embedding);
+        let indexes = tracer.input(indexes);
+        tracer.output(&embedding.forward(indexes));
+        let graph = tracer.finish();
+
+        let onnx = to_onnx(&graph, &embedding.state()).unwrap();
+
+        let graph = onnx.graph.as_ref().unwrap();
+        let gather = &graph.node[0];
+        let input = graph.input[0].r#type.as_ref().unwrap();
+        assert_eq!(gather.op_type, "Gather");
+        assert_eq!(gather.input[0], graph.initializer[0].name);
+        assert_eq!(gather.input[1], "input_0");
+        assert_eq!(
+            input.tensor_type.as_ref().unwrap().elem_type,
+            DataType::Int64 as i32
+        );
+    }
+
+    #[test]
+    fn should_decode_encoded_model() {
+        let model = Model::<TestBackend>::new();
+        let input = Tensor::<TestBackend, 2>::zeros([2, 4]);
+        let onnx = export(&model, input, |model, input| model.forward(input)).unwrap();
+
+        let decoded = ModelProto::decode(onnx.encode_to_vec().as_slice()).unwrap();
+
+        assert_eq!(decoded, onnx);
+    }
+
+    #[test]
+    fn should_report_unsupported_ops() {
+        let model = Model::<TestBackend>::new();
+        let input = Tensor::<TestBackend, 2>::zeros([2, 4]);
+
+        let result = export(&model, input, |model, input| {
+            let output = model.forward(input);
+            output.index_assign([0..1, 0..3], &output.index([1..2, 0..3]))
+        });
+
+        assert!(matches!(
+            result,
+            Err(ExportError::UnsupportedOp(op)) if op == "IndexAssign { ranges: [0..1, 0..3] }"
+        ));
+    }
+}
@@ -0,0 +1,2124 @@
+use crate::data;
+use crate::proto::*;
+use burn::module::{ParamId, State, StateNamed};
+use burn_tensor::DataSerialize;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub enum ImportError {
+    InvalidModel(String),
+    UnsupportedOp(String),
+    UnknownShape(String),
+}
+
+impl std::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut message = "Import error => ".to_string();
+
+        match self {
+            Self::InvalidModel(err) => {
+                message += format!("Invalid model: {}", err).as_str();
+            }
+            Self::UnsupportedOp(err) => {
+                message += format!("Unsupported operation: {}", err).as_str();
+            }
+            Self::UnknownShape(err) => {
+                message += format!("Unknown shape: {}", err).as_str();
+            }
+        };
+
+        f.write_str(message.as_str())
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+/// Module generated from an ONNX model by [import].
+pub struct ImportedModule {
+    /// Source code of the module.
+    pub source: String,
+    /// Weights of the ONNX model, which can be loaded by the generated module with
+    /// [load](burn::module::Module::load).
+    pub state: State<f32>,
+}
+
+impl ImportedModule {
+    /// Save the source code of the module and its state to files.
+    pub fn save(self, source_file: &str, state_file: &str) -> std::io::Result<()> {
+        std::fs::write(source_file, &self.source)?;
+        self.state.save(state_file)
+    }
+}
+
+/// Generate the source code of a module computing the forward pass of an ONNX model.
+///
+/// The module has a `new` function creating it with random weights, to be replaced by the
+/// weights of the ONNX model [loaded](burn::module::Module::load) from the generated state, and a
+/// `forward` function taking the inputs of the model in their order.
+///
+/// Matrix multiplications by a weight, followed by the addition of a bias, are generated as
+/// [linear](burn::nn::Linear) layers, gathers from a weight as
+/// [embeddings](burn::nn::Embedding) and layer normalizations as
+/// [layer norms](burn::nn::LayerNorm). The other weights are generated as tensor parameters.
+///
+/// The shapes of the inputs must be known, the shapes of the tensors being computed when
+/// generating the code.
+pub fn import(model: &ModelProto, name: &str) -> Result<ImportedModule, ImportError> {
+    let graph = model
+        .graph
+        .as_ref()
+        .ok_or_else(|| ImportError::InvalidModel("Missing graph".to_string()))?;
+    let opset = model
+        .opset_import
+        .iter()
+        .find(|opset| opset.domain.is_empty() || opset.domain == "ai.onnx")
+        .map(|opset| opset.version)
+        .unwrap_or(OPSET_VERSION);
+
+    let mut codegen = Codegen::new(graph, opset)?;
+    for position in 0..graph.node.len() {
+        if !codegen.fused.contains(&position) {
+            codegen.import(position)?;
+        }
+    }
+
+    codegen.build(graph, name)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Float,
+    Int,
+}
+
+impl Kind {
+    fn of(data_type: i32) -> Option<Self> {
+        match DataType::from_i32(data_type)? {
+            DataType::Float | DataType::Double | DataType::Float16 => Some(Self::Float),
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::Uint8
+            | DataType::Uint16 => Some(Self::Int),
+            _ => None,
+        }
+    }
+
+    fn tensor(&self, rank: usize) -> String {
+        match self {
+            Self::Float => format!("Tensor<B, {}>", rank),
+            Self::Int => format!("Tensor<B::IntegerBackend, {}>", rank),
+        }
+    }
+}
+
+/// Tensor whose values are known when generating the code, such as initializers and shapes.
+#[derive(Clone, Debug)]
+struct Constant {
+    /// Name of the initializer it comes from, used to name the field holding it.
+    hint: String,
+    shape: Vec<usize>,
+    values: Vec<f64>,
+    kind: Kind,
+}
+
+/// Size of a dimension of a tensor computed by the forward pass.
+///
+/// It is the product of a factor known when generating the code and of the sizes of dynamic
+/// dimensions, held by variables named after the symbolic dimensions of the inputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Dim {
+    factor: usize,
+    /// Variables holding the sizes of dynamic dimensions, sorted.
+    symbols: Vec<String>,
+}
+
+impl Dim {
+    fn symbol(var: String) -> Self {
+        Self {
+            factor: 1,
+            symbols: vec![var],
+        }
+    }
+
+    /// Returns the size of the dimension when it isn't dynamic.
+    fn size(&self) -> Option<usize> {
+        match self.symbols.is_empty() {
+            true => Some(self.factor),
+            false => None,
+        }
+    }
+
+    fn mul(&self, other: &Dim) -> Dim {
+        let mut symbols = [self.symbols.as_slice(), other.symbols.as_slice()].concat();
+        symbols.sort();
+
+        Dim {
+            factor: self.factor * other.factor,
+            symbols,
+        }
+    }
+
+    /// Returns the dimension multiplied by the other one to give the current one, if it can be
+    /// computed without knowing the sizes of the dynamic dimensions.
+    fn div(&self, other: &Dim) -> Option<Dim> {
+        if other.factor == 0 || !self.factor.is_multiple_of(other.factor) {
+            return None;
+        }
+
+        let mut symbols = self.symbols.clone();
+        for symbol in other.symbols.iter() {
+            let position = symbols.iter().position(|current| current == symbol)?;
+            symbols.remove(position);
+        }
+
+        Some(Dim {
+            factor: self.factor / other.factor,
+            symbols,
+        })
+    }
+}
+
+impl From<usize> for Dim {
+    fn from(size: usize) -> Self {
+        Self {
+            factor: size,
+            symbols: Vec::new(),
+        }
+    }
+}
+
+/// The expression of the size of the dimension.
+impl std::fmt::Display for Dim {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.symbols.is_empty(), self.factor) {
+            (true, factor) => write!(f, "{}", factor),
+            (false, 1) => f.write_str(self.symbols.join(" * ").as_str()),
+            (false, factor) => write!(f, "{} * {}", self.symbols.join(" * "), factor),
+        }
+    }
+}
+
+/// Tensor computed by the forward pass, or held by a field of the module.
+#[derive(Clone, Debug)]
+struct Value {
+    expr: String,
+    kind: Kind,
+    shape: Vec<Dim>,
+    /// If the value is a local variable, which can be moved on its last use.
+    local: bool,
+}
+
+struct Field {
+    name: String,
+    ty: String,
+    init: String,
+    state: State<f32>,
+}
+
+struct Codegen<'a> {
+    nodes: &'a [NodeProto],
+    opset: i64,
+    constants: HashMap<String, Constant>,
+    values: HashMap<String, Value>,
+    uses: HashMap<String, usize>,
+    /// Nodes imported with the node before them, such as the addition of a bias.
+    fused: HashSet<usize>,
+    inputs: Vec<String>,
+    fields: Vec<Field>,
+    field_names: HashSet<String>,
+    var_names: HashSet<String>,
+    /// Variables holding the sizes of the dynamic dimensions of the inputs, by symbol, with
+    /// the statement declaring them.
+    symbols: HashMap<String, (String, String)>,
+    body: String,
+    /// Last statement of the body, with the variable it declares and the length of the body
+    /// before it.
+    last: Option<(String, String, usize)>,
+    uses_nn: bool,
+    uses_activation: bool,
+}
+
+impl<'a> Codegen<'a> {
+    fn new(graph: &'a GraphProto, opset: i64) -> Result<Self, ImportError> {
+        let mut codegen = Self {
+            nodes: &graph.node,
+            opset,
+            constants: HashMap::new(),
+            values: HashMap::new(),
+            uses: HashMap::new(),
+            fused: HashSet::new(),
+            inputs: Vec::new(),
+            fields: Vec::new(),
+            field_names: HashSet::new(),
+            var_names: HashSet::new(),
+            symbols: HashMap::new(),
+            body: String::new(),
+            last: None,
+            uses_nn: false,
+            uses_activation: false,
+        };
+
+        for initializer in graph.initializer.iter() {
+            let constant = constant(initializer.name.clone(), initializer)?;
+            codegen.constants.insert(initializer.name.clone(), constant);
+        }
+
+        let names = graph.node.iter().flat_map(|node| node.input.iter());
+        let outputs = graph.output.iter().map(|output| &output.name);
+        for name in names.chain(outputs).filter(|name| !name.is_empty()) {
+            *codegen.uses.entry(name.clone()).or_insert(0) += 1;
+        }
+
+        for input in graph.input.iter() {
+            if codegen.constants.contains_key(&input.name) {
+                continue;
+            }
+
+            let (kind, shape) = value_type(input)?;
+            let var = unique(&mut codegen.var_names, ident(&input.name, "input"));
+            let shape = shape
+                .into_iter()
+                .enumerate()
+                .map(|(dim, size)| match size {
+                    Ok(size) => Dim::from(size),
+                    Err(symbol) => codegen.symbol(symbol, &var, dim),
+                })
+                .collect();
+            let value = Value {
+                expr: var.clone(),
+                kind,
+                shape: non_scalar(shape),
+                local: true,
+            };
+            codegen
+                .inputs
+                .push(format!("{}: {}", var, kind.tensor(value.shape.len())));
+            codegen.values.insert(input.name.clone(), value);
+        }
+
+        Ok(codegen)
+    }
+
+    /// Returns the size of a dynamic dimension of an input, held by a variable shared by the
+    /// dimensions with the same symbol.
+    fn symbol(&mut self, symbol: String, input: &str, dim: usize) -> Dim {
+        if let Some((var, _)) = self.symbols.get(&symbol) {
+            return Dim::symbol(var.clone());
+        }
+
+        let var = unique(&mut self.var_names, ident(&symbol, "dim"));
+        let statement = format!("        let {} = {}.dims()[{}];\n", var, input, dim);
+        self.symbols.insert(symbol, (var.clone(), statement));
+
+        Dim::symbol(var)
+    }
+
+    fn import(&mut self, position: usize) -> Result<(), ImportError> {
+        let nodes = self.nodes;
+        let node = &nodes[position];
+
+        if !node.domain.is_empty() && node.domain != "ai.onnx" {
+            return Err(unsupported(node));
+        }
+        output(node)?;
+        if self.fold(node)? {
+            return Ok(());
+        }
+
+        let op = node.op_type.as_str();
+        match op {
+            "Identity" | "Dropout" => self.unary(node, 0, |input| input)?,
+            "Cast" => {
+                let value = self.value(required(node, 0)?)?;
+                if Kind::of(attr_int(node, "to", 0) as i32) != Some(value.kind) {
+                    return Err(unsupported(node));
+                }
+                self.unary(node, 0, |input| input)?;
+            }
+            "Reshape" | "Flatten" | "Squeeze" | "Unsqueeze" => {
+                let input = required(node, 0)?;
+                let value = self.value(input)?;
+                let shape = non_scalar(self.reshaped(node, &value.shape)?);
+                let expr = format!("{}.reshape({})", self.borrow(input)?, dims(&shape));
+                self.emit(node, value.kind, shape, expr)?;
+            }
+            "Add" | "Sub" | "Mul" | "Div" => self.binary(node)?,
+            "Pow" => {
+                let exponent = self
+                    .scalar(required(node, 1)?)
+                    .ok_or_else(|| unsupported(node))?;
+                self.unary_ref(node, |input| format!("{}.powf({})", input, float(exponent)))?;
+            }
+            "Sqrt" => self.unary_ref(node, |input| format!("{}.powf(0.5)", input))?,
+            "Reciprocal" => self.unary_ref(node, |input| format!("{}.powf(-1.0)", input))?,
+            "Exp" | "Log" | "Neg" | "Erf" => {
+                let method = op.to_lowercase();
+                self.unary_ref(node, |input| format!("{}.{}()", input, method))?;
+            }
+            "Sigmoid" => self.unary_ref(node, |input| {
+                format!("{}.neg().exp().add_scalar(1.0).powf(-1.0)", input)
+            })?,
+            "Tanh" => self.unary_ref(node, |input| {
+                format!(
+                    "{}.mul_scalar(-2.0).exp().add_scalar(1.0).powf(-1.0).mul_scalar(2.0).sub_scalar(1.0)",
+                    input
+                )
+            })?,
+            "Relu" | "Gelu" => {
+                self.uses_activation = true;
+                let function = op.to_lowercase();
+                self.unary_ref(node, |input| format!("activation::{}(&{})", function, input))?;
+            }
+            "Softmax" | "LogSoftmax" => {
+                let rank = self.value(required(node, 0)?)?.shape.len();
+                // Before opset 13, the input is flattened to a matrix at the axis.
+                let default = if self.opset < 13 { 1 } else { -1 };
+                let dim = axis(attr_int(node, "axis", default), rank)?;
+                if self.opset < 13 && dim != rank - 1 {
+                    return Err(unsupported(node));
+                }
+
+                self.uses_activation = true;
+                let function = match op {
+                    "Softmax" => "softmax",
+                    _ => "log_softmax",
+                };
+                self.unary_ref(node, |input| {
+                    format!("activation::{}(&{}, {})", function, input, dim)
+                })?;
+            }
+            "MatMul" => self.matmul(position)?,
+            "Gemm" => self.gemm(node)?,
+            "Gather" => self.embedding(node)?,
+            "LayerNormalization" => self.layer_norm(node)?,
+            "Transpose" => self.transpose(node)?,
+            "ReduceMean" | "ReduceSum" => self.reduce(node)?,
+            "ArgMax" | "ArgMin" => self.arg(node)?,
+            "Concat" => self.concat(node)?,
+            "Slice" => self.slice(node)?,
+            "Tile" => self.tile(node)?,
+            "ConstantOfShape" => self.constant_of_shape(node)?,
+            _ => return Err(unsupported(node)),
+        };
+
+        Ok(())
+    }
+
+    /// Compute the output of a node whose inputs are constants, such as the computation of
+    /// shapes, returning if the node has been folded.
+    fn fold(&mut self, node: &NodeProto) -> Result<bool, ImportError> {
+        let output = output(node)?.to_string();
+        let inputs: Vec<&str> = node
+            .input
+            .iter()
+            .filter(|input| !input.is_empty())
+            .map(String::as_str)
+            .collect();
+        let constants: Option<Vec<Constant>> = inputs
+            .iter()
+            .map(|input| self.constants.get(*input).cloned())
+            .collect();
+
+        let folded = match (node.op_type.as_str(), constants) {
+            ("Constant", _) => Some(constant_attribute(&output, node)?),
+            ("Shape", _) => {
+                let input = required(node, 0)?;
+                let shape = match self.values.get(input) {
+                    Some(value) => known(&value.shape).ok_or_else(|| {
+                        ImportError::UnknownShape(format!(
+                            "Shape of {} with dynamic dimensions {}",
+                            input,
+                            dims(&value.shape)
+                        ))
+                    })?,
+                    None => match self.constants.get(input) {
+                        Some(constant) => constant.shape.clone(),
+                        None => return Ok(false),
+                    },
+                };
+                Some(ints(&output, &shape))
+            }
+            (_, None) => None,
+            ("Identity" | "Reshape" | "Flatten" | "Squeeze" | "Unsqueeze", Some(constants)) => {
+                let shape = fixed(&constants[0].shape);
+                // The shape of a constant is known, so the reshaped one is too.
+                let shape = known(&self.reshaped(node, &shape)?).unwrap();
+                Some(Constant {
+                    shape,
+                    ..constants[0].clone()
+                })
+            }
+            ("Cast", Some(constants)) => {
+                let kind =
+                    Kind::of(attr_int(node, "to", 0) as i32).ok_or_else(|| unsupported(node))?;
+                Some(Constant {
+                    kind,
+                    ..constants[0].clone()
+                })
+            }
+            ("Transpose", Some(constants)) if constants[0].shape.len() == 2 => {
+                let [rows, cols] = [constants[0].shape[0], constants[0].shape[1]];
+                Some(Constant {
+                    shape: vec![cols, rows],
+                    values: transpose(&constants[0].values, rows, cols),
+                    ..constants[0].clone()
+                })
+            }
+            ("Concat", Some(constants)) if constants.iter().all(|c| c.shape.len() == 1) => {
+                let values: Vec<f64> = constants
+                    .iter()
+                    .flat_map(|constant| constant.values.iter().copied())
+                    .collect();
+                Some(Constant {
+                    hint: output.clone(),
+                    shape: vec![values.len()],
+                    values,
+                    kind: constants[0].kind,
+                })
+            }
+            ("Gather", Some(constants))
+                if constants[0].shape.len() == 1 && attr_int(node, "axis", 0) == 0 =>
+            {
+                let size = constants[0].values.len() as i64;
+                let values = constants[1]
+                    .values
+                    .iter()
+                    .map(|index| {
+                        let index = *index as i64;
+                        match index {
+                            index if (0..size).contains(&index) => Ok(index),
+                            index if (-size..0).contains(&index) => Ok(index + size),
+                            index => Err(ImportError::InvalidModel(format!(
+                                "Index {} of Gather is out of bounds for a constant of size {}",
+                                index, size
+                            ))),
+                        }
+                        .map(|index| constants[0].values[index as usize])
+                    })
+                    .collect::<Result<_, _>>()?;
+                Some(Constant {
+                    hint: output.clone(),
+                    shape: constants[1].shape.clone(),
+                    values,
+                    kind: constants[0].kind,
+                })
+            }
+            _ => None,
+        };
+
+        match folded {
+            Some(constant) => {
+                self.constants.insert(output, constant);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn unary<F>(&mut self, node: &NodeProto, position: usize, expr: F) -> Result<(), ImportError>
+    where
+        F: FnOnce(String) -> String,
+    {
+        let input = required(node, position)?;
+        let value = self.value(input)?;
+        let expr = expr(self.take(input)?);
+
+        self.emit(node, value.kind, value.shape, expr)
+    }
+
+    /// Same as [unary](Self::unary) with the first input, borrowed by the expression.
+    fn unary_ref<F>(&mut self, node: &NodeProto, expr: F) -> Result<(), ImportError>
+    where
+        F: FnOnce(String) -> String,
+    {
+        let input = required(node, 0)?;
+        let value = self.value(input)?;
+        let expr = expr(self.borrow(input)?);
+
+        self.emit(node, value.kind, value.shape, expr)
+    }
+
+    fn binary(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let op = node.op_type.as_str();
+        let method = op.to_lowercase();
+        let (lhs, rhs) = (required(node, 0)?, required(node, 1)?);
+
+        if let Some(value) = self.scalar(rhs) {
+            return self.unary_ref(node, |input| {
+                format!("{}.{}_scalar({})", input, method, float(value))
+            });
+        }
+
+        if let Some(value) = self.scalar(lhs) {
+            let input = self.value(rhs)?;
+            let tensor = self.borrow(rhs)?;
+            let expr = match op {
+                "Sub" => format!("{}.neg().add_scalar({})", tensor, float(value)),
+                "Div" => format!("{}.powf(-1.0).mul_scalar({})", tensor, float(value)),
+                _ => format!("{}.{}_scalar({})", tensor, method, float(value)),
+            };
+            return self.emit(node, input.kind, input.shape, expr);
+        }
+
+        let (lhs_value, rhs_value) = (self.value(lhs)?, self.value(rhs)?);
+        let shape = broadcast(&lhs_value.shape, &rhs_value.shape).ok_or_else(|| {
+            ImportError::InvalidModel(format!(
+                "Can't broadcast the inputs of {} with shapes {:?} and {:?}",
+                op, lhs_value.shape, rhs_value.shape
+            ))
+        })?;
+        let rank = shape.len();
+        let expr = format!(
+            "{}.{}(&{})",
+            self.operand(lhs, rank)?,
+            method,
+            self.operand(rhs, rank)?
+        );
+
+        self.emit(node, lhs_value.kind, shape, expr)
+    }
+
+    fn matmul(&mut self, position: usize) -> Result<(), ImportError> {
+        let nodes = self.nodes;
+        let node = &nodes[position];
+        let (lhs, rhs) = (required(node, 0)?, required(node, 1)?);
+        let input = self.value(lhs)?;
+        let weight = self.weight(rhs, 2);
+
+        if let (Some(weight), true) = (weight, input.shape.len() >= 2) {
+            let [d_input, d_output] = [weight.shape[0], weight.shape[1]];
+            let mut output = node;
+            let mut bias = None;
+
+            // The addition of a bias following the multiplication is part of the linear layer.
+            let product = self::output(node)?;
+            if let Some(consumer) = self.consumer(position, product) {
+                let add = &nodes[consumer];
+                let other = add.input.iter().find(|name| *name != product);
+
+                if let (true, Some(other)) = (add.op_type == "Add", other) {
+                    if let Some(constant) = self.constant(other)? {
+                        let vector = constant.shape.last() == Some(&d_output)
+                            && constant.shape.len() <= input.shape.len()
+                            && constant.values.len() == d_output;
+
+                        if constant.kind == Kind::Float && vector {
+                            bias = Some(constant.values);
+                            output = add;
+                            self.fused.insert(consumer);
+                        }
+                    }
+                }
+            }
+
+            let field = self.linear(&weight.hint, d_input, d_output, weight.values, bias);
+            let mut shape = input.shape;
+            let rank = shape.len();
+            shape[rank - 1] = Dim::from(d_output);
+            let expr = format!("self.{}.forward({})", field, self.take(lhs)?);
+
+            return self.emit(output, Kind::Float, shape, expr);
+        }
+
+        let other = self.value(rhs)?;
+        if input.shape.len() < 2 || other.shape.len() < 2 {
+            return Err(ImportError::UnsupportedOp(
+                "MatMul of one dimensional tensors".to_string(),
+            ));
+        }
+
+        let [lhs_batch, rhs_batch] =
+            [&input.shape, &other.shape].map(|shape| shape[..shape.len() - 2].to_vec());
+        let mut shape = broadcast(&lhs_batch, &rhs_batch).ok_or_else(|| {
+            ImportError::InvalidModel(format!(
+                "Can't broadcast the inputs of MatMul with shapes {:?} and {:?}",
+                input.shape, other.shape
+            ))
+        })?;
+        shape.push(input.shape[input.shape.len() - 2].clone());
+        shape.push(other.shape[other.shape.len() - 1].clone());
+        let rank = shape.len();
+        let expr = format!(
+            "{}.matmul(&{})",
+            self.operand(lhs, rank)?,
+            self.operand(rhs, rank)?
+        );
+
+        self.emit(node, Kind::Float, shape, expr)
+    }
+
+    fn gemm(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let (lhs, rhs) = (required(node, 0)?, required(node, 1)?);
+        let input = self.value(lhs)?;
+        let scaled = attr_float(node, "alpha", 1.0) != 1.0 || attr_float(node, "beta", 1.0) != 1.0;
+
+        let weight = match self.weight(rhs, 2) {
+            Some(weight) if !scaled && attr_int(node, "transA", 0) == 0 => weight,
+            _ => return Err(unsupported(node)),
+        };
+        let (d_input, d_output, weights) = match attr_int(node, "transB", 0) {
+            0 => (weight.shape[0], weight.shape[1], weight.values),
+            _ => (
+                weight.shape[1],
+                weight.shape[0],
+                transpose(&weight.values, weight.shape[0], weight.shape[1]),
+            ),
+        };
+        let bias = match input_name(node, 2) {
+            Some(name) => match self.constants.get(name) {
+                Some(bias) if bias.values.len() == d_output => Some(bias.values.clone()),
+                Some(bias) if bias.values.len() == 1 => Some(vec![bias.values[0]; d_output]),
+                _ => return Err(unsupported(node)),
+            },
+            None => None,
+        };
+
+        let field = self.linear(&weight.hint, d_input, d_output, weights, bias);
+        let expr = format!("self.{}.forward({})", field, self.take(lhs)?);
+
+        let shape = vec![input.shape[0].clone(), Dim::from(d_output)];
+        self.emit(node, Kind::Float, shape, expr)
+    }
+
+    fn embedding(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let (data, indices) = (required(node, 0)?, required(node, 1)?);
+        let weight = self.weight(data, 2);
+        let input = self.value(indices)?;
+
+        let weight = match weight {
+            Some(weight) if attr_int(node, "axis", 0) == 0 && input.kind == Kind::Int => weight,
+            _ => return Err(unsupported(node)),
+        };
+        let [n_embedding, d_model] = [weight.shape[0], weight.shape[1]];
+
+        let field = self.module_field(&weight.hint, "embedding");
+        let state = named(vec![("weight", param(&weight.shape, &weight.values))]);
+        self.push_module(
+            field.clone(),
+            "nn::Embedding<B>",
+            format!(
+                "nn::Embedding::new(&nn::EmbeddingConfig::new({}, {}))",
+                n_embedding, d_model
+            ),
+            state,
+        );
+
+        let (expr, shape) = match input.shape.as_slice() {
+            [batch_size, seq_length] => (
+                format!("self.{}.forward({})", field, self.take(indices)?),
+                vec![batch_size.clone(), seq_length.clone(), Dim::from(d_model)],
+            ),
+            [size] => (
+                format!(
+                    "self.{}.forward({}.reshape([1, {}])).reshape([{}, {}])",
+                    field,
+                    self.borrow(indices)?,
+                    size,
+                    size,
+                    d_model
+                ),
+                vec![size.clone(), Dim::from(d_model)],
+            ),
+            _ => return Err(unsupported(node)),
+        };
+
+        self.emit(node, Kind::Float, shape, expr)
+    }
+
+    fn layer_norm(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let input = required(node, 0)?;
+        let value = self.value(input)?;
+        let rank = value.shape.len();
+        let d_model = value.shape[rank - 1].size().ok_or_else(|| {
+            ImportError::UnknownShape(format!(
+                "Dynamic last dimension of the input of LayerNormalization {}",
+                input
+            ))
+        })?;
+
+        let gamma = match self.weight(required(node, 1)?, 1) {
+            Some(gamma) if axis(attr_int(node, "axis", -1), rank)? == rank - 1 => gamma,
+            _ => return Err(unsupported(node)),
+        };
+        let beta = match input_name(node, 2) {
+            Some(name) => {
+                self.weight(name, 1)
+                    .ok_or_else(|| unsupported(node))?
+                    .values
+            }
+            None => vec![0.0; d_model],
+        };
+        if gamma.values.len() != d_model || beta.len() != d_model {
+            return Err(ImportError::InvalidModel(format!(
+                "Invalid shape of the scale and bias of LayerNormalization for inputs of shape {:?}",
+                value.shape
+            )));
+        }
+
+        let epsilon = attr_float(node, "epsilon", 1e-5);
+        let field = self.module_field(&gamma.hint, "norm");
+        let state = named(vec![
+            ("gamma", param(&[d_model], &gamma.values)),
+            ("beta", param(&[d_model], &beta)),
+        ]);
+        self.push_module(
+            field.clone(),
+            "nn::LayerNorm<B>",
+            format!(
+                "nn::LayerNorm::new(&nn::LayerNormConfig::new({}).with_epsilon({}))",
+                d_model,
+                // The attribute is a single precision float.
+                float(epsilon.to_string().parse().unwrap())
+            ),
+            state,
+        );
+
+        let expr = format!("self.{}.forward({})", field, self.take(input)?);
+        self.emit(node, Kind::Float, value.shape, expr)
+    }
+
+    fn transpose(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let input = required(node, 0)?;
+        let value = self.value(input)?;
+        let rank = value.shape.len();
+
+        let perm: Vec<usize> = match attr_ints(node, "perm") {
+            Some(perm) => perm
+                .iter()
+                .map(|dim| axis(*dim, rank))
+                .collect::<Result<_, _>>()?,
+            None => (0..rank).rev().collect(),
+        };
+        let mut sorted = perm.clone();
+        sorted.sort_unstable();
+        if sorted != (0..rank).collect::<Vec<_>>() {
+            return Err(ImportError::InvalidModel(format!(
+                "Invalid permutation {:?} of Transpose",
+                perm
+            )));
+        }
+
+        // The permutation is generated as a sequence of swapped dimensions.
+        let mut dims: Vec<usize> = (0..rank).collect();
+        let mut swaps = String::new();
+        for dim in 0..rank {
+            if dims[dim] != perm[dim] {
+                let other = dims.iter().position(|d| *d == perm[dim]).unwrap();
+                dims.swap(dim, other);
+                swaps += format!(".swap_dims({}, {})", dim, other).as_str();
+            }
+        }
+
+        if swaps.is_empty() {
+            return self.unary(node, 0, |input| input);
+        }
+
+        let shape = perm.iter().map(|dim| value.shape[*dim].clone()).collect();
+        let expr = format!("{}{}", self.borrow(input)?, swaps);
+        self.emit(node, value.kind, shape, expr)
+    }
+
+    fn reduce(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let input = required(node, 0)?;
+        let value = self.value(input)?;
+        let rank = value.shape.len();
+        let method = match node.op_type.as_str() {
+            "ReduceMean" => "mean",
+            _ => "sum",
+        };
+
+        let mut axes: Vec<usize> = match self.ints(node, 1, "axes")? {
+            Some(axes) if !axes.is_empty() => axes
+                .iter()
+                .map(|dim| axis(*dim, rank))
+                .collect::<Result<_, _>>()?,
+            _ => (0..rank).collect(),
+        };
+        axes.sort_unstable();
+        axes.dedup();
+
+        let keepdims = attr_int(node, "keepdims", 1) != 0;
+        let shape: Vec<Dim> = value
+            .shape
+            .iter()
+            .enumerate()
+            .filter_map(|(dim, size)| match axes.contains(&dim) {
+                true if keepdims => Some(Dim::from(1)),
+                true => None,
+                false => Some(size.clone()),
+            })
+            .collect();
+        let shape = non_scalar(shape);
+
+        let mut expr = self.borrow(input)?;
+        let reduced = if axes.len() == rank {
+            expr += format!(".{}()", method).as_str();
+            vec![Dim::from(1)]
+        } else {
+            for dim in axes.iter() {
+                expr += format!(".{}_dim({})", method, dim).as_str();
+            }
+            let mut reduced = value.shape.clone();
+            axes.iter().for_each(|dim| reduced[*dim] = Dim::from(1));
+            reduced
+        };
+        if reduced != shape {
+            expr += format!(".reshape({})", dims(&shape)).as_str();
+        }
+
+        self.emit(node, value.kind, shape, expr)
+    }
+
+    fn arg(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let input = required(node, 0)?;
+        let value = self.value(input)?;
+        let dim = axis(attr_int(node, "axis", 0), value.shape.len())?;
+        let method = node.op_type.to_lowercase();
+
+        let mut shape = value.shape.clone();
+        shape[dim] = Dim::from(1);
+        let mut expr = format!("{}.{}({})", self.borrow(input)?, method, dim);
+
+        if attr_int(node, "keepdims", 1) == 0 {
+            shape.remove(dim);
+            shape = non_scalar(shape);
+            expr += format!(".reshape({})", dims(&shape)).as_str();
+        }
+
+        self.emit(node, Kind::Int, shape, expr)
+    }
+
+    fn concat(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let inputs: Vec<&str> = node
+            .input
+            .iter()
+            .filter(|input| !input.is_empty())
+            .map(String::as_str)
+            .collect();
+        let first = self.value(required(node, 0)?)?;
+        let rank = first.shape.len();
+        let dim = axis(attr_int(node, "axis", 0), rank)?;
+
+        let mut size = 0;
+        let mut tensors = Vec::new();
+        for input in inputs {
+            let value = self.value(input)?;
+            if value.shape.len() != rank {
+                return Err(ImportError::InvalidModel(format!(
+                    "Can't concatenate tensors of shapes {} and {}",
+                    dims(&first.shape),
+                    dims(&value.shape)
+                )));
+            }
+
+            size += value.shape[dim].size().ok_or_else(|| {
+                ImportError::UnknownShape(format!(
+                    "Concat along the dynamic dimension {} of {}",
+                    dim, input
+                ))
+            })?;
+            tensors.push(self.take(input)?);
+        }
+
+        let mut shape = first.shape.clone();
+        shape[dim] = Dim::from(size);
+        let expr = format!("Tensor::cat(vec![{}], {})", tensors.join(", "), dim);
+        self.emit(node, first.kind, shape, expr)
+    }
+
+    fn slice(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let input = required(node, 0)?;
+        let value = self.value(input)?;
+        let rank = value.shape.len();
+        let missing =
+            || ImportError::InvalidModel("Missing the starts and ends of Slice".to_string());
+
+        let starts = self.ints(node, 1, "starts")?.ok_or_else(missing)?;
+        let ends = self.ints(node, 2, "ends")?.ok_or_else(missing)?;
+        let axes = self
+            .ints(node, 3, "axes")?
+            .unwrap_or_else(|| (0..starts.len() as i64).collect());
+        if let Some(steps) = self.ints(node, 4, "steps")? {
+            if steps.iter().any(|step| *step != 1) {
+                return Err(ImportError::UnsupportedOp(
+                    "Slice with steps other than one".to_string(),
+                ));
+            }
+        }
+
+        let mut shape = value.shape.clone();
+        let mut ranges: Vec<String> = value
+            .shape
+            .iter()
+            .map(|size| format!("0..{}", size))
+            .collect();
+        for ((start, end), dim) in starts.iter().zip(ends.iter()).zip(axes.iter()) {
+            let dim = axis(*dim, rank)?;
+            let size = value.shape[dim].size().ok_or_else(|| {
+                ImportError::UnknownShape(format!(
+                    "Slice of the dynamic dimension {} of {}",
+                    dim, input
+                ))
+            })? as i64;
+            let clamp = |index: i64| match index < 0 {
+                true => (index + size).clamp(0, size),
+                false => index.clamp(0, size),
+            } as usize;
+
+            let start = clamp(*start);
+            let end = clamp(*end).max(start);
+            shape[dim] = Dim::from(end - start);
+            ranges[dim] = format!("{}..{}", start, end);
+        }
+
+        let expr = format!("{}.index([{}])", self.borrow(input)?, ranges.join(", "));
+        self.emit(node, value.kind, shape, expr)
+    }
+
+    fn tile(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let input = required(node, 0)?;
+        let value = self.value(input)?;
+        let repeats = self
+            .ints(node, 1, "repeats")?
+            .ok_or_else(|| ImportError::InvalidModel("Missing the repeats of Tile".to_string()))?;
+
+        let mut shape = value.shape.clone();
+        let mut expr = self.borrow(input)?;
+        for (dim, times) in repeats.iter().enumerate() {
+            if *times == 1 {
+                continue;
+            }
+            // Only the dimensions of size one can be repeated.
+            if shape.get(dim).and_then(Dim::size) != Some(1) {
+                return Err(ImportError::UnsupportedOp(
+                    "Tile of dimensions larger than one".to_string(),
+                ));
+            }
+
+            shape[dim] = Dim::from(*times as usize);
+            expr += format!(".repeat({}, {})", dim, times).as_str();
+        }
+
+        self.emit(node, value.kind, shape, expr)
+    }
+
+    fn constant_of_shape(&mut self, node: &NodeProto) -> Result<(), ImportError> {
+        let shape: Vec<usize> = self
+            .ints(node, 0, "")?
+            .ok_or_else(|| {
+                ImportError::InvalidModel("Missing the shape of ConstantOfShape".to_string())
+            })?
+            .iter()
+            .map(|size| *size as usize)
+            .collect();
+        let shape = non_scalar(shape);
+
+        let (kind, value) = match node.attribute("value").and_then(|value| value.t.as_ref()) {
+            Some(tensor) => (
+                Kind::of(tensor.data_type).ok_or_else(|| unsupported(node))?,
+                data::values(tensor).map_err(ImportError::InvalidModel)?[0],
+            ),
+            None => (Kind::Float, 0.0),
+        };
+
+        let tensor = match kind {
+            Kind::Float => format!("Tensor::<B, {}>", shape.len()),
+            Kind::Int => format!("Tensor::<B::IntegerBackend, {}>", shape.len()),
+        };
+        let expr = match value {
+            0.0 => format!("{}::zeros({})", tensor, dims(&shape)),
+            1.0 => format!("{}::ones({})", tensor, dims(&shape)),
+            value => format!(
+                "{}::zeros({}).add_scalar({})",
+                tensor,
+                dims(&shape),
+                float(value)
+            ),
+        };
+
+        self.emit(node, kind, fixed(&shape), expr)
+    }
+
+    fn linear(
+        &mut self,
+        hint: &str,
+        d_input: usize,
+        d_output: usize,
+        weight: Vec<f64>,
+        bias: Option<Vec<f64>>,
+    ) -> String {
+        let field = self.module_field(hint, "linear");
+        // The layers are created with a bias, zero when the ONNX model has none, so that they
+        // are loaded from the same state.
+        let bias = bias.unwrap_or_else(|| vec![0.0; d_output]);
+        let state = named(vec![
+            ("weight", param(&[d_input, d_output], &weight)),
+            ("bias", param(&[d_output], &bias)),
+        ]);
+
+        self.push_module(
+            field.clone(),
+            "nn::Linear<B>",
+            format!(
+                "nn::Linear::new(&nn::LinearConfig::new({}, {}))",
+                d_input, d_output
+            ),
+            state,
+        );
+
+        field
+    }
+
+    fn push_module(&mut self, name: String, ty: &str, init: String, state: State<f32>) {
+        self.uses_nn = true;
+        self.fields.push(Field {
+            name,
+            ty: format!("Param<{}>", ty),
+            init: format!("Param::new({})", init),
+            state: with_id(state),
+        });
+    }
+
+    /// Returns the name of the field of a module, from the name of its weight.
+    fn module_field(&mut self, hint: &str, fallback: &str) -> String {
+        let name = ["weight", "bias", "gamma", "beta", "scale"]
+            .iter()
+            .find_map(|suffix| hint.strip_suffix(suffix))
+            .unwrap_or(hint);
+
+        unique(&mut self.field_names, ident(name, fallback))
+    }
+
+    /// Returns a constant, folding the node computing it when it comes later in the graph,
+    /// such as a bias reshaped after a matrix multiplication.
+    fn constant(&mut self, name: &str) -> Result<Option<Constant>, ImportError> {
+        if !self.constants.contains_key(name) {
+            let nodes = self.nodes;
+            let producer = nodes
+                .iter()
+                .position(|node| node.output.iter().any(|output| output == name));
+
+            if let Some(position) = producer {
+                if !self.fused.contains(&position) && self.fold(&nodes[position])? {
+                    self.fused.insert(position);
+                }
+            }
+        }
+
+        Ok(self.constants.get(name).cloned())
+    }
+
+    /// Returns a float constant of the given rank, used as the weight of a module.
+    fn weight(&self, name: &str, rank: usize) -> Option<Constant> {
+        self.constants
+            .get(name)
+            .filter(|constant| constant.kind == Kind::Float && constant.shape.len() == rank)
+            .cloned()
+    }
+
+    fn scalar(&self, name: &str) -> Option<f64> {
+        self.constants
+            .get(name)
+            .filter(|constant| constant.values.len() == 1)
+            .map(|constant| constant.values[0])
+    }
+
+    /// Returns the integers of a constant input, or of an attribute for the previous versions
+    /// of the operation.
+    fn ints(
+        &self,
+        node: &NodeProto,
+        position: usize,
+        attribute: &str,
+    ) -> Result<Option<Vec<i64>>, ImportError> {
+        if let Some(name) = input_name(node, position) {
+            let constant = self.constants.get(name).ok_or_else(|| {
+                ImportError::UnknownShape(format!(
+                    "The input {} of {} isn't a constant",
+                    name, node.op_type
+                ))
+            })?;
+
+            return Ok(Some(
+                constant.values.iter().map(|value| *value as i64).collect(),
+            ));
+        }
+
+        Ok(attr_ints(node, attribute))
+    }
+
+    /// Returns the output shape of the operations changing the shape of a tensor.
+    fn reshaped(&self, node: &NodeProto, shape: &[Dim]) -> Result<Vec<Dim>, ImportError> {
+        let rank = shape.len();
+        let num_elements = product(shape);
+
+        let reshaped = match node.op_type.as_str() {
+            "Reshape" => {
+                let target = self.ints(node, 1, "shape")?.ok_or_else(|| {
+                    ImportError::InvalidModel("Missing the shape of Reshape".to_string())
+                })?;
+                let invalid = || {
+                    ImportError::InvalidModel(format!(
+                        "Can't reshape a tensor of shape {} to {:?}",
+                        dims(shape),
+                        target
+                    ))
+                };
+
+                let mut inferred = None;
+                let mut reshaped = Vec::with_capacity(target.len());
+                for (dim, size) in target.iter().enumerate() {
+                    let size = match size {
+                        0 => shape.get(dim).cloned().ok_or_else(invalid)?,
+                        -1 if inferred.is_none() => {
+                            inferred = Some(dim);
+                            Dim::from(1)
+                        }
+                        size if *size > 0 => Dim::from(*size as usize),
+                        _ => return Err(invalid()),
+                    };
+                    reshaped.push(size);
+                }
+
+                if let Some(dim) = inferred {
+                    reshaped[dim] = num_elements.div(&product(&reshaped)).ok_or_else(invalid)?;
+                }
+                reshaped
+            }
+            "Flatten" => {
+                let dim = match attr_int(node, "axis", 1) {
+                    // The axis can be the rank, flattening to a single row.
+                    dim if dim == rank as i64 => rank,
+                    dim => axis(dim, rank)?,
+                };
+                vec![product(&shape[..dim]), product(&shape[dim..])]
+            }
+            "Squeeze" => match self.ints(node, 1, "axes")? {
+                Some(axes) => {
+                    let axes: Vec<usize> = axes
+                        .iter()
+                        .map(|dim| axis(*dim, rank))
+                        .collect::<Result<_, _>>()?;
+                    shape
+                        .iter()
+                        .enumerate()
+                        .filter(|(dim, _)| !axes.contains(dim))
+                        .map(|(_, size)| size.clone())
+                        .collect()
+                }
+                None => shape
+                    .iter()
+                    .filter(|size| size.size() != Some(1))
+                    .cloned()
+                    .collect(),
+            },
+            "Unsqueeze" => {
+                let axes = self.ints(node, 1, "axes")?.ok_or_else(|| {
+                    ImportError::InvalidModel("Missing the axes of Unsqueeze".to_string())
+                })?;
+                let rank = rank + axes.len();
+                let mut axes: Vec<usize> = axes
+                    .iter()
+                    .map(|dim| axis(*dim, rank))
+                    .collect::<Result<_, _>>()?;
+                axes.sort_unstable();
+
+                let mut reshaped = shape.to_vec();
+                axes.iter()
+                    .for_each(|dim| reshaped.insert(*dim, Dim::from(1)));
+                reshaped
+            }
+            _ => shape.to_vec(),
+        };
+
+        Ok(reshaped)
+    }
+
+    /// Returns the node consuming an output, if it is its only use.
+    fn consumer(&self, position: usize, output: &str) -> Option<usize> {
+        if self.uses.get(output) != Some(&1) {
+            return None;
+        }
+
+        self.nodes
+            .iter()
+            .enumerate()
+            .skip(position + 1)
+            .find(|(_, node)| node.input.iter().any(|input| input == output))
+            .map(|(position, _)| position)
+    }
+
+    /// Returns the tensor of a name, the float constants being added as parameters.
+    fn value(&mut self, name: &str) -> Result<Value, ImportError> {
+        if let Some(value) = self.values.get(name) {
+            return Ok(value.clone());
+        }
+
+        let constant = match self.constants.get(name) {
+            Some(constant) if constant.kind == Kind::Float => constant.clone(),
+            Some(_) => {
+                return Err(ImportError::UnsupportedOp(format!(
+                    "Integer constant {} used as a tensor",
+                    name
+                )))
+            }
+            None => {
+                return Err(ImportError::InvalidModel(format!(
+                    "Unknown tensor {}",
+                    name
+                )))
+            }
+        };
+
+        let field = unique(&mut self.field_names, ident(&constant.hint, "param"));
+        let shape = non_scalar(constant.shape);
+        self.fields.push(Field {
+            name: field.clone(),
+            ty: format!("Param<{}>", Kind::Float.tensor(shape.len())),
+            init: format!("Param::new(Tensor::zeros({}))", dims(&shape)),
+            state: param(&shape, &constant.values),
+        });
+
+        let value = Value {
+            expr: format!("self.{}", field),
+            kind: Kind::Float,
+            shape: fixed(&shape),
+            local: false,
+        };
+        self.values.insert(name.to_string(), value.clone());
+
+        Ok(value)
+    }
+
+    /// Returns the expression of a tensor used by reference.
+    fn borrow(&mut self, name: &str) -> Result<String, ImportError> {
+        let value = self.value(name)?;
+        self.used(name);
+
+        Ok(value.expr)
+    }
+
+    /// Returns the expression of a tensor used by value, which is moved on its last use.
+    fn take(&mut self, name: &str) -> Result<String, ImportError> {
+        let value = self.value(name)?;
+
+        match self.used(name) && value.local {
+            true => Ok(value.expr),
+            false => Ok(format!("{}.clone()", value.expr)),
+        }
+    }
+
+    /// Returns the expression of a tensor used by reference, with the given rank.
+    fn operand(&mut self, name: &str, rank: usize) -> Result<String, ImportError> {
+        let value = self.value(name)?;
+        let expr = self.borrow(name)?;
+
+        match value.shape.len() < rank {
+            true => Ok(format!("{}.unsqueeze::<{}>()", expr, rank)),
+            false => Ok(expr),
+        }
+    }
+
+    /// Decrement the uses of a tensor, returning if it was the last one.
+    fn used(&mut self, name: &str) -> bool {
+        let uses = self.uses.entry(name.to_string()).or_insert(0);
+        *uses = uses.saturating_sub(1);
+
+        *uses == 0
+    }
+
+    fn emit(
+        &mut self,
+        node: &NodeProto,
+        kind: Kind,
+        shape: Vec<Dim>,
+        expr: String,
+    ) -> Result<(), ImportError> {
+        let output = output(node)?;
+        let var = unique(&mut self.var_names, ident(output, "x"));
+        self.last = Some((var.clone(), expr.clone(), self.body.len()));
+        self.body += format!("        let {} = {};\n", var, expr).as_str();
+
+        let value = Value {
+            expr: var,
+            kind,
+            shape: non_scalar(shape),
+            local: true,
+        };
+        self.values.insert(output.to_string(), value);
+
+        Ok(())
+    }
+
+    fn build(mut self, graph: &GraphProto, name: &str) -> Result<ImportedModule, ImportError> {
+        let mut outputs = Vec::new();
+        let mut types = Vec::new();
+        for output in graph.output.iter() {
+            let value = self.value(&output.name)?;
+            outputs.push(self.take(&output.name)?);
+            types.push(value.kind.tensor(value.shape.len()));
+        }
+        let (outputs, types) = match outputs.len() {
+            1 => {
+                let mut output = outputs.remove(0);
+
+                // The last statement is returned directly when it computes the output.
+                if let Some((_, expr, len)) = self.last.take().filter(|last| last.0 == output) {
+                    self.body.truncate(len);
+                    output = expr;
+                }
+
+                (output, types.remove(0))
+            }
+            _ => (
+                format!("({})", outputs.join(", ")),
+                format!("({})", types.join(", ")),
+            ),
+        };
+
+        // The sizes of the dynamic dimensions are declared first, when they are used.
+        let words: HashSet<&str> = self
+            .body
+            .split(|char: char| !char.is_ascii_alphanumeric() && char != '_')
+            .chain(outputs.split(|char: char| !char.is_ascii_alphanumeric() && char != '_'))
+            .collect();
+        let mut symbols: Vec<&(String, String)> = self
+            .symbols
+            .values()
+            .filter(|(var, _)| words.contains(var.as_str()))
+            .collect();
+        symbols.sort();
+        let statements: String = symbols
+            .iter()
+            .map(|(_, statement)| statement.as_str())
+            .collect();
+        self.body.insert_str(0, statements.as_str());
+
+        // Modules without parameters still need to use their backend.
+        if self.fields.is_empty() {
+            self.fields.push(Field {
+                name: "phantom".to_string(),
+                ty: "PhantomData<B>".to_string(),
+                init: "PhantomData".to_string(),
+                state: State::StateNamed(StateNamed::new()),
+            });
+        }
+        let params = self
+            .fields
+            .iter()
+            .any(|field| field.ty.starts_with("Param"));
+
+        let mut source = String::new();
+        source += "// Generated by burn-onnx.\n";
+        match params {
+            true => source += "use burn::module::{Module, Param};\n",
+            false => source += "use burn::module::Module;\n",
+        }
+        if self.uses_nn {
+            source += "use burn::nn;\n";
+        }
+        if self.uses_activation {
+            source += "use burn::tensor::activation;\n";
+        }
+        source += "use burn::tensor::backend::Backend;\n";
+        source += "use burn::tensor::Tensor;\n";
+        if !params {
+            source += "use std::marker::PhantomData;\n";
+        }
+        source += "\n";
+
+        source += "#[derive(Module, Debug)]\n";
+        source += format!("pub struct {}<B: Backend> {{\n", name).as_str();
+        for field in self.fields.iter() {
+            source += format!("    {}: {},\n", field.name, field.ty).as_str();
+        }
+        source += "}\n\n";
+
+        source += format!("impl<B: Backend> {}<B> {{\n", name).as_str();
+        source += "    /// Create the module with random weights, to be loaded from its state.\n";
+        source += "    pub fn new() -> Self {\n";
+        source += "        Self {\n";
+        for field in self.fields.iter() {
+            source += format!("            {}: {},\n", field.name, field.init).as_str();
+        }
+        source += "        }\n";
+        source += "    }\n\n";
+        source += format!(
+            "    pub fn forward(&self, {}) -> {} {{\n",
+            self.inputs.join(", "),
+            types
+        )
+        .as_str();
+        source += self.body.as_str();
+        if !self.body.is_empty() {
+            source += "\n";
+        }
+        source += format!("        {}\n", outputs).as_str();
+        source += "    }\n";
+        source += "}\n\n";
+
+        source += format!("impl<B: Backend> Default for {}<B> {{\n", name).as_str();
+        source += "    fn default() -> Self {\n";
+        source += "        Self::new()\n";
+        source += "    }\n";
+        source += "}\n";
+
+        let mut state = StateNamed::new();
+        for field in self.fields.into_iter().filter(|_| params) {
+            state.register_state(&field.name, field.state);
+        }
+
+        Ok(ImportedModule {
+            source,
+            state: State::StateNamed(state),
+        })
+    }
+}
+
+fn constant(hint: String, tensor: &TensorProto) -> Result<Constant, ImportError> {
+    let kind = Kind::of(tensor.data_type).unwrap_or(Kind::Int);
+    let values = data::values(tensor)
+        .map_err(|err| ImportError::InvalidModel(format!("{}: {}", tensor.name, err)))?;
+
+    Ok(Constant {
+        hint,
+        shape: tensor.dims.iter().map(|dim| *dim as usize).collect(),
+        values,
+        kind,
+    })
+}
+
+fn constant_attribute(output: &str, node: &NodeProto) -> Result<Constant, ImportError> {
+    let hint = output.to_string();
+
+    if let Some(tensor) = node.attribute("value").and_then(|value| value.t.as_ref()) {
+        return constant(hint, tensor);
+    }
+
+    let (shape, values, kind) = if let Some(value) = node.attribute("value_float") {
+        (vec![], vec![value.f as f64], Kind::Float)
+    } else if let Some(value) = node.attribute("value_floats") {
+        let values: Vec<f64> = value.floats.iter().map(|value| *value as f64).collect();
+        (vec![values.len()], values, Kind::Float)
+    } else if let Some(value) = node.attribute("value_int") {
+        (vec![], vec![value.i as f64], Kind::Int)
+    } else if let Some(value) = node.attribute("value_ints") {
+        let values: Vec<f64> = value.ints.iter().map(|value| *value as f64).collect();
+        (vec![values.len()], values, Kind::Int)
+    } else {
+        return Err(unsupported(node));
+    };
+
+    Ok(Constant {
+        hint,
+        shape,
+        values,
+        kind,
+    })
+}
+
+fn ints(hint: &str, values: &[usize]) -> Constant {
+    Constant {
+        hint: hint.to_string(),
+        shape: vec![values.len()],
+        values: values.iter().map(|value| *value as f64).collect(),
+        kind: Kind::Int,
+    }
+}
+
+/// Returns the type of an input, with the sizes of its dimensions or the symbols of its dynamic
+/// dimensions.
+fn value_type(value: &ValueInfoProto) -> Result<(Kind, Vec<Result<usize, String>>), ImportError> {
+    let tensor = value
+        .r#type
+        .as_ref()
+        .and_then(|ty| ty.tensor_type.as_ref())
+        .ok_or_else(|| ImportError::UnknownShape(format!("Input {}", value.name)))?;
+    let kind = Kind::of(tensor.elem_type).ok_or_else(|| {
+        ImportError::UnsupportedOp(format!("Input {} of type {}", value.name, tensor.elem_type))
+    })?;
+
+    let dims = tensor.shape.as_ref().map(|shape| shape.dim.as_slice());
+    let shape = dims
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .map(|(position, dim)| match (dim.dim_value, &dim.dim_param) {
+            (Some(size), _) if size >= 0 => Ok(size as usize),
+            (_, Some(symbol)) if !symbol.is_empty() => Err(symbol.clone()),
+            // Dimensions without a symbol don't share their size with other dimensions.
+            _ => Err(format!("{}_dim_{}", value.name, position)),
+        })
+        .collect();
+
+    Ok((kind, shape))
+}
+
+fn unsupported(node: &NodeProto) -> ImportError {
+    ImportError::UnsupportedOp(node.op_type.clone())
+}
+
+fn output(node: &NodeProto) -> Result<&str, ImportError> {
+    node.output
+        .first()
+        .map(String::as_str)
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| ImportError::InvalidModel(format!("Missing output of {}", node.op_type)))
+}
+
+fn input_name(node: &NodeProto, position: usize) -> Option<&str> {
+    node.input
+        .get(position)
+        .map(String::as_str)
+        .filter(|name| !name.is_empty())
+}
+
+fn required(node: &NodeProto, position: usize) -> Result<&str, ImportError> {
+    input_name(node, position).ok_or_else(|| {
+        ImportError::InvalidModel(format!("Missing input {} of {}", position, node.op_type))
+    })
+}
+
+fn attr_int(node: &NodeProto, name: &str, default: i64) -> i64 {
+    node.attribute(name).map(|value| value.i).unwrap_or(default)
+}
+
+fn attr_float(node: &NodeProto, name: &str, default: f32) -> f32 {
+    node.attribute(name).map(|value| value.f).unwrap_or(default)
+}
+
+fn attr_ints(node: &NodeProto, name: &str) -> Option<Vec<i64>> {
+    node.attribute(name).map(|value| value.ints.clone())
+}
+
+fn axis(axis: i64, rank: usize) -> Result<usize, ImportError> {
+    let rank = rank as i64;
+
+    match axis {
+        axis if (0..rank).contains(&axis) => Ok(axis as usize),
+        axis if (-rank..0).contains(&axis) => Ok((axis + rank) as usize),
+        axis => Err(ImportError::InvalidModel(format!(
+            "Axis {} is out of bounds for a tensor of rank {}",
+            axis, rank
+        ))),
+    }
+}
+
+/// Returns the shape of a tensor resulting from the broadcast of two tensors.
+///
+/// Dynamic dimensions are assumed to match the known sizes they are broadcasted with.
+fn broadcast(lhs: &[Dim], rhs: &[Dim]) -> Option<Vec<Dim>> {
+    let rank = usize::max(lhs.len(), rhs.len());
+    let size = |shape: &[Dim], dim: usize| match dim + shape.len() < rank {
+        true => Dim::from(1),
+        false => shape[dim + shape.len() - rank].clone(),
+    };
+
+    (0..rank)
+        .map(|dim| match (size(lhs, dim), size(rhs, dim)) {
+            (lhs, rhs) if lhs == rhs || rhs.size() == Some(1) => Some(lhs),
+            (lhs, rhs) if lhs.size() == Some(1) => Some(rhs),
+            (lhs, rhs) => match (lhs.size(), rhs.size()) {
+                (None, Some(_)) => Some(rhs),
+                (Some(_), None) => Some(lhs),
+                _ => None,
+            },
+        })
+        .collect()
+}
+
+fn transpose(values: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+    (0..cols)
+        .flat_map(|col| (0..rows).map(move |row| values[row * cols + col]))
+        .collect()
+}
+
+/// Tensors have at least one dimension.
+fn non_scalar<T: From<usize>>(shape: Vec<T>) -> Vec<T> {
+    match shape.is_empty() {
+        true => vec![T::from(1)],
+        false => shape,
+    }
+}
+
+fn fixed(shape: &[usize]) -> Vec<Dim> {
+    shape.iter().map(|size| Dim::from(*size)).collect()
+}
+
+/// Returns the sizes of the dimensions, if none of them is dynamic.
+fn known(shape: &[Dim]) -> Option<Vec<usize>> {
+    shape.iter().map(Dim::size).collect()
+}
+
+fn product(shape: &[Dim]) -> Dim {
+    shape
+        .iter()
+        .fold(Dim::from(1), |product, size| product.mul(size))
+}
+
+fn dims<T: std::fmt::Display>(shape: &[T]) -> String {
+    let dims: Vec<String> = shape.iter().map(|size| size.to_string()).collect();
+    format!("[{}]", dims.join(", "))
+}
+
+fn float(value: f64) -> String {
+    match value {
+        value if value.is_nan() => "f32::NAN".to_string(),
+        value if value == f64::INFINITY => "f32::INFINITY".to_string(),
+        value if value == f64::NEG_INFINITY => "f32::NEG_INFINITY".to_string(),
+        // The single precision values are written without the digits of their conversion.
+        value if value as f32 as f64 == value => format!("{:?}", value as f32),
+        value => format!("{:?}", value),
+    }
+}
+
+const KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "yield",
+];
+
+/// Returns a snake case identifier from the name of an ONNX tensor.
+fn ident(name: &str, fallback: &str) -> String {
+    let mut ident = String::new();
+    for char in name.chars() {
+        if char.is_ascii_alphanumeric() {
+            ident.push(char.to_ascii_lowercase());
+        } else if !ident.is_empty() && !ident.ends_with('_') {
+            ident.push('_');
+        }
+    }
+    let ident = ident.trim_end_matches('_');
+
+    if ident.is_empty() {
+        return fallback.to_string();
+    }
+    if ident.starts_with(|char: char| char.is_ascii_digit()) || KEYWORDS.contains(&ident) {
+        return format!("{}_{}", fallback, ident);
+    }
+
+    ident.to_string()
+}
+
+fn unique(names: &mut HashSet<String>, ident: String) -> String {
+    let mut name = ident.clone();
+    let mut count = 1;
+
+    while !names.insert(name.clone()) {
+        count += 1;
+        name = format!("{}_{}", ident, count);
+    }
+
+    name
+}
+
+fn param(shape: &[usize], values: &[f64]) -> State<f32> {
+    let data = DataSerialize {
+        value: values.iter().map(|value| *value as f32).collect(),
+        shape: shape.to_vec(),
+    };
+
+    with_id(State::Data(data))
+}
+
+fn named(states: Vec<(&str, State<f32>)>) -> State<f32> {
+    let mut named = StateNamed::new();
+    for (name, state) in states {
+        named.register_state(name, state);
+    }
+
+    State::StateNamed(named)
+}
+
+/// Wrap the state of a parameter with its id, as done by [params](burn::module::Param).
+fn with_id(state: State<f32>) -> State<f32> {
+    named(vec![
+        ("data", state),
+        ("id", State::ParamId(ParamId::new())),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::tensor_proto;
+    use crate::export;
+    use burn::module::{Module, Param};
+    use burn::nn;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{Data, Tensor};
+    use burn_tracing::{ElemType, TracingBackend};
+
+    type TestBackend = burn_ndarray::NdArrayBackend<f32>;
+
+    fn node(op: &str, inputs: &[&str], output: &str, attribute: Vec<AttributeProto>) -> NodeProto {
+        NodeProto {
+            input: inputs.iter().map(|input| input.to_string()).collect(),
+            output: vec![output.to_string()],
+            op_type: op.to_string(),
+            attribute,
+            ..Default::default()
+        }
+    }
+
+    fn value(name: &str, elem_type: DataType, dims: &[i64]) -> ValueInfoProto {
+        let dim = dims
+            .iter()
+            .map(|dim| DimensionProto {
+                dim_value: Some(*dim),
+                dim_param: None,
+            })
+            .collect();
+
+        ValueInfoProto {
+            name: name.to_string(),
+            r#type: Some(TypeProto {
+                tensor_type: Some(TensorTypeProto {
+                    elem_type: elem_type as i32,
+                    shape: Some(TensorShapeProto { dim }),
+                }),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn weight(name: &str, dims: &[usize], values: &[f64]) -> TensorProto {
+        tensor_proto(name.to_string(), ElemType::Float32, dims, values)
+    }
+
+    fn model(
+        node: Vec<NodeProto>,
+        input: Vec<ValueInfoProto>,
+        initializer: Vec<TensorProto>,
+        output: &str,
+    ) -> ModelProto {
+        ModelProto {
+            ir_version: IR_VERSION,
+            opset_import: vec![OperatorSetIdProto {
+                domain: String::new(),
+                version: 17,
+            }],
+            graph: Some(GraphProto {
+                node,
+                input,
+                initializer,
+                output: vec![ValueInfoProto {
+                    name: output.to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[derive(Module, Debug)]
+    struct Classifier<B: Backend> {
+        fc1: Param<nn::Linear<B>>,
+    }
+
+    #[test]
+    fn should_import_gemm_as_linear() {
+        let onnx = model(
+            vec![
+                node(
+                    "Gemm",
+                    &["input", "fc1.weight", "fc1.bias"],
+                    "/fc1/Gemm_output_0",
+                    vec![AttributeProto::int("transB", 1)],
+                ),
+                node("Relu", &["/fc1/Gemm_output_0"], "output", Vec::new()),
+            ],
+            vec![value("input", DataType::Float, &[1, 2])],
+            vec![
+                weight("fc1.weight", &[3, 2], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+                weight("fc1.bias", &[3], &[0.5, -20.0, 0.0]),
+            ],
+            "output",
+        );
+
+        let imported = import(&onnx, "Classifier").unwrap();
+
+        assert!(imported
+            .source
+            .contains("pub struct Classifier<B: Backend>"));
+        assert!(imported.source.contains("fc1: Param<nn::Linear<B>>,"));
+        assert!(imported
+            .source
+            .contains("fc1: Param::new(nn::Linear::new(&nn::LinearConfig::new(2, 3))),"));
+        assert!(imported
+            .source
+            .contains("pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {"));
+        assert!(imported
+            .source
+            .contains("let fc1_gemm_output_0 = self.fc1.forward(input);"));
+        assert!(imported
+            .source
+            .contains("        activation::relu(&fc1_gemm_output_0)\n"));
+
+        let mut classifier = Classifier::<TestBackend> {
+            fc1: Param::new(nn::Linear::new(&nn::LinearConfig::new(2, 3))),
+        };
+        classifier.load(&imported.state).unwrap();
+        let input = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 1.0]]));
+        let output = classifier.fc1.forward(input);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.5, -13.0, 11.0]]), 3);
+    }
+
+    #[derive(Module, Debug)]
+    struct Encoder<B: Backend> {
+        embed: Param<nn::Embedding<B>>,
+        norm: Param<nn::LayerNorm<B>>,
+    }
+
+    #[test]
+    fn should_import_gather_and_layer_normalization_as_modules() {
+        let onnx = model(
+            vec![
+                node(
+                    "Gather",
+                    &["embed.weight", "tokens"],
+                    "embedded",
+                    Vec::new(),
+                ),
+                node(
+                    "LayerNormalization",
+                    &["embedded", "norm.weight", "norm.bias"],
+                    "output",
+                    vec![AttributeProto::float("epsilon", 1e-6)],
+                ),
+            ],
+            vec![value("tokens", DataType::Int64, &[1, 2])],
+            vec![
+                weight("embed.weight", &[4, 3], &[0.0; 12]),
+                weight("norm.weight", &[3], &[1.0, 2.0, 3.0]),
+                weight("norm.bias", &[3], &[0.0; 3]),
+            ],
+            "output",
+        );
+
+        let imported = import(&onnx, "Encoder").unwrap();
+
+        assert!(imported
+            .source
+            .contains("embed: Param::new(nn::Embedding::new(&nn::EmbeddingConfig::new(4, 3))),"));
+        assert!(imported.source.contains(
+            "norm: Param::new(nn::LayerNorm::new(&nn::LayerNormConfig::new(3).with_epsilon(1e-6))),"
+        ));
+        assert!(imported.source.contains(
+            "pub fn forward(&self, tokens: Tensor<B::IntegerBackend, 2>) -> Tensor<B, 3> {"
+        ));
+        assert!(imported
+            .source
+            .contains("let embedded = self.embed.forward(tokens);"));
+        assert!(imported
+            .source
+            .contains("        self.norm.forward(embedded)\n"));
+
+        let mut encoder = Encoder::<TestBackend> {
+            embed: Param::new(nn::Embedding::new(&nn::EmbeddingConfig::new(4, 3))),
+            norm: Param::new(nn::LayerNorm::new(&nn::LayerNormConfig::new(3))),
+        };
+        encoder.load(&imported.state).unwrap();
+        let state = encoder.norm.state();
+        let gamma = state.get("data").unwrap().get("gamma").unwrap();
+
+        assert_eq!(
+            gamma.get("data"),
+            Some(&State::Data(Data::from([1.0, 2.0, 3.0]).serialize()))
+        );
+    }
+
+    #[test]
+    fn should_import_exported_linear_with_its_weights() {
+        let config = nn::LinearConfig::new(4, 3);
+        let linear = nn::Linear::<TracingBackend<TestBackend>>::new(&config);
+        let input = Tensor::<TracingBackend<TestBackend>, 2>::ones([2, 4]);
+        let expected = linear.forward(input.clone()).to_data();
+
+        let onnx = export(&linear, input, |linear, input| linear.forward(input)).unwrap();
+        let imported = import(&onnx, "Imported").unwrap();
+
+        let state = match &imported.state {
+            State::StateNamed(state) => state,
+            _ => panic!("The state of a module is named"),
+        };
+        assert_eq!(state.values.len(), 1);
+        assert_eq!(imported.source.matches("nn::Linear::new").count(), 1);
+
+        let mut imported_linear = Param::new(nn::Linear::<TestBackend>::new(&config));
+        imported_linear
+            .load(state.values.values().next().unwrap())
+            .unwrap();
+        let output = imported_linear.forward(Tensor::ones([2, 4]));
+
+        output.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_generate_tensor_ops_with_computed_shapes() {
+        let onnx = model(
+            vec![
+                node(
+                    "Transpose",
+                    &["x"],
+                    "y",
+                    vec![AttributeProto::ints("perm", vec![2, 0, 1])],
+                ),
+                node("Shape", &["y"], "shape", Vec::new()),
+                node(
+                    "ReduceMean",
+                    &["y"],
+                    "z",
+                    vec![
+                        AttributeProto::ints("axes", vec![1]),
+                        AttributeProto::int("keepdims", 0),
+                    ],
+                ),
+                node("Softmax", &["z"], "probs", Vec::new()),
+                node("Reshape", &["probs", "new_shape"], "output", Vec::new()),
+            ],
+            vec![value("x", DataType::Float, &[2, 3, 4])],
+            vec![tensor_proto(
+                "new_shape".to_string(),
+                ElemType::Int64,
+                &[2],
+                &[-1.0, 2.0],
+            )],
+            "output",
+        );
+
+        let imported = import(&onnx, "Ops").unwrap();
+
+        let body: Vec<&str> = imported
+            .source
+            .lines()
+            .skip_while(|line| !line.contains("pub fn forward"))
+            .skip(1)
+            .take(4)
+            .map(str::trim)
+            .collect();
+        assert_eq!(
+            body,
+            vec![
+                "let y = x.swap_dims(0, 2).swap_dims(1, 2);",
+                "let z = y.mean_dim(1).reshape([4, 3]);",
+                "let probs = activation::softmax(&z, 1);",
+                "",
+            ]
+        );
+        assert!(imported.source.contains("        probs.reshape([6, 2])\n"));
+    }
+
+    #[test]
+    fn should_report_unsupported_ops() {
+        let onnx = model(
+            vec![node("Conv", &["x", "kernel"], "output", Vec::new())],
+            vec![value("x", DataType::Float, &[1, 1, 3])],
+            vec![weight("kernel", &[1, 1, 2], &[1.0, 1.0])],
+            "output",
+        );
+
+        let err = import(&onnx, "Model").err().unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            "Import error => Unsupported operation: Conv"
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_models_instead_of_panicking() {
+        let shape = |name: &str, values: &[f64]| {
+            tensor_proto(name.to_string(), ElemType::Int64, &[values.len()], values)
+        };
+        let cases = vec![
+            (
+                NodeProto {
+                    input: vec!["x".to_string()],
+                    op_type: "Relu".to_string(),
+                    ..Default::default()
+                },
+                Vec::new(),
+                "Missing output of Relu",
+            ),
+            (
+                node("Gather", &["values", "index"], "output", Vec::new()),
+                vec![shape("values", &[1.0, 2.0]), shape("index", &[2.0])],
+                "Index 2 of Gather is out of bounds for a constant of size 2",
+            ),
+            (
+                node(
+                    "Softmax",
+                    &["x"],
+                    "output",
+                    vec![AttributeProto::int("axis", -3)],
+                ),
+                Vec::new(),
+                "Axis -3 is out of bounds for a tensor of rank 2",
+            ),
+            (
+                node("Reshape", &["x", "new_shape"], "output", Vec::new()),
+                vec![shape("new_shape", &[-2.0, 3.0])],
+                "Can't reshape a tensor of shape [2, 3] to [-2, 3]",
+            ),
+        ];
+
+        for (node, initializer, expected) in cases {
+            let onnx = model(
+                vec![node],
+                vec![value("x", DataType::Float, &[2, 3])],
+                initializer,
+                "output",
+            );
+
+            let err = import(&onnx, "Model").err().unwrap();
+
+            assert_eq!(
+                err.to_string(),
+                format!("Import error => Invalid model: {}", expected)
+            );
+        }
+    }
+
+    #[test]
+    fn should_read_dynamic_dims_from_the_inputs() {
+        let mut input = value("x", DataType::Float, &[0, 3]);
+        if let Some(TypeProto {
+            tensor_type:
+                Some(TensorTypeProto {
+                    shape: Some(shape), ..
+                }),
+        }) = input.r#type.as_mut()
+        {
+            shape.dim[0] = DimensionProto {
+                dim_value: None,
+                dim_param: Some("batch_size".to_string()),
+            };
+        }
+        let onnx = model(
+            vec![node(
+                "Flatten",
+                &["x"],
+                "output",
+                vec![AttributeProto::int("axis", 0)],
+            )],
+            vec![input],
+            Vec::new(),
+            "output",
+        );
+
+        let imported = import(&onnx, "Model").unwrap();
+
+        assert!(imported.source.contains("let batch_size = x.dims()[0];\n"));
+        assert!(imported
+            .source
+            .contains("        x.reshape([1, batch_size * 3])\n"));
+    }
+}
@@ -0,0 +1,8 @@
+pub(crate) mod data;
+
+mod export;
+mod import;
+pub mod proto;
+
+pub use export::*;
+pub use import::*;
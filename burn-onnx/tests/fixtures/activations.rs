@@ -0,0 +1,34 @@
+// Generated by burn-onnx.
+use burn::module::Module;
+use burn::tensor::activation;
+use burn::tensor::backend::Backend;
+use burn::tensor::Tensor;
+use std::marker::PhantomData;
+
+#[derive(Module, Debug)]
+pub struct Activations<B: Backend> {
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend> Activations<B> {
+    /// Create the module with random weights, to be loaded from its state.
+    pub fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 1> {
+        let relu = activation::relu(&input);
+        let scaled = relu.mul_scalar(2.0);
+        let flat = scaled.reshape([6]);
+
+        activation::softmax(&flat, 0)
+    }
+}
+
+impl<B: Backend> Default for Activations<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,2 @@
+
+"Q<U;d;w?U;U;Boutput
\ No newline at end of file
@@ -0,0 +1,38 @@
+// Generated by burn-onnx.
+use burn::module::{Module, Param};
+use burn::nn;
+use burn::tensor::activation;
+use burn::tensor::backend::Backend;
+use burn::tensor::Tensor;
+
+#[derive(Module, Debug)]
+pub struct Mlp<B: Backend> {
+    fc1: Param<nn::Linear<B>>,
+    fc2: Param<nn::Linear<B>>,
+}
+
+impl<B: Backend> Mlp<B> {
+    /// Create the module with random weights, to be loaded from its state.
+    pub fn new() -> Self {
+        Self {
+            fc1: Param::new(nn::Linear::new(&nn::LinearConfig::new(4, 3))),
+            fc2: Param::new(nn::Linear::new(&nn::LinearConfig::new(3, 2))),
+        }
+    }
+
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 2> {
+        let batch_size = input.dims()[0];
+        let flatten_output_0 = input.reshape([batch_size, 4]);
+        let fc1_gemm_output_0 = self.fc1.forward(flatten_output_0);
+        let relu_relu_output_0 = activation::relu(&fc1_gemm_output_0);
+        let fc2_add_output_0 = self.fc2.forward(relu_relu_output_0);
+
+        activation::softmax(&fc2_add_output_0, 1)
+    }
+}
+
+impl<B: Backend> Default for Mlp<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
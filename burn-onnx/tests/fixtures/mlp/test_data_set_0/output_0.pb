@@ -0,0 +1,2 @@
+
+"Z?W>i?J,>Boutput
\ No newline at end of file
@@ -0,0 +1,73 @@
+//! Run the modules generated from the ONNX models of the fixtures, and compare their outputs
+//! with the reference outputs of the models, stored in the layout of the ONNX backend tests.
+//!
+//! The fixtures are generated again, from the `burn-onnx` directory, with:
+//!
+//! ```bash
+//! cargo run --bin onnx2burn -- tests/fixtures/mlp.onnx Mlp tests/fixtures
+//! cargo run --bin onnx2burn -- tests/fixtures/activations.onnx Activations tests/fixtures
+//! ```
+use burn::module::{Module, State};
+use burn_onnx::proto::{ModelProto, TensorProto};
+use burn_tensor::{Data, Shape, Tensor};
+use prost::Message;
+
+type TestBackend = burn_ndarray::NdArrayBackend<f32>;
+
+mod mlp {
+    include!("fixtures/mlp.rs");
+}
+
+mod activations {
+    include!("fixtures/activations.rs");
+}
+
+fn fixture(file: &str) -> String {
+    format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), file)
+}
+
+fn tensor<const D: usize>(file: &str) -> Tensor<TestBackend, D> {
+    let content = std::fs::read(fixture(file)).unwrap();
+    let tensor = TensorProto::decode(content.as_slice()).unwrap();
+    let dims: Vec<usize> = tensor.dims.iter().map(|dim| *dim as usize).collect();
+
+    Tensor::from_data(Data::new(
+        tensor.float_data,
+        Shape::new(dims.try_into().unwrap()),
+    ))
+}
+
+#[test]
+fn should_match_the_reference_outputs_of_mlp() {
+    let mut model = mlp::Mlp::<TestBackend>::new();
+    model
+        .load(&State::load(&fixture("mlp.json.gz")).unwrap())
+        .unwrap();
+
+    let output = model.forward(tensor::<3>("mlp/test_data_set_0/input_0.pb"));
+
+    let expected = tensor::<2>("mlp/test_data_set_0/output_0.pb");
+    output.to_data().assert_approx_eq(&expected.to_data(), 5);
+}
+
+#[test]
+fn should_match_the_reference_outputs_of_a_model_without_weights() {
+    let model = activations::Activations::<TestBackend>::new();
+
+    let output = model.forward(tensor::<2>("activations/test_data_set_0/input_0.pb"));
+
+    let expected = tensor::<1>("activations/test_data_set_0/output_0.pb");
+    output.to_data().assert_approx_eq(&expected.to_data(), 5);
+}
+
+#[test]
+fn should_generate_the_source_of_the_fixtures() {
+    for (file, name) in [("mlp", "Mlp"), ("activations", "Activations")] {
+        let model = ModelProto::load(&fixture(&format!("{}.onnx", file))).unwrap();
+
+        let imported = burn_onnx::import(&model, name).unwrap();
+
+        let source = std::fs::read_to_string(fixture(&format!("{}.rs", file))).unwrap();
+        assert_eq!(imported.source, source);
+    }
+}
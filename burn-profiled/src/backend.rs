@@ -0,0 +1,49 @@
+use burn_tensor::backend::Backend;
+use burn_tensor::{Generator, RngState};
+
+/// Backend decorator measuring the time spent in every operation of the inner backend.
+///
+/// The calls are recorded with the shapes of their inputs and output in a [profile](crate::profile).
+/// Decorated by [ADBackendDecorator](https://docs.rs/burn-autodiff), the operations of the
+/// backward pass are recorded as well.
+///
+/// The time of backends executing operations asynchronously is attributed to the operations
+/// waiting for the results, such as reading the data of a tensor.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProfiledBackend<B> {
+    _b: B,
+}
+
+impl<B: Backend> Backend for ProfiledBackend<B> {
+    type Device = B::Device;
+    type Elem = B::Elem;
+    type FullPrecisionElem = B::FullPrecisionElem;
+    type IntegerBackend = ProfiledBackend<B::IntegerBackend>;
+    type FullPrecisionBackend = ProfiledBackend<B::FullPrecisionBackend>;
+    type TensorPrimitive<const D: usize> = B::TensorPrimitive<D>;
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<D>;
+
+    fn ad_enabled() -> bool {
+        false
+    }
+
+    fn name() -> String {
+        format!("profiled<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn fork_rng(device: B::Device) -> Generator {
+        B::fork_rng(device)
+    }
+
+    fn get_rng_state(device: B::Device) -> RngState {
+        B::get_rng_state(device)
+    }
+
+    fn set_rng_state(device: B::Device, state: RngState) {
+        B::set_rng_state(device, state)
+    }
+}
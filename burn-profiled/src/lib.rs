@@ -0,0 +1,17 @@
+pub(crate) mod ops;
+pub(crate) mod profile;
+
+mod backend;
+pub use backend::*;
+pub use profile::{
+    profile, profile_options, reset_profile, set_profile_options, OpProfile, Profile,
+    ProfileOptions, ShapeProfile, TraceEvent,
+};
+
+#[cfg(test)]
+mod tests {
+    type TestBackend = crate::ProfiledBackend<burn_ndarray::NdArrayBackend<f32>>;
+
+    burn_tensor::testgen_all!();
+    burn_autodiff::testgen_all!();
+}
@@ -0,0 +1,34 @@
+use crate::profile::{dims, run_output};
+use crate::ProfiledBackend;
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::*;
+
+type IntTensor<B, const D: usize> = <<B as Backend>::IntegerBackend as Backend>::TensorPrimitive<D>;
+
+impl<B: Backend> ModuleOps<ProfiledBackend<B>> for ProfiledBackend<B> {
+    fn embedding(
+        weights: &B::TensorPrimitive<2>,
+        indexes: &IntTensor<B, 2>,
+    ) -> B::TensorPrimitive<3> {
+        let inputs = vec![
+            dims(B::shape(weights)),
+            dims(B::IntegerBackend::shape(indexes)),
+        ];
+        run_output::<B, 3, _>("embedding", inputs, || B::embedding(weights, indexes))
+    }
+
+    fn embedding_backward(
+        weights: &B::TensorPrimitive<2>,
+        output: &B::TensorPrimitive<3>,
+        indexes: &IntTensor<B, 2>,
+    ) -> B::TensorPrimitive<2> {
+        let inputs = vec![
+            dims(B::shape(weights)),
+            dims(B::shape(output)),
+            dims(B::IntegerBackend::shape(indexes)),
+        ];
+        run_output::<B, 2, _>("embedding_backward", inputs, || {
+            B::embedding_backward(weights, output, indexes)
+        })
+    }
+}
@@ -0,0 +1,552 @@
+use crate::profile::{dims, run, run_bool_output, run_output};
+use crate::ProfiledBackend;
+use burn_tensor::backend::Backend;
+use burn_tensor::{
+    ops::*, Data, Distribution, Generator, QuantizationScheme, QuantizedData, Shape,
+};
+use std::ops::Range;
+
+type Tensor<B, const D: usize> = <B as Backend>::TensorPrimitive<D>;
+type BoolTensor<B, const D: usize> = <B as Backend>::BoolTensorPrimitive<D>;
+type IntTensor<B, const D: usize> = <<B as Backend>::IntegerBackend as Backend>::TensorPrimitive<D>;
+type FullPrecisionTensor<B, const D: usize> =
+    <<B as Backend>::FullPrecisionBackend as Backend>::TensorPrimitive<D>;
+
+impl<B: Backend> TensorOps<ProfiledBackend<B>> for ProfiledBackend<B> {
+    fn from_data<const D: usize>(data: Data<B::Elem, D>, device: B::Device) -> Tensor<B, D> {
+        run_output::<B, D, _>("from_data", vec![dims(&data.shape)], || {
+            B::from_data(data, device)
+        })
+    }
+
+    fn from_data_bool<const D: usize>(data: Data<bool, D>, device: B::Device) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>("from_data_bool", vec![dims(&data.shape)], || {
+            B::from_data_bool(data, device)
+        })
+    }
+
+    fn random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        device: B::Device,
+    ) -> Tensor<B, D> {
+        run_output::<B, D, _>("random", Vec::new(), || {
+            B::random(shape, distribution, device)
+        })
+    }
+
+    fn random_with<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        generator: &mut Generator,
+        device: B::Device,
+    ) -> Tensor<B, D> {
+        run_output::<B, D, _>("random_with", Vec::new(), || {
+            B::random_with(shape, distribution, generator, device)
+        })
+    }
+
+    fn zeros<const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+        run_output::<B, D, _>("zeros", Vec::new(), || B::zeros(shape, device))
+    }
+
+    fn ones<const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+        run_output::<B, D, _>("ones", Vec::new(), || B::ones(shape, device))
+    }
+
+    fn shape<const D: usize>(tensor: &Tensor<B, D>) -> &Shape<D> {
+        B::shape(tensor)
+    }
+
+    fn to_data<const D: usize>(tensor: &Tensor<B, D>) -> Data<B::Elem, D> {
+        run(
+            "to_data",
+            vec![dims(B::shape(tensor))],
+            || B::to_data(tensor),
+            |_| Vec::new(),
+        )
+    }
+
+    fn into_data<const D: usize>(tensor: Tensor<B, D>) -> Data<B::Elem, D> {
+        let inputs = vec![dims(B::shape(&tensor))];
+        run("into_data", inputs, || B::into_data(tensor), |_| Vec::new())
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<B, D>) -> &Shape<D> {
+        B::bool_shape(tensor)
+    }
+
+    fn bool_to_data<const D: usize>(tensor: &BoolTensor<B, D>) -> Data<bool, D> {
+        run(
+            "bool_to_data",
+            vec![dims(B::bool_shape(tensor))],
+            || B::bool_to_data(tensor),
+            |_| Vec::new(),
+        )
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<B, D>) -> Data<bool, D> {
+        let inputs = vec![dims(B::bool_shape(&tensor))];
+        run(
+            "bool_into_data",
+            inputs,
+            || B::bool_into_data(tensor),
+            |_| Vec::new(),
+        )
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<B, D>) -> B::Device {
+        B::bool_device(tensor)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: &BoolTensor<B, D>,
+        device: B::Device,
+    ) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>("bool_to_device", vec![dims(B::bool_shape(tensor))], || {
+            B::bool_to_device(tensor, device)
+        })
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: &BoolTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<B, D2> {
+        run_bool_output::<B, D2, _>("bool_reshape", vec![dims(B::bool_shape(tensor))], || {
+            B::bool_reshape(tensor, shape)
+        })
+    }
+
+    fn device<const D: usize>(tensor: &Tensor<B, D>) -> B::Device {
+        B::device(tensor)
+    }
+
+    fn to_device<const D: usize>(tensor: &Tensor<B, D>, device: B::Device) -> Tensor<B, D> {
+        run_output::<B, D, _>("to_device", vec![dims(B::shape(tensor))], || {
+            B::to_device(tensor, device)
+        })
+    }
+
+    fn arange(range: Range<usize>, device: B::Device) -> IntTensor<B, 1> {
+        run_output::<B::IntegerBackend, 1, _>("arange", Vec::new(), || B::arange(range, device))
+    }
+
+    fn empty<const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+        run_output::<B, D, _>("empty", Vec::new(), || B::empty(shape, device))
+    }
+
+    fn repeat<const D: usize>(tensor: &Tensor<B, D>, dim: usize, times: usize) -> Tensor<B, D> {
+        run_output::<B, D, _>("repeat", vec![dims(B::shape(tensor))], || {
+            B::repeat(tensor, dim, times)
+        })
+    }
+
+    fn add<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>(
+            "add",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::add(lhs, rhs),
+        )
+    }
+
+    fn add_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        run_output::<B, D, _>("add_scalar", vec![dims(B::shape(lhs))], || {
+            B::add_scalar(lhs, rhs)
+        })
+    }
+
+    fn sub<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>(
+            "sub",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::sub(lhs, rhs),
+        )
+    }
+
+    fn sub_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        run_output::<B, D, _>("sub_scalar", vec![dims(B::shape(lhs))], || {
+            B::sub_scalar(lhs, rhs)
+        })
+    }
+
+    fn mul<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>(
+            "mul",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::mul(lhs, rhs),
+        )
+    }
+
+    fn mul_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        run_output::<B, D, _>("mul_scalar", vec![dims(B::shape(lhs))], || {
+            B::mul_scalar(lhs, rhs)
+        })
+    }
+
+    fn div<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>(
+            "div",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::div(lhs, rhs),
+        )
+    }
+
+    fn div_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        run_output::<B, D, _>("div_scalar", vec![dims(B::shape(lhs))], || {
+            B::div_scalar(lhs, rhs)
+        })
+    }
+
+    fn matmul<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>(
+            "matmul",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::matmul(lhs, rhs),
+        )
+    }
+
+    fn spmm(
+        indices: &IntTensor<B, 2>,
+        values: &Tensor<B, 1>,
+        shape: Shape<2>,
+        dense: &Tensor<B, 2>,
+    ) -> Tensor<B, 2> {
+        run_output::<B, 2, _>(
+            "spmm",
+            vec![
+                dims(B::IntegerBackend::shape(indices)),
+                dims(B::shape(values)),
+                dims(&shape),
+                dims(B::shape(dense)),
+            ],
+            || B::spmm(indices, values, shape, dense),
+        )
+    }
+
+    fn quantize<const D: usize>(
+        tensor: &Tensor<B, D>,
+        scheme: QuantizationScheme,
+    ) -> QuantizedData<D> {
+        run(
+            "quantize",
+            vec![dims(B::shape(tensor))],
+            || B::quantize(tensor, scheme),
+            |_| Vec::new(),
+        )
+    }
+
+    fn dequantize<const D: usize>(data: &QuantizedData<D>, device: B::Device) -> Tensor<B, D> {
+        run_output::<B, D, _>("dequantize", vec![dims(&data.values.shape)], || {
+            B::dequantize(data, device)
+        })
+    }
+
+    fn quantized_matmul(lhs: &Tensor<B, 2>, rhs: &QuantizedData<2>) -> Tensor<B, 2> {
+        run_output::<B, 2, _>(
+            "quantized_matmul",
+            vec![dims(B::shape(lhs)), dims(&rhs.values.shape)],
+            || B::quantized_matmul(lhs, rhs),
+        )
+    }
+
+    fn fft<const D: usize>(tensor: &Tensor<B, D>, inverse: bool) -> Tensor<B, D> {
+        run_output::<B, D, _>("fft", vec![dims(B::shape(tensor))], || {
+            B::fft(tensor, inverse)
+        })
+    }
+
+    fn rfft<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>("rfft", vec![dims(B::shape(tensor))], || B::rfft(tensor))
+    }
+
+    fn neg<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>("neg", vec![dims(B::shape(tensor))], || B::neg(tensor))
+    }
+
+    fn transpose<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>("transpose", vec![dims(B::shape(tensor))], || {
+            B::transpose(tensor)
+        })
+    }
+
+    fn swap_dims<const D: usize>(tensor: &Tensor<B, D>, dim1: usize, dim2: usize) -> Tensor<B, D> {
+        run_output::<B, D, _>("swap_dims", vec![dims(B::shape(tensor))], || {
+            B::swap_dims(tensor, dim1, dim2)
+        })
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: &Tensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> Tensor<B, D2> {
+        run_output::<B, D2, _>("reshape", vec![dims(B::shape(tensor))], || {
+            B::reshape(tensor, shape)
+        })
+    }
+
+    fn index<const D1: usize, const D2: usize>(
+        tensor: &Tensor<B, D1>,
+        indexes: [Range<usize>; D2],
+    ) -> Tensor<B, D1> {
+        run_output::<B, D1, _>("index", vec![dims(B::shape(tensor))], || {
+            B::index(tensor, indexes)
+        })
+    }
+
+    fn index_assign<const D1: usize, const D2: usize>(
+        tensor: &Tensor<B, D1>,
+        indexes: [Range<usize>; D2],
+        value: &Tensor<B, D1>,
+    ) -> Tensor<B, D1> {
+        run_output::<B, D1, _>(
+            "index_assign",
+            vec![dims(B::shape(tensor)), dims(B::shape(value))],
+            || B::index_assign(tensor, indexes, value),
+        )
+    }
+
+    fn mask_fill<const D: usize>(
+        tensor: &Tensor<B, D>,
+        mask: &BoolTensor<B, D>,
+        value: B::Elem,
+    ) -> Tensor<B, D> {
+        run_output::<B, D, _>(
+            "mask_fill",
+            vec![dims(B::shape(tensor)), dims(B::bool_shape(mask))],
+            || B::mask_fill(tensor, mask, value),
+        )
+    }
+
+    fn equal<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>(
+            "equal",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::equal(lhs, rhs),
+        )
+    }
+
+    fn equal_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>("equal_scalar", vec![dims(B::shape(lhs))], || {
+            B::equal_scalar(lhs, rhs)
+        })
+    }
+
+    fn greater<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>(
+            "greater",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::greater(lhs, rhs),
+        )
+    }
+
+    fn greater_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>("greater_scalar", vec![dims(B::shape(lhs))], || {
+            B::greater_scalar(lhs, rhs)
+        })
+    }
+
+    fn greater_equal<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>(
+            "greater_equal",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::greater_equal(lhs, rhs),
+        )
+    }
+
+    fn greater_equal_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>("greater_equal_scalar", vec![dims(B::shape(lhs))], || {
+            B::greater_equal_scalar(lhs, rhs)
+        })
+    }
+
+    fn lower<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>(
+            "lower",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::lower(lhs, rhs),
+        )
+    }
+
+    fn lower_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>("lower_scalar", vec![dims(B::shape(lhs))], || {
+            B::lower_scalar(lhs, rhs)
+        })
+    }
+
+    fn lower_equal<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>(
+            "lower_equal",
+            vec![dims(B::shape(lhs)), dims(B::shape(rhs))],
+            || B::lower_equal(lhs, rhs),
+        )
+    }
+
+    fn lower_equal_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        run_bool_output::<B, D, _>("lower_equal_scalar", vec![dims(B::shape(lhs))], || {
+            B::lower_equal_scalar(lhs, rhs)
+        })
+    }
+
+    fn detach<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        B::detach(tensor)
+    }
+
+    fn mean<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, 1> {
+        run_output::<B, 1, _>("mean", vec![dims(B::shape(tensor))], || B::mean(tensor))
+    }
+
+    fn sum<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, 1> {
+        run_output::<B, 1, _>("sum", vec![dims(B::shape(tensor))], || B::sum(tensor))
+    }
+
+    fn mean_dim<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+        run_output::<B, D, _>("mean_dim", vec![dims(B::shape(tensor))], || {
+            B::mean_dim(tensor, dim)
+        })
+    }
+
+    fn sum_dim<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+        run_output::<B, D, _>("sum_dim", vec![dims(B::shape(tensor))], || {
+            B::sum_dim(tensor, dim)
+        })
+    }
+
+    fn to_full_precision<const D: usize>(tensor: &Tensor<B, D>) -> FullPrecisionTensor<B, D> {
+        run_output::<B::FullPrecisionBackend, D, _>(
+            "to_full_precision",
+            vec![dims(B::shape(tensor))],
+            || B::to_full_precision(tensor),
+        )
+    }
+
+    fn from_full_precision<const D: usize>(tensor: &FullPrecisionTensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>(
+            "from_full_precision",
+            vec![dims(B::FullPrecisionBackend::shape(tensor))],
+            || B::from_full_precision(tensor),
+        )
+    }
+
+    fn argmax<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        run_output::<B::IntegerBackend, D, _>("argmax", vec![dims(B::shape(tensor))], || {
+            B::argmax(tensor, dim)
+        })
+    }
+
+    fn argmin<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        run_output::<B::IntegerBackend, D, _>("argmin", vec![dims(B::shape(tensor))], || {
+            B::argmin(tensor, dim)
+        })
+    }
+
+    fn multinomial<const D: usize>(
+        tensor: &Tensor<B, D>,
+        num_samples: usize,
+        replacement: bool,
+        generator: &mut Generator,
+    ) -> IntTensor<B, D> {
+        run_output::<B::IntegerBackend, D, _>("multinomial", vec![dims(B::shape(tensor))], || {
+            B::multinomial(tensor, num_samples, replacement, generator)
+        })
+    }
+
+    fn exp<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>("exp", vec![dims(B::shape(tensor))], || B::exp(tensor))
+    }
+
+    fn log<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>("log", vec![dims(B::shape(tensor))], || B::log(tensor))
+    }
+
+    fn powf<const D: usize>(tensor: &Tensor<B, D>, value: f32) -> Tensor<B, D> {
+        run_output::<B, D, _>("powf", vec![dims(B::shape(tensor))], || {
+            B::powf(tensor, value)
+        })
+    }
+
+    fn erf<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>("erf", vec![dims(B::shape(tensor))], || B::erf(tensor))
+    }
+
+    fn cat<const D: usize>(tensors: &[Tensor<B, D>], dim: usize) -> Tensor<B, D> {
+        let inputs = tensors
+            .iter()
+            .map(|tensor| dims(B::shape(tensor)))
+            .collect();
+        run_output::<B, D, _>("cat", inputs, || B::cat(tensors, dim))
+    }
+
+    fn relu<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        run_output::<B, D, _>("relu", vec![dims(B::shape(tensor))], || B::relu(tensor))
+    }
+
+    fn add_owned<const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&lhs)), dims(B::shape(&rhs))];
+        run_output::<B, D, _>("add", inputs, || B::add_owned(lhs, rhs))
+    }
+
+    fn add_scalar_owned<const D: usize>(lhs: Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&lhs))];
+        run_output::<B, D, _>("add_scalar", inputs, || B::add_scalar_owned(lhs, rhs))
+    }
+
+    fn sub_owned<const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&lhs)), dims(B::shape(&rhs))];
+        run_output::<B, D, _>("sub", inputs, || B::sub_owned(lhs, rhs))
+    }
+
+    fn sub_scalar_owned<const D: usize>(lhs: Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&lhs))];
+        run_output::<B, D, _>("sub_scalar", inputs, || B::sub_scalar_owned(lhs, rhs))
+    }
+
+    fn mul_owned<const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&lhs)), dims(B::shape(&rhs))];
+        run_output::<B, D, _>("mul", inputs, || B::mul_owned(lhs, rhs))
+    }
+
+    fn mul_scalar_owned<const D: usize>(lhs: Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&lhs))];
+        run_output::<B, D, _>("mul_scalar", inputs, || B::mul_scalar_owned(lhs, rhs))
+    }
+
+    fn div_owned<const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&lhs)), dims(B::shape(&rhs))];
+        run_output::<B, D, _>("div", inputs, || B::div_owned(lhs, rhs))
+    }
+
+    fn div_scalar_owned<const D: usize>(lhs: Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&lhs))];
+        run_output::<B, D, _>("div_scalar", inputs, || B::div_scalar_owned(lhs, rhs))
+    }
+
+    fn neg_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&tensor))];
+        run_output::<B, D, _>("neg", inputs, || B::neg_owned(tensor))
+    }
+
+    fn exp_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&tensor))];
+        run_output::<B, D, _>("exp", inputs, || B::exp_owned(tensor))
+    }
+
+    fn log_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&tensor))];
+        run_output::<B, D, _>("log", inputs, || B::log_owned(tensor))
+    }
+
+    fn erf_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&tensor))];
+        run_output::<B, D, _>("erf", inputs, || B::erf_owned(tensor))
+    }
+
+    fn relu_owned<const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&tensor))];
+        run_output::<B, D, _>("relu", inputs, || B::relu_owned(tensor))
+    }
+
+    fn powf_owned<const D: usize>(tensor: Tensor<B, D>, value: f32) -> Tensor<B, D> {
+        let inputs = vec![dims(B::shape(&tensor))];
+        run_output::<B, D, _>("powf", inputs, || B::powf_owned(tensor, value))
+    }
+}
@@ -0,0 +1,425 @@
+use burn_tensor::{backend::Backend, Shape};
+use serde::Serialize;
+use std::cell::Cell;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+static PROFILE_OPTIONS: RwLock<ProfileOptions> = RwLock::new(ProfileOptions::const_default());
+static PROFILER: Mutex<Option<Profiler>> = Mutex::new(None);
+static NUM_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static THREAD: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Options used to profile the operations of the profiled backend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProfileOptions {
+    /// Keep every call in the trace of the [profile](Profile), otherwise only the statistics of
+    /// each operation are kept, which don't grow with the number of calls.
+    ///
+    /// Disabled by default, since the trace keeps an event with the shapes of every call until
+    /// the profile is [reset](reset_profile), which grows without bound over a training.
+    pub trace: bool,
+}
+
+impl ProfileOptions {
+    const fn const_default() -> Self {
+        Self { trace: false }
+    }
+}
+
+impl Default for ProfileOptions {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Set the options used to profile the operations of the profiled backend.
+pub fn set_profile_options(options: ProfileOptions) {
+    let mut current = PROFILE_OPTIONS.write().unwrap();
+    *current = options;
+}
+
+/// Returns the options used to profile the operations of the profiled backend.
+pub fn profile_options() -> ProfileOptions {
+    *PROFILE_OPTIONS.read().unwrap()
+}
+
+/// Returns the profile of the operations called since the start of the program, or since the
+/// last [reset](reset_profile).
+pub fn profile() -> Profile {
+    let profiler = PROFILER.lock().unwrap();
+
+    match &*profiler {
+        Some(profiler) => profiler.profile(),
+        None => Profile::default(),
+    }
+}
+
+/// Clear the profile, which starts again from the next operation.
+pub fn reset_profile() {
+    *PROFILER.lock().unwrap() = None;
+}
+
+/// Statistics and trace of the operations called on the profiled backend.
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    /// Statistics of each operation, the most time consuming first.
+    pub ops: Vec<OpProfile>,
+    /// Every call in the order they started, empty when the trace is disabled.
+    pub trace: Vec<TraceEvent>,
+}
+
+/// Statistics of the calls to an operation.
+#[derive(Clone, Debug)]
+pub struct OpProfile {
+    pub name: &'static str,
+    pub count: usize,
+    pub time: Duration,
+    /// Statistics of the calls grouped by shapes, the most time consuming first.
+    pub shapes: Vec<ShapeProfile>,
+}
+
+/// Statistics of the calls to an operation with the same shapes.
+#[derive(Clone, Debug)]
+pub struct ShapeProfile {
+    pub inputs: Vec<Vec<usize>>,
+    pub output: Vec<usize>,
+    pub count: usize,
+    pub time: Duration,
+}
+
+/// Call to an operation.
+#[derive(Clone, Debug)]
+pub struct TraceEvent {
+    pub name: &'static str,
+    /// Time elapsed between the start of the profile and the call.
+    pub start: Duration,
+    pub duration: Duration,
+    /// Index of the thread calling the operation, in the order threads first called one.
+    pub thread: usize,
+    pub inputs: Vec<Vec<usize>>,
+    pub output: Vec<usize>,
+}
+
+impl Profile {
+    /// Total time spent in the operations.
+    pub fn time(&self) -> Duration {
+        self.ops.iter().map(|op| op.time).sum()
+    }
+
+    /// Returns the trace in the Chrome trace event format, which can be opened with
+    /// `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+    pub fn to_chrome_trace(&self) -> String {
+        #[derive(Serialize)]
+        struct Args<'a> {
+            inputs: &'a [Vec<usize>],
+            output: &'a [usize],
+        }
+
+        #[derive(Serialize)]
+        struct Event<'a> {
+            name: &'a str,
+            cat: &'a str,
+            ph: &'a str,
+            ts: f64,
+            dur: f64,
+            pid: usize,
+            tid: usize,
+            args: Args<'a>,
+        }
+
+        let events: Vec<Event> = self
+            .trace
+            .iter()
+            .map(|event| Event {
+                name: event.name,
+                cat: "op",
+                ph: "X",
+                ts: event.start.as_secs_f64() * 1e6,
+                dur: event.duration.as_secs_f64() * 1e6,
+                pid: 0,
+                tid: event.thread,
+                args: Args {
+                    inputs: &event.inputs,
+                    output: &event.output,
+                },
+            })
+            .collect();
+
+        serde_json::to_string(&events).unwrap()
+    }
+}
+
+/// Number of shapes displayed under each operation in the table of the profile.
+const NUM_DISPLAYED_SHAPES: usize = 3;
+
+impl Display for Profile {
+    /// Formats the profile as a table, with the most time consuming shapes of each operation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let total = self.time().as_secs_f64();
+        let percent = |time: Duration| match total > 0.0 {
+            true => 100.0 * time.as_secs_f64() / total,
+            false => 0.0,
+        };
+
+        writeln!(
+            f,
+            "{:<40} {:>10} {:>14} {:>14} {:>7}",
+            "op", "calls", "total", "mean", "%"
+        )?;
+
+        for op in self.ops.iter() {
+            writeln!(
+                f,
+                "{:<40} {:>10} {:>14?} {:>14?} {:>6.2}%",
+                op.name,
+                op.count,
+                op.time,
+                op.time / op.count as u32,
+                percent(op.time)
+            )?;
+
+            for shape in op.shapes.iter().take(NUM_DISPLAYED_SHAPES) {
+                let inputs: Vec<String> = shape
+                    .inputs
+                    .iter()
+                    .map(|input| format!("{:?}", input))
+                    .collect();
+                let shapes = format!("  {} -> {:?}", inputs.join(", "), shape.output);
+
+                writeln!(
+                    f,
+                    "{:<40} {:>10} {:>14?} {:>14?} {:>6.2}%",
+                    shapes,
+                    shape.count,
+                    shape.time,
+                    shape.time / shape.count as u32,
+                    percent(shape.time)
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct Profiler {
+    start: Instant,
+    ops: HashMap<&'static str, OpStats>,
+    trace: Vec<TraceEvent>,
+}
+
+#[derive(Default)]
+struct OpStats {
+    count: usize,
+    time: Duration,
+    shapes: HashMap<Shapes, ShapeStats>,
+}
+
+/// Shapes of the inputs and output of a call.
+type Shapes = (Vec<Vec<usize>>, Vec<usize>);
+
+#[derive(Default)]
+struct ShapeStats {
+    count: usize,
+    time: Duration,
+}
+
+impl Profiler {
+    fn new(start: Instant) -> Self {
+        Self {
+            start,
+            ops: HashMap::new(),
+            trace: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, event: TraceEvent, trace: bool) {
+        let stats = self.ops.entry(event.name).or_default();
+        stats.count += 1;
+        stats.time += event.duration;
+
+        let shape_stats = stats
+            .shapes
+            .entry((event.inputs.clone(), event.output.clone()))
+            .or_default();
+        shape_stats.count += 1;
+        shape_stats.time += event.duration;
+
+        if trace {
+            self.trace.push(event);
+        }
+    }
+
+    fn profile(&self) -> Profile {
+        let mut ops: Vec<OpProfile> = self
+            .ops
+            .iter()
+            .map(|(name, stats)| {
+                let mut shapes: Vec<ShapeProfile> = stats
+                    .shapes
+                    .iter()
+                    .map(|((inputs, output), shape_stats)| ShapeProfile {
+                        inputs: inputs.clone(),
+                        output: output.clone(),
+                        count: shape_stats.count,
+                        time: shape_stats.time,
+                    })
+                    .collect();
+                shapes.sort_by_key(|shape| Reverse(shape.time));
+
+                OpProfile {
+                    name,
+                    count: stats.count,
+                    time: stats.time,
+                    shapes,
+                }
+            })
+            .collect();
+        ops.sort_by_key(|op| Reverse(op.time));
+
+        Profile {
+            ops,
+            trace: self.trace.clone(),
+        }
+    }
+}
+
+/// Run an operation, recording its duration with the shapes of its inputs and output.
+pub(crate) fn run<R, F, S>(name: &'static str, inputs: Vec<Vec<usize>>, func: F, output: S) -> R
+where
+    F: FnOnce() -> R,
+    S: FnOnce(&R) -> Vec<usize>,
+{
+    let start = Instant::now();
+    let result = func();
+    let duration = start.elapsed();
+
+    let trace = profile_options().trace;
+    let thread = THREAD.with(|thread| match thread.get() {
+        Some(index) => index,
+        None => {
+            let index = NUM_THREADS.fetch_add(1, Ordering::Relaxed);
+            thread.set(Some(index));
+            index
+        }
+    });
+    let event_output = output(&result);
+
+    let mut profiler = PROFILER.lock().unwrap();
+    let profiler = profiler.get_or_insert_with(|| Profiler::new(start));
+    let event = TraceEvent {
+        name,
+        start: start.saturating_duration_since(profiler.start),
+        duration,
+        thread,
+        inputs,
+        output: event_output,
+    };
+    profiler.record(event, trace);
+
+    result
+}
+
+/// Same as [run] for operations returning a tensor.
+pub(crate) fn run_output<B: Backend, const D: usize, F>(
+    name: &'static str,
+    inputs: Vec<Vec<usize>>,
+    func: F,
+) -> B::TensorPrimitive<D>
+where
+    F: FnOnce() -> B::TensorPrimitive<D>,
+{
+    run(name, inputs, func, |tensor| dims(B::shape(tensor)))
+}
+
+/// Same as [run] for operations returning a tensor of booleans.
+pub(crate) fn run_bool_output<B: Backend, const D: usize, F>(
+    name: &'static str,
+    inputs: Vec<Vec<usize>>,
+    func: F,
+) -> B::BoolTensorPrimitive<D>
+where
+    F: FnOnce() -> B::BoolTensorPrimitive<D>,
+{
+    run(name, inputs, func, |tensor| dims(B::bool_shape(tensor)))
+}
+
+pub(crate) fn dims<const D: usize>(shape: &Shape<D>) -> Vec<usize> {
+    shape.dims.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProfiledBackend;
+    use burn_autodiff::ADBackendDecorator;
+    use burn_tensor::Tensor;
+
+    type TestBackend = ProfiledBackend<burn_ndarray::NdArrayBackend<f32>>;
+    type TestADBackend = ADBackendDecorator<TestBackend>;
+
+    // The profile is shared by the tests running in parallel, the shapes used by each test are
+    // unique to find its calls.
+    fn find<'a>(profile: &'a Profile, name: &str, inputs: &[&[usize]]) -> &'a ShapeProfile {
+        let op = profile.ops.iter().find(|op| op.name == name).unwrap();
+
+        op.shapes
+            .iter()
+            .find(|shape| shape.inputs == inputs)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_record_calls_with_shapes() {
+        let lhs = Tensor::<TestBackend, 2>::zeros([7, 13]);
+        let rhs = Tensor::<TestBackend, 2>::zeros([13, 5]);
+
+        lhs.matmul(&rhs);
+        lhs.matmul(&rhs);
+
+        let profile = profile();
+        let shape = find(&profile, "matmul", &[&[7, 13], &[13, 5]]);
+        assert_eq!(shape.count, 2);
+        assert_eq!(shape.output, vec![7, 5]);
+        assert!(profile.to_string().contains("  [7, 13], [13, 5] -> [7, 5]"));
+    }
+
+    #[test]
+    fn should_record_the_backward_pass() {
+        let lhs = Tensor::<TestADBackend, 2>::zeros([3, 11]);
+        let rhs = Tensor::<TestADBackend, 2>::zeros([11, 17]);
+
+        lhs.matmul(&rhs).backward();
+
+        let profile = profile();
+        // The gradients of the inputs are multiplied with the gradient of the output.
+        find(&profile, "matmul", &[&[3, 17], &[17, 11]]);
+        find(&profile, "matmul", &[&[11, 3], &[3, 17]]);
+    }
+
+    #[test]
+    fn should_export_chrome_trace() {
+        set_profile_options(ProfileOptions { trace: true });
+        let tensor = Tensor::<TestBackend, 3>::ones([2, 19, 23]);
+
+        tensor.sum_dim(1);
+
+        let trace: serde_json::Value = serde_json::from_str(&profile().to_chrome_trace()).unwrap();
+        let event = trace
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|event| event["args"]["inputs"] == serde_json::json!([[2, 19, 23]]))
+            .unwrap();
+        assert_eq!(event["name"], "sum_dim");
+        assert_eq!(event["ph"], "X");
+        assert_eq!(event["args"]["output"], serde_json::json!([2, 1, 23]));
+    }
+}
@@ -1,8 +1,36 @@
 use super::element::TchElement;
 use super::TchTensor;
 use burn_tensor::backend::Backend;
+use burn_tensor::{Generator, RngState};
+use std::collections::HashMap;
+use std::sync::Mutex;
 
-#[derive(Clone, Copy, Debug)]
+/// The generators of each device, seeded from the operating system unless a seed is set.
+static GENERATORS: Mutex<Option<DeviceGenerators>> = Mutex::new(None);
+
+#[derive(Default)]
+struct DeviceGenerators {
+    seed: Option<u64>,
+    generators: HashMap<TchDevice, Generator>,
+}
+
+pub(crate) fn with_generator<T, F: FnOnce(&mut Generator) -> T>(device: TchDevice, func: F) -> T {
+    let mut generators = GENERATORS.lock().unwrap();
+    let generators = generators.get_or_insert_with(DeviceGenerators::default);
+    let seed = generators.seed;
+
+    let generator = generators
+        .generators
+        .entry(device)
+        .or_insert_with(|| match seed {
+            Some(seed) => Generator::new(seed),
+            None => Generator::from_entropy(),
+        });
+
+    func(generator)
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 /// The device struct when using the `tch` backend.
 ///
 /// Note that you need to provide the device index when using Cuda.
@@ -30,6 +58,15 @@ impl From<TchDevice> for tch::Device {
     }
 }
 
+impl From<tch::Device> for TchDevice {
+    fn from(device: tch::Device) -> Self {
+        match device {
+            tch::Device::Cpu => TchDevice::Cpu,
+            tch::Device::Cuda(num) => TchDevice::Cuda(num),
+        }
+    }
+}
+
 impl Default for TchDevice {
     fn default() -> Self {
         Self::Cpu
@@ -52,6 +89,24 @@ impl<E: TchElement> Backend for TchBackend<E> {
 
     fn seed(seed: u64) {
         tch::manual_seed(seed as i64);
+
+        let mut generators = GENERATORS.lock().unwrap();
+        *generators = Some(DeviceGenerators {
+            seed: Some(seed),
+            generators: HashMap::new(),
+        });
+    }
+
+    fn fork_rng(device: TchDevice) -> Generator {
+        with_generator(device, |generator| generator.fork())
+    }
+
+    fn get_rng_state(device: TchDevice) -> RngState {
+        with_generator(device, |generator| generator.state())
+    }
+
+    fn set_rng_state(device: TchDevice, state: RngState) {
+        with_generator(device, |generator| generator.set_state(&state))
     }
 
     fn ad_enabled() -> bool {
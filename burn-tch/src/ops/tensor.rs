@@ -1,6 +1,16 @@
-use crate::{element::TchElement, TchBackend, TchDevice, TchKind, TchShape, TchTensor};
-use burn_tensor::{backend::Backend, ops::TensorOps, Data, Distribution, ElementConversion, Shape};
+use crate::{
+    element::TchElement, with_generator, TchBackend, TchDevice, TchKind, TchShape, TchTensor,
+};
+use burn_tensor::{
+    backend::Backend, ops::TensorOps, Data, Distribution, ElementConversion, Generator, Shape,
+};
+use rand::RngCore;
 use std::ops::{Add, Div, Mul, Range, Sub};
+use std::sync::Mutex;
+
+/// Libtorch only has a global generator, which is seeded from the given generator before each
+/// random operation.
+static TORCH_RNG: Mutex<()> = Mutex::new(());
 
 impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
     fn from_data<const D: usize>(data: Data<E, D>, device: TchDevice) -> TchTensor<E, D> {
@@ -15,10 +25,7 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         data: Data<bool, D>,
         device: TchDevice,
     ) -> TchTensor<bool, D> {
-        let device = match device {
-            TchDevice::Cpu => tch::Device::Cpu,
-            TchDevice::Cuda(num) => tch::Device::Cuda(num),
-        };
+        let device: tch::Device = device.into();
         TchTensor::from_data(data, device)
     }
 
@@ -27,6 +34,20 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         distribution: Distribution<E>,
         device: TchDevice,
     ) -> TchTensor<E, D> {
+        with_generator(device, |generator| {
+            Self::random_with(shape, distribution, generator, device)
+        })
+    }
+
+    fn random_with<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<E>,
+        generator: &mut Generator,
+        device: TchDevice,
+    ) -> TchTensor<E, D> {
+        let _lock = TORCH_RNG.lock().unwrap();
+        tch::manual_seed(generator.next_u64() as i64);
+
         match distribution {
             Distribution::Standard => {
                 let mut tensor = TchTensor::<E, D>::empty(shape, device);
@@ -50,6 +71,14 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
                 tensor.tensor = tensor.tensor.normal(mean, std);
                 tensor
             }
+            Distribution::Exponential(rate) => {
+                let mut tensor = TchTensor::<E, D>::empty(shape, device);
+                tensor.tensor = tensor.tensor.exponential_(rate);
+                tensor
+            }
+            Distribution::TruncatedNormal(..) | Distribution::Gumbel(..) => {
+                Self::from_data(Data::random(shape, distribution, generator), device)
+            }
         }
     }
 
@@ -103,17 +132,17 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         Data::new(values, tensor.shape)
     }
 
+    fn bool_device<const D: usize>(tensor: &TchTensor<bool, D>) -> TchDevice {
+        tensor.tensor.device().into()
+    }
+
     fn bool_to_device<const D: usize>(
         tensor: &TchTensor<bool, D>,
         device: TchDevice,
     ) -> TchTensor<bool, D> {
-        let device = match device {
-            TchDevice::Cpu => tch::Device::Cpu,
-            TchDevice::Cuda(num) => tch::Device::Cuda(num),
-        };
         TchTensor {
             kind: tensor.kind,
-            tensor: tensor.tensor.to(device),
+            tensor: tensor.tensor.to(device.into()),
             shape: tensor.shape,
         }
     }
@@ -460,6 +489,29 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         to_tensor(tensor)
     }
 
+    fn multinomial<const D: usize>(
+        tensor: &TchTensor<E, D>,
+        num_samples: usize,
+        replacement: bool,
+        generator: &mut Generator,
+    ) -> TchTensor<i64, D> {
+        let _lock = TORCH_RNG.lock().unwrap();
+        tch::manual_seed(generator.next_u64() as i64);
+
+        let mut dims = tensor.shape.dims;
+        let num_categories = dims[D - 1] as i64;
+        dims[D - 1] = num_samples;
+        let shape: TchShape<D> = Shape::new(dims).into();
+
+        let tensor = tensor
+            .tensor
+            .reshape(&[-1, num_categories])
+            .multinomial(num_samples as i64, replacement)
+            .reshape(&shape.dims);
+
+        to_tensor(tensor)
+    }
+
     fn exp<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
         to_tensor(tensor.tensor.exp())
     }
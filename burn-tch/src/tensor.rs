@@ -118,15 +118,14 @@ impl<P: tch::kind::Element + Default + Copy + std::fmt::Debug, const D: usize> T
 mod tests {
     use super::*;
     use burn_tensor::Distribution;
-    use rand::prelude::StdRng;
-    use rand::SeedableRng;
+    use burn_tensor::Generator;
 
     #[test]
     fn should_support_into_and_from_data_1d() {
         let data_expected = Data::<f32, 1>::random(
             Shape::new([3]),
             Distribution::Standard,
-            &mut StdRng::from_entropy(),
+            &mut Generator::from_entropy(),
         );
         let tensor = TchTensor::from_data(data_expected.clone(), tch::Device::Cpu);
 
@@ -140,7 +139,7 @@ mod tests {
         let data_expected = Data::<f32, 2>::random(
             Shape::new([2, 3]),
             Distribution::Standard,
-            &mut StdRng::from_entropy(),
+            &mut Generator::from_entropy(),
         );
         let tensor = TchTensor::from_data(data_expected.clone(), tch::Device::Cpu);
 
@@ -7,4 +7,5 @@ mod tensor;
 mod tests;
 
 pub use half::f16;
+pub use num_complex::Complex32;
 pub use tensor::*;
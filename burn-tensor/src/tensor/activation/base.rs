@@ -7,16 +7,21 @@ pub fn relu<const D: usize, B: Backend>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
     tensor.relu()
 }
 
+/// Same as [relu], but consumes the tensor so that its memory can be reused.
+pub fn relu_owned<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.relu_owned()
+}
+
 /// Applies the Gaussian Error Linear Units function as described in the paper in [Gaussian Error Linear Units (GELUs)](https://arxiv.org/pdf/1606.08415v3.pdf).
 pub fn gelu<const D: usize, B: Backend>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
-    let x = tensor.div_scalar(2.0_f32.sqrt()).erf().add_scalar(1.0_f32);
+    let x = tensor.div_scalar(2.0_f32.sqrt()).erf_owned() + 1.0_f32;
 
-    tensor.mul(&x) / 2
+    x * tensor.clone() / 2
 }
 
 /// Applies the softmax function.
 pub fn softmax<const D: usize, B: Backend>(tensor: &Tensor<B, D>, dim: usize) -> Tensor<B, D> {
-    log_softmax(tensor, dim).exp()
+    log_softmax(tensor, dim).exp_owned()
 }
 
 /// Applies the log softmax function.
@@ -24,10 +29,10 @@ pub fn log_softmax<const D: usize, B: Backend>(tensor: &Tensor<B, D>, dim: usize
     let tensor_tmp = match B::Elem::precision() {
         Precision::Half => {
             let tensor_full = tensor.to_full_precision();
-            let tensor_tmp = tensor_full.exp().sum_dim(dim).log();
+            let tensor_tmp = tensor_full.exp().sum_dim(dim).log_owned();
             Tensor::from_full_precision(tensor_tmp)
         }
-        _ => tensor.exp().sum_dim(dim).log(),
+        _ => tensor.exp().sum_dim(dim).log_owned(),
     };
 
     tensor.sub(&tensor_tmp)
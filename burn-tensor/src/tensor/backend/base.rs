@@ -1,6 +1,6 @@
 use super::Gradients;
 use crate::ops::*;
-use crate::tensor::Element;
+use crate::tensor::{Element, Generator, RngState};
 
 pub trait Backend:
     TensorOps<Self>
@@ -39,6 +39,16 @@ pub trait Backend:
     fn ad_enabled() -> bool;
     fn name() -> String;
     fn seed(seed: u64);
+
+    /// Returns a new generator forked from the generator of the given device.
+    ///
+    /// Components sampling with the forked generator are reproducible for a given seed, without
+    /// contending for the generator of the device.
+    fn fork_rng(device: Self::Device) -> Generator;
+    /// Returns the state of the generator used by the random operations on the given device.
+    fn get_rng_state(device: Self::Device) -> RngState;
+    /// Restore the generator used by the random operations on the given device.
+    fn set_rng_state(device: Self::Device, state: RngState);
 }
 
 pub(crate) type ADBackendTensorPrimitive<const D: usize, B> =
@@ -2,7 +2,7 @@ use crate::backend::ADBackend;
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::ElementConversion;
-use crate::tensor::{Data, Distribution, Shape};
+use crate::tensor::{Data, Distribution, Generator, Shape};
 use crate::BoolTensor;
 use std::convert::TryInto;
 use std::ops::Range;
@@ -27,6 +27,13 @@ where
     }
 }
 
+impl<B: Backend> From<Vec<B::Elem>> for Tensor<B, 1> {
+    fn from(elems: Vec<B::Elem>) -> Self {
+        let shape = Shape::new([elems.len()]);
+        Self::from_data(Data::new(elems, shape))
+    }
+}
+
 impl<const D: usize, B> Tensor<B, D>
 where
     B: Backend,
@@ -62,6 +69,41 @@ where
         B::device(&self.value)
     }
 
+    /// Sample indexes from the categorical distributions defined by the last dimension, where
+    /// each value is the unnormalized probability of its index.
+    ///
+    /// The last dimension of the returned tensor has the size `num_samples`. The samples are drawn
+    /// with a generator forked from the generator of the device.
+    ///
+    /// # Panics
+    ///
+    /// If a probability is negative or not finite, if the probabilities of a distribution sum to
+    /// zero, or if sampling without replacement more indexes than there are non-zero
+    /// probabilities.
+    pub fn multinomial(
+        &self,
+        num_samples: usize,
+        replacement: bool,
+    ) -> Tensor<B::IntegerBackend, D> {
+        let mut generator = B::fork_rng(self.device());
+        self.multinomial_with(num_samples, replacement, &mut generator)
+    }
+
+    /// Sample indexes like [multinomial](Tensor::multinomial) with the given generator.
+    pub fn multinomial_with(
+        &self,
+        num_samples: usize,
+        replacement: bool,
+        generator: &mut Generator,
+    ) -> Tensor<B::IntegerBackend, D> {
+        Tensor::new(B::multinomial(
+            &self.value,
+            num_samples,
+            replacement,
+            generator,
+        ))
+    }
+
     /// Applies element wise exponential operation.
     ///
     /// `y = e^x`
@@ -90,6 +132,26 @@ where
         Self::new(B::powf(&self.value, value))
     }
 
+    /// Same as [exp](Tensor::exp), but consumes the tensor so that its memory can be reused.
+    pub fn exp_owned(self) -> Self {
+        Self::new(B::exp_owned(self.value))
+    }
+
+    /// Same as [log](Tensor::log), but consumes the tensor so that its memory can be reused.
+    pub fn log_owned(self) -> Self {
+        Self::new(B::log_owned(self.value))
+    }
+
+    /// Same as [erf](Tensor::erf), but consumes the tensor so that its memory can be reused.
+    pub fn erf_owned(self) -> Self {
+        Self::new(B::erf_owned(self.value))
+    }
+
+    /// Same as [powf](Tensor::powf), but consumes the tensor so that its memory can be reused.
+    pub fn powf_owned(self, value: f32) -> Self {
+        Self::new(B::powf_owned(self.value, value))
+    }
+
     /// Returns the shape of the current tensor.
     pub fn shape(&self) -> &Shape<D> {
         B::shape(&self.value)
@@ -124,6 +186,44 @@ where
         Tensor::new(tensor)
     }
 
+    /// Create a tensor from floats (f32) on the default device.
+    ///
+    /// The values are converted into the element type of the backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    /// ```
+    pub fn from_floats<A: Into<Data<f32, D>>>(floats: A) -> Self {
+        Self::from_data(floats.into().convert())
+    }
+
+    /// Create a tensor from floats (f32) on the given device.
+    pub fn from_floats_device<A: Into<Data<f32, D>>>(floats: A, device: B::Device) -> Self {
+        Self::from_data_device(floats.into().convert(), device)
+    }
+
+    /// Create a tensor from integers (i32) on the default device.
+    ///
+    /// The values are converted into the element type of the backend.
+    pub fn from_ints<A: Into<Data<i32, D>>>(ints: A) -> Self {
+        Self::from_data(ints.into().convert())
+    }
+
+    /// Create a tensor from integers (i32) on the given device.
+    pub fn from_ints_device<A: Into<Data<i32, D>>>(ints: A, device: B::Device) -> Self {
+        Self::from_data_device(ints.into().convert(), device)
+    }
+
+    /// Returns true if all elements are close to the ones of the other tensor.
+    ///
+    /// Two elements `a` and `b` are close if `|a - b| <= atol + rtol * |b|`, NaN values are only
+    /// close to other NaN values.
+    pub fn all_close(&self, other: &Self, rtol: f64, atol: f64) -> bool {
+        self.to_data().all_close(&other.to_data(), rtol, atol)
+    }
+
     /// Returns a new tensor with the same shape and device as the current tensor filled with zeros.
     pub fn zeros_like(&self) -> Self {
         Tensor::new(B::zeros(*self.shape(), self.device()))
@@ -381,6 +481,32 @@ where
         Self::new(tensor)
     }
 
+    /// Create a random tensor of the given shape on the default device, sampled with the given
+    /// generator instead of the generator of the device.
+    pub fn random_with<S: Into<Shape<D>>>(
+        shape: S,
+        distribution: Distribution<B::Elem>,
+        generator: &mut Generator,
+    ) -> Self {
+        Self::random_with_device(shape, distribution, generator, B::Device::default())
+    }
+
+    /// Create a random tensor of the given shape on the given device, sampled with the given
+    /// generator instead of the generator of the device.
+    pub fn random_with_device<S: Into<Shape<D>>>(
+        shape: S,
+        distribution: Distribution<B::Elem>,
+        generator: &mut Generator,
+        device: B::Device,
+    ) -> Self {
+        Self::new(B::random_with(
+            shape.into(),
+            distribution,
+            generator,
+            device,
+        ))
+    }
+
     /// Create a tensor of the given shape where each element is zero.
     pub fn zeros<S: Into<Shape<D>>>(shape: S) -> Self {
         let tensor = B::zeros(shape.into(), B::Device::default());
@@ -525,6 +651,20 @@ where
         Self::new(B::detach(&self.value))
     }
 
+    /// Enable or disable the gradients of the current tensor.
+    ///
+    /// Operations whose inputs all have their gradients disabled are not recorded in the
+    /// autodiff graph. Enabling the gradients of a tensor that had them disabled makes it a new
+    /// leaf of the graph. This function does nothing when autodiff is not enabled.
+    pub fn require_grad(self, require_grad: bool) -> Self {
+        Self::new(B::require_grad(&self.value, require_grad))
+    }
+
+    /// Returns whether the gradients of the current tensor are computed during the backward pass.
+    pub fn is_require_grad(&self) -> bool {
+        B::is_require_grad(&self.value)
+    }
+
     /// Unsqueeze the current tensor. Create new dimensions to fit the given size.
     ///
     /// # Panics
@@ -574,6 +714,10 @@ where
     pub(crate) fn relu(&self) -> Self {
         Self::new(B::relu(&self.value))
     }
+
+    pub(crate) fn relu_owned(self) -> Self {
+        Self::new(B::relu_owned(self.value))
+    }
 }
 
 impl<const D: usize, B> std::ops::Add<Self> for Tensor<B, D>
@@ -583,7 +727,7 @@ where
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        Tensor::add(&self, &other)
+        Self::new(B::add_owned(self.value, other.value))
     }
 }
 
@@ -595,7 +739,7 @@ where
     type Output = Self;
 
     fn add(self, other: E) -> Self {
-        Tensor::add_scalar(&self, other)
+        Self::new(B::add_scalar_owned(self.value, &other.to_elem()))
     }
 }
 
@@ -606,7 +750,7 @@ where
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        Tensor::sub(&self, &other)
+        Self::new(B::sub_owned(self.value, other.value))
     }
 }
 
@@ -618,7 +762,7 @@ where
     type Output = Self;
 
     fn sub(self, other: E) -> Self {
-        Tensor::sub_scalar(&self, other)
+        Self::new(B::sub_scalar_owned(self.value, &other.to_elem()))
     }
 }
 
@@ -629,7 +773,7 @@ where
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        Tensor::mul(&self, &other)
+        Self::new(B::mul_owned(self.value, other.value))
     }
 }
 
@@ -641,7 +785,7 @@ where
     type Output = Self;
 
     fn mul(self, other: E) -> Self {
-        Tensor::mul_scalar(&self, other)
+        Self::new(B::mul_scalar_owned(self.value, &other.to_elem()))
     }
 }
 
@@ -652,7 +796,7 @@ where
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
-        Tensor::div(&self, &other)
+        Self::new(B::div_owned(self.value, other.value))
     }
 }
 
@@ -664,7 +808,18 @@ where
     type Output = Self;
 
     fn div(self, other: E) -> Self {
-        Tensor::div_scalar(&self, other)
+        Self::new(B::div_scalar_owned(self.value, &other.to_elem()))
+    }
+}
+
+impl<const D: usize, B> std::ops::Neg for Tensor<B, D>
+where
+    B: Backend,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(B::neg_owned(self.value))
     }
 }
 
@@ -19,6 +19,11 @@ where
         B::bool_shape(&self.value)
     }
 
+    /// Returns the device of the current tensor.
+    pub fn device(&self) -> B::Device {
+        B::bool_device(&self.value)
+    }
+
     pub fn to_device(&self, device: B::Device) -> Self {
         Self::new(B::bool_to_device(&self.value, device))
     }
@@ -0,0 +1,231 @@
+use crate::backend::Backend;
+use crate::{Complex32, Data, ElementConversion, Shape, Tensor};
+
+/// A complex tensor stored as two real tensors, one for the real parts and one for the
+/// imaginary parts.
+///
+/// Since all operations are built on top of real tensors, they are differentiable when the
+/// backend supports auto-differentiation.
+///
+/// # Notes
+///
+/// [Complex32](Complex32) isn't a tensor [element](crate::Element), since elements must be ordered
+/// and sampled from real distributions, and every backend operation (comparisons, argmax,
+/// clamping, random, ...) relies on it. Storing both parts in real tensors gives complex values
+/// to all backends without duplicating their operations.
+#[derive(Debug, Clone)]
+pub struct ComplexTensor<B: Backend, const D: usize> {
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> ComplexTensor<B, D> {
+    /// Create a complex tensor from its real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the parts differ.
+    pub fn new(re: Tensor<B, D>, im: Tensor<B, D>) -> Self {
+        if re.dims() != im.dims() {
+            panic!(
+                "Real and imaginary parts must have the same shape, got {:?} and {:?}",
+                re.dims(),
+                im.dims()
+            );
+        }
+
+        Self { re, im }
+    }
+
+    /// Create a complex tensor with null imaginary parts.
+    pub fn from_real(re: Tensor<B, D>) -> Self {
+        let im = re.zeros_like();
+        Self { re, im }
+    }
+
+    /// Create a complex tensor from the given data.
+    pub fn from_data(data: Data<Complex32, D>) -> Self {
+        Self::from_data_device(data, B::Device::default())
+    }
+
+    /// Create a complex tensor from the given data on the given device.
+    pub fn from_data_device(data: Data<Complex32, D>, device: B::Device) -> Self {
+        let re = data.value.iter().map(|value| value.re.to_elem()).collect();
+        let im = data.value.iter().map(|value| value.im.to_elem()).collect();
+
+        Self {
+            re: Tensor::from_data_device(Data::new(re, data.shape), device),
+            im: Tensor::from_data_device(Data::new(im, data.shape), device),
+        }
+    }
+
+    /// Returns the data of the current tensor.
+    pub fn to_data(&self) -> Data<Complex32, D> {
+        let re = self.re.to_data();
+        let im = self.im.to_data();
+
+        let value = re
+            .value
+            .iter()
+            .zip(im.value.iter())
+            .map(|(re, im)| Complex32::new(re.to_elem(), im.to_elem()))
+            .collect();
+
+        Data::new(value, re.shape)
+    }
+
+    /// Returns the real parts.
+    pub fn re(&self) -> &Tensor<B, D> {
+        &self.re
+    }
+
+    /// Returns the imaginary parts.
+    pub fn im(&self) -> &Tensor<B, D> {
+        &self.im
+    }
+
+    /// Returns the dimensions of the current tensor.
+    pub fn dims(&self) -> [usize; D] {
+        self.re.dims()
+    }
+
+    /// Returns the device of the current tensor.
+    pub fn device(&self) -> B::Device {
+        self.re.device()
+    }
+
+    /// Returns the complex conjugate.
+    pub fn conj(&self) -> Self {
+        Self {
+            re: self.re.clone(),
+            im: self.im.neg(),
+        }
+    }
+
+    /// Applies element wise addition.
+    pub fn add(&self, other: &Self) -> Self {
+        Self {
+            re: self.re.add(&other.re),
+            im: self.im.add(&other.im),
+        }
+    }
+
+    /// Applies element wise complex multiplication.
+    pub fn mul(&self, other: &Self) -> Self {
+        Self {
+            re: self.re.mul(&other.re).sub(&self.im.mul(&other.im)),
+            im: self.re.mul(&other.im).add(&self.im.mul(&other.re)),
+        }
+    }
+
+    /// Returns the squared magnitude of each element, also known as the power spectrum.
+    pub fn norm_sqr(&self) -> Tensor<B, D> {
+        self.re.mul(&self.re).add(&self.im.mul(&self.im))
+    }
+
+    /// Returns the magnitude of each element.
+    pub fn abs(&self) -> Tensor<B, D> {
+        self.norm_sqr().powf(0.5)
+    }
+
+    /// Computes the discrete Fourier transform along the last dimension.
+    pub fn fft(&self) -> Self {
+        self.transform(false)
+    }
+
+    /// Computes the inverse discrete Fourier transform along the last dimension, normalized by
+    /// `1 / n`.
+    pub fn ifft(&self) -> Self {
+        self.transform(true)
+    }
+
+    fn transform(&self, inverse: bool) -> Self {
+        let packed = Tensor::cat(vec![self.re.clone(), self.im.clone()], D - 1);
+        let packed = Tensor::new(B::fft(&packed.value, inverse));
+
+        Self::unpack(&packed)
+    }
+
+    /// Split the tensor with the real parts followed by the imaginary parts along the last
+    /// dimension.
+    fn unpack(packed: &Tensor<B, D>) -> Self {
+        let dims = packed.dims();
+        let size = dims[D - 1] / 2;
+
+        let ranges = |offset: usize| {
+            let mut ranges = dims.map(|dim| 0..dim);
+            ranges[D - 1] = offset..offset + size;
+            ranges
+        };
+
+        Self {
+            re: packed.index(ranges(0)),
+            im: packed.index(ranges(size)),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the discrete Fourier transform of real values along the last dimension.
+    ///
+    /// Only the `n / 2 + 1` non-redundant frequencies are returned, the others being the complex
+    /// conjugates of the returned ones.
+    pub fn rfft(&self) -> ComplexTensor<B, D> {
+        ComplexTensor::unpack(&Tensor::new(B::rfft(&self.value)))
+    }
+}
+
+impl<B: Backend> Tensor<B, 2> {
+    /// Computes the short-time Fourier transform of a batch of signals.
+    ///
+    /// The signals are split into frames of `n_fft` samples every `hop_length` samples, each
+    /// frame being multiplied by the window before its [rfft](Tensor::rfft). The signals are not
+    /// padded, so the last samples that don't fill a frame are ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - signals: `[batch_size, num_samples]`
+    /// - window: `[n_fft]`
+    /// - output: `[batch_size, num_frames, n_fft / 2 + 1]`
+    ///
+    /// # Panics
+    ///
+    /// If the signals are shorter than a frame or if the window doesn't have `n_fft` elements.
+    pub fn stft(
+        &self,
+        n_fft: usize,
+        hop_length: usize,
+        window: Option<&Tensor<B, 1>>,
+    ) -> ComplexTensor<B, 3> {
+        let [batch_size, num_samples] = self.dims();
+
+        if n_fft == 0 || hop_length == 0 || num_samples < n_fft {
+            panic!(
+                "Can't split {} samples into frames of {} samples every {} samples",
+                num_samples, n_fft, hop_length
+            );
+        }
+
+        let num_frames = (num_samples - n_fft) / hop_length + 1;
+        let frames = (0..num_frames)
+            .map(|frame| {
+                let start = frame * hop_length;
+                self.index([0..batch_size, start..start + n_fft])
+                    .reshape(Shape::new([batch_size, 1, n_fft]))
+            })
+            .collect();
+        let frames = Tensor::cat(frames, 1);
+
+        let frames = match window {
+            Some(window) if window.dims() != [n_fft] => panic!(
+                "Expected a window of {} elements, got {:?}",
+                n_fft,
+                window.dims()
+            ),
+            Some(window) => frames.mul(&window.unsqueeze()),
+            None => frames,
+        };
+
+        frames.rfft()
+    }
+}
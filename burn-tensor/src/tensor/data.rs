@@ -1,6 +1,8 @@
 use super::ops::{Ones, Zeros};
+use crate::Generator;
 use crate::{tensor::Shape, Element, ElementConversion};
-use rand::{distributions::Standard, prelude::StdRng, Rng};
+use rand::{distributions::Standard, Rng};
+use statrs::distribution::ContinuousCDF;
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq)]
 pub struct DataSerialize<P> {
@@ -20,6 +22,14 @@ pub enum Distribution<P> {
     Bernoulli(f64),
     Uniform(P, P),
     Normal(f64, f64),
+    /// Normal distribution (mean, std) truncated to the interval [low, high].
+    ///
+    /// Sampling panics if `low` isn't lower than `high`.
+    TruncatedNormal(f64, f64, f64, f64),
+    /// Exponential distribution with the given rate.
+    Exponential(f64),
+    /// Gumbel distribution (location, scale).
+    Gumbel(f64, f64),
 }
 
 #[derive(new)]
@@ -29,7 +39,7 @@ where
     P: rand::distributions::uniform::SampleUniform,
 {
     kind: DistributionSamplerKind<P>,
-    rng: &'a mut StdRng,
+    rng: &'a mut Generator,
 }
 
 pub enum DistributionSamplerKind<P>
@@ -41,6 +51,9 @@ where
     Uniform(rand::distributions::Uniform<P>),
     Bernoulli(rand::distributions::Bernoulli),
     Normal(statrs::distribution::Normal),
+    TruncatedNormal(f64, f64, f64, f64),
+    Exponential(statrs::distribution::Exp),
+    Gumbel(f64, f64),
 }
 
 impl<'a, P> DistributionSampler<'a, P>
@@ -63,6 +76,69 @@ where
             DistributionSamplerKind::Normal(distribution) => {
                 self.rng.sample(distribution).to_elem()
             }
+            DistributionSamplerKind::TruncatedNormal(mean, std, low, high) => {
+                let value = sample_truncated_standard_normal(self.rng, *low, *high);
+                (mean + std * value).to_elem()
+            }
+            DistributionSamplerKind::Exponential(distribution) => {
+                self.rng.sample(distribution).to_elem()
+            }
+            DistributionSamplerKind::Gumbel(location, scale) => {
+                let prob = 1.0 - self.rng.gen::<f64>();
+                (location - scale * f64::ln(-f64::ln(prob))).to_elem()
+            }
+        }
+    }
+}
+
+/// Samples the standard normal distribution truncated to the interval [low, high].
+fn sample_truncated_standard_normal(rng: &mut Generator, low: f64, high: f64) -> f64 {
+    // Past this many standard deviations, the cumulative probabilities of the bounds are too
+    // close to each other (or underflow) for inverse transform sampling to spread the values.
+    const TAIL: f64 = 5.0;
+
+    if low >= TAIL {
+        sample_standard_normal_tail(rng, low, high)
+    } else if high <= -TAIL {
+        -sample_standard_normal_tail(rng, -high, -low)
+    } else if low > 0.0 {
+        // The cumulative probabilities of the lower tail keep their precision, unlike the ones
+        // of the upper tail which are rounded toward 1.
+        -sample_standard_normal_inverse_cdf(rng, -high, -low)
+    } else {
+        sample_standard_normal_inverse_cdf(rng, low, high)
+    }
+}
+
+/// Inverse transform sampling between the cumulative probabilities of the bounds.
+fn sample_standard_normal_inverse_cdf(rng: &mut Generator, low: f64, high: f64) -> f64 {
+    let distribution = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+    let cdf_low = distribution.cdf(low);
+    let cdf_high = distribution.cdf(high);
+    let prob = cdf_low + (cdf_high - cdf_low) * rng.gen::<f64>();
+
+    distribution.inverse_cdf(prob).clamp(low, high)
+}
+
+/// Rejection sampling of the upper tail [low, high] with `low > 0` (Robert, 1995).
+///
+/// Wide intervals are sampled with a translated exponential proposal and narrow ones with a
+/// uniform proposal, so that both keep a high acceptance rate.
+fn sample_standard_normal_tail(rng: &mut Generator, low: f64, high: f64) -> f64 {
+    if (high - low) * low < 1.0 {
+        loop {
+            let value = low + (high - low) * rng.gen::<f64>();
+            if rng.gen::<f64>() <= f64::exp((low * low - value * value) / 2.0) {
+                return value;
+            }
+        }
+    }
+
+    let alpha = (low + f64::sqrt(low * low + 4.0)) / 2.0;
+    loop {
+        let value = low - f64::ln(1.0 - rng.gen::<f64>()) / alpha;
+        if value <= high && rng.gen::<f64>() <= f64::exp(-(value - alpha).powi(2) / 2.0) {
+            return value;
         }
     }
 }
@@ -72,7 +148,7 @@ where
     Standard: rand::distributions::Distribution<P>,
     P: rand::distributions::uniform::SampleUniform,
 {
-    pub fn sampler(self, rng: &'_ mut StdRng) -> DistributionSampler<'_, P> {
+    pub fn sampler(self, rng: &'_ mut Generator) -> DistributionSampler<'_, P> {
         let kind = match self {
             Distribution::Standard => {
                 DistributionSamplerKind::Standard(rand::distributions::Standard {})
@@ -86,6 +162,26 @@ where
             Distribution::Normal(mean, std) => DistributionSamplerKind::Normal(
                 statrs::distribution::Normal::new(mean, std).unwrap(),
             ),
+            Distribution::TruncatedNormal(mean, std, low, high) => {
+                assert!(
+                    low < high,
+                    "Truncated normal distribution requires low < high, got low = {} and high = {}",
+                    low,
+                    high
+                );
+                DistributionSamplerKind::TruncatedNormal(
+                    mean,
+                    std,
+                    (low - mean) / std,
+                    (high - mean) / std,
+                )
+            }
+            Distribution::Exponential(rate) => {
+                DistributionSamplerKind::Exponential(statrs::distribution::Exp::new(rate).unwrap())
+            }
+            Distribution::Gumbel(location, scale) => {
+                DistributionSamplerKind::Gumbel(location, scale)
+            }
         };
 
         DistributionSampler::new(kind, rng)
@@ -102,6 +198,11 @@ where
             Distribution::Uniform(a, b) => Distribution::Uniform(E::from_elem(a), E::from_elem(b)),
             Distribution::Bernoulli(prob) => Distribution::Bernoulli(prob),
             Distribution::Normal(mean, std) => Distribution::Normal(mean, std),
+            Distribution::TruncatedNormal(mean, std, low, high) => {
+                Distribution::TruncatedNormal(mean, std, low, high)
+            }
+            Distribution::Exponential(rate) => Distribution::Exponential(rate),
+            Distribution::Gumbel(location, scale) => Distribution::Gumbel(location, scale),
         }
     }
 }
@@ -143,7 +244,7 @@ impl<const D: usize> Data<bool, D> {
     }
 }
 impl<P: Element, const D: usize> Data<P, D> {
-    pub fn random(shape: Shape<D>, distribution: Distribution<P>, rng: &mut StdRng) -> Self {
+    pub fn random(shape: Shape<D>, distribution: Distribution<P>, rng: &mut Generator) -> Self {
         let num_elements = shape.num_elements();
         let mut data = Vec::with_capacity(num_elements);
 
@@ -154,6 +255,83 @@ impl<P: Element, const D: usize> Data<P, D> {
         Data::new(data, shape)
     }
 }
+impl<P: Element, const D: usize> Data<P, D> {
+    /// Sample indexes from the categorical distributions defined by the last dimension, where
+    /// each value is the unnormalized probability of its index.
+    ///
+    /// The last dimension of the returned data has the size `num_samples`.
+    ///
+    /// # Panics
+    ///
+    /// If a probability is negative or not finite, if the probabilities of a distribution sum to
+    /// zero, or if sampling without replacement more indexes than there are non-zero
+    /// probabilities.
+    pub fn multinomial(
+        &self,
+        num_samples: usize,
+        replacement: bool,
+        rng: &mut Generator,
+    ) -> Data<i64, D> {
+        let num_categories = self.shape.dims[D - 1];
+        let mut dims = self.shape.dims;
+        dims[D - 1] = num_samples;
+
+        let mut value = Vec::with_capacity(dims.iter().product());
+
+        for probs in self.value.chunks(num_categories.max(1)) {
+            let mut probs: Vec<f64> = probs
+                .iter()
+                .map(|prob| prob.to_f64().unwrap_or(f64::NAN))
+                .collect();
+
+            if probs.iter().any(|prob| !prob.is_finite() || *prob < 0.0) {
+                panic!(
+                    "Probabilities must be finite and non-negative, got {:?}",
+                    probs
+                );
+            }
+
+            let num_non_zero = probs.iter().filter(|prob| **prob > 0.0).count();
+            if num_non_zero == 0 {
+                panic!("Probabilities must not sum to zero");
+            }
+            if !replacement && num_samples > num_non_zero {
+                panic!(
+                    "Can't sample {} indexes without replacement from {} non-zero probabilities",
+                    num_samples, num_non_zero
+                );
+            }
+
+            for _ in 0..num_samples {
+                let index = sample_index(&probs, rng);
+                value.push(index as i64);
+
+                if !replacement {
+                    probs[index] = 0.0;
+                }
+            }
+        }
+
+        Data::new(value, Shape::new(dims))
+    }
+}
+
+fn sample_index(probs: &[f64], rng: &mut Generator) -> usize {
+    let total: f64 = probs.iter().sum();
+    let target = rng.gen::<f64>() * total;
+    let mut cumulative = 0.0;
+
+    for (index, prob) in probs.iter().enumerate() {
+        cumulative += prob;
+        if target < cumulative {
+            return index;
+        }
+    }
+
+    // Rounding errors can leave the target above the last cumulative probability.
+    probs.iter().rposition(|prob| *prob > 0.0).unwrap()
+}
+
 impl<P: std::fmt::Debug, const D: usize> Data<P, D>
 where
     P: Zeros + Default,
@@ -233,6 +411,102 @@ impl<P: Into<f64> + Clone + std::fmt::Debug + PartialEq, const D: usize> Data<P,
     }
 }
 
+impl<P: num_traits::ToPrimitive + std::fmt::Debug, const D: usize> Data<P, D> {
+    /// Returns true if all elements are close to the ones of the other data.
+    ///
+    /// Two elements `a` and `b` are close if `|a - b| <= atol + rtol * |b|`, NaN values are only
+    /// close to other NaN values and infinite values to the same infinity.
+    pub fn all_close(&self, other: &Self, rtol: f64, atol: f64) -> bool {
+        self.close_mismatch(other, rtol, atol).is_none()
+    }
+
+    /// Assert that all elements are close to the ones of the other data, see
+    /// [all_close](Data::all_close).
+    ///
+    /// # Panics
+    ///
+    /// If the shapes are different or if any element isn't close, with a message reporting the
+    /// first mismatching index and the maximum absolute and relative errors.
+    #[track_caller]
+    pub fn assert_close(&self, other: &Self, rtol: f64, atol: f64) {
+        if let Some(message) = self.close_mismatch(other, rtol, atol) {
+            panic!("{}", message);
+        }
+    }
+
+    fn close_mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<String> {
+        if self.shape != other.shape {
+            return Some(format!(
+                "Shapes are different => actual: {:?}, expected: {:?}",
+                self.shape.dims, other.shape.dims
+            ));
+        }
+
+        let mut first_mismatch = None;
+        let mut num_mismatches = 0;
+        let mut max_abs_error: f64 = 0.0;
+        let mut max_rel_error: f64 = 0.0;
+
+        for (i, (a, b)) in self.value.iter().zip(other.value.iter()).enumerate() {
+            let a = a.to_f64().unwrap_or(f64::NAN);
+            let b = b.to_f64().unwrap_or(f64::NAN);
+
+            let close = match (a.is_nan(), b.is_nan()) {
+                (true, true) => true,
+                (false, false) if a == b => true,
+                // Infinite values are only close to the same infinity.
+                (false, false) if a.is_finite() && b.is_finite() => {
+                    (a - b).abs() <= atol + rtol * b.abs()
+                }
+                _ => false,
+            };
+
+            if !a.is_nan() && !b.is_nan() && a != b {
+                let error = (a - b).abs();
+                max_abs_error = max_abs_error.max(error);
+                max_rel_error = max_rel_error.max(error / b.abs());
+            }
+
+            if !close {
+                num_mismatches += 1;
+                first_mismatch.get_or_insert((i, a, b));
+            }
+        }
+
+        let (index, actual, expected) = first_mismatch?;
+
+        Some(format!(
+            "Tensors are not close (rtol = {}, atol = {})\n  \
+             first mismatch at index {:?} => actual: {}, expected: {}\n  \
+             mismatched elements: {} / {}\n  \
+             max absolute error: {}\n  \
+             max relative error: {}\n  \
+             shape: {:?}",
+            rtol,
+            atol,
+            unravel_index(index, &self.shape.dims),
+            actual,
+            expected,
+            num_mismatches,
+            self.value.len(),
+            max_abs_error,
+            max_rel_error,
+            self.shape.dims,
+        ))
+    }
+}
+
+fn unravel_index<const D: usize>(mut index: usize, dims: &[usize; D]) -> [usize; D] {
+    let mut indexes = [0; D];
+
+    for i in (0..D).rev() {
+        indexes[i] = index % dims[i];
+        index /= dims[i];
+    }
+
+    indexes
+}
+
 impl<const D: usize> Data<usize, D> {
     pub fn from_usize<O: num_traits::FromPrimitive>(self) -> Data<O, D> {
         let value: Vec<O> = self
@@ -306,6 +580,26 @@ impl<P: std::fmt::Debug + Copy, const A: usize, const B: usize, const C: usize>
     }
 }
 
+impl<P: std::fmt::Debug + Copy, const A: usize, const B: usize, const C: usize, const D: usize>
+    From<[[[[P; D]; C]; B]; A]> for Data<P, 4>
+{
+    fn from(elems: [[[[P; D]; C]; B]; A]) -> Self {
+        let mut data = Vec::with_capacity(A * B * C * D);
+
+        for elem in elems.into_iter().take(A) {
+            for elem in elem.into_iter().take(B) {
+                for elem in elem.into_iter().take(C) {
+                    for elem in elem.into_iter().take(D) {
+                        data.push(elem);
+                    }
+                }
+            }
+        }
+
+        Data::new(data, Shape::new([A, B, C, D]))
+    }
+}
+
 impl<P: std::fmt::Debug, const D: usize> std::fmt::Display for Data<P, D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(format!("{:?}", &self.value).as_str())
@@ -314,16 +608,159 @@ impl<P: std::fmt::Debug, const D: usize> std::fmt::Display for Data<P, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rand::SeedableRng;
 
     #[test]
     fn should_have_right_num_elements() {
         let shape = Shape::new([3, 5, 6]);
-        let data =
-            Data::<f32, 3>::random(shape, Distribution::Standard, &mut StdRng::from_entropy());
+        let data = Data::<f32, 3>::random(
+            shape,
+            Distribution::Standard,
+            &mut Generator::from_entropy(),
+        );
         assert_eq!(shape.num_elements(), data.value.len());
     }
 
+    #[test]
+    fn should_be_close_within_tolerance() {
+        let data = Data::<f64, 1>::from([1.0, 1000.0, f64::NAN]);
+        let other = Data::from([1.0 + 1e-9, 1000.001, f64::NAN]);
+
+        assert!(data.all_close(&other, 1e-5, 1e-8));
+        assert!(!data.all_close(&other, 0.0, 1e-8));
+    }
+
+    #[test]
+    fn should_not_be_close_when_only_one_is_nan() {
+        let data = Data::<f64, 1>::from([1.0, f64::NAN]);
+        let other = Data::from([1.0, 1.0]);
+
+        assert!(!data.all_close(&other, 1e-5, 1e-8));
+    }
+
+    #[test]
+    fn should_not_be_close_to_infinity_when_finite() {
+        let data = Data::<f64, 1>::from([1.0, f64::INFINITY]);
+        let other = Data::from([f64::INFINITY, f64::INFINITY]);
+
+        assert!(!data.all_close(&other, 1e-5, 1e-8));
+        assert!(data.all_close(&data, 1e-5, 1e-8));
+    }
+
+    #[test]
+    fn should_not_be_close_to_the_opposite_infinity() {
+        let data = Data::<f64, 1>::from([f64::INFINITY]);
+        let other = Data::from([f64::NEG_INFINITY]);
+
+        assert!(!data.all_close(&other, 1e-5, 1e-8));
+        assert!(!other.all_close(&data, 1e-5, 1e-8));
+    }
+
+    #[test]
+    fn should_report_first_mismatch() {
+        let data = Data::<f64, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let other = Data::from([[1.0, 2.0], [3.5, 4.5]]);
+
+        let message = data.close_mismatch(&other, 1e-5, 1e-8).unwrap();
+
+        assert!(message.contains("first mismatch at index [1, 0] => actual: 3, expected: 3.5"));
+        assert!(message.contains("mismatched elements: 2 / 4"));
+        assert!(message.contains("max absolute error: 0.5"));
+    }
+
+    #[test]
+    fn should_sample_truncated_normal_within_bounds() {
+        let distribution = Distribution::TruncatedNormal(0.0, 1.0, -0.5, 2.0);
+
+        let data = Data::<f64, 1>::random(Shape::new([1000]), distribution, &mut Generator::new(0));
+
+        assert!(data.value.iter().all(|value| (-0.5..=2.0).contains(value)));
+    }
+
+    #[test]
+    fn should_sample_truncated_normal_far_in_the_tails() {
+        for (low, high) in [(10.0, 11.0), (-11.0, -10.0), (4.0, 6.0), (10.0, 10.01)] {
+            let distribution = Distribution::TruncatedNormal(0.0, 1.0, low, high);
+
+            let data =
+                Data::<f64, 1>::random(Shape::new([1000]), distribution, &mut Generator::new(0));
+            let min = data.value.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = data.value.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+            assert!(
+                low <= min && max <= high,
+                "{min} {max} not in [{low}, {high}]"
+            );
+            assert!(max - min > (high - low) / 2.0, "{min} {max} don't spread");
+        }
+    }
+
+    #[test]
+    fn should_sample_truncated_normal_tail_with_right_mean() {
+        let distribution = Distribution::TruncatedNormal(1.0, 2.0, 21.0, 23.0);
+
+        let data =
+            Data::<f64, 1>::random(Shape::new([10000]), distribution, &mut Generator::new(0));
+        let mean = data.value.iter().sum::<f64>() / 10000.0;
+
+        // The standardized bounds are [10, 11], whose truncated mean is about 10.098.
+        assert!((mean - (1.0 + 2.0 * 10.098)).abs() < 0.02, "{}", mean);
+    }
+
+    #[test]
+    #[should_panic(expected = "Truncated normal distribution requires low < high")]
+    fn should_not_sample_truncated_normal_with_inverted_bounds() {
+        let distribution = Distribution::TruncatedNormal(0.0, 1.0, 2.0, -0.5);
+
+        Data::<f64, 1>::random(Shape::new([10]), distribution, &mut Generator::new(0));
+    }
+
+    #[test]
+    fn should_sample_exponential_with_right_mean() {
+        let distribution = Distribution::Exponential(2.0);
+
+        let data =
+            Data::<f64, 1>::random(Shape::new([10000]), distribution, &mut Generator::new(0));
+        let mean = data.value.iter().sum::<f64>() / 10000.0;
+
+        assert!(data.value.iter().all(|value| *value >= 0.0));
+        assert!((mean - 0.5).abs() < 0.05, "mean {}", mean);
+    }
+
+    #[test]
+    fn should_sample_gumbel_with_right_mean() {
+        let distribution = Distribution::Gumbel(1.0, 2.0);
+
+        let data =
+            Data::<f64, 1>::random(Shape::new([10000]), distribution, &mut Generator::new(0));
+        let mean = data.value.iter().sum::<f64>() / 10000.0;
+
+        // The mean is location + scale * euler_gamma.
+        assert!((mean - 2.1544).abs() < 0.1, "mean {}", mean);
+    }
+
+    #[test]
+    fn should_sample_multinomial_without_replacement() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0, 2.0, 0.0], [5.0, 0.0, 0.0, 1.0]]);
+
+        let samples = data.multinomial(2, false, &mut Generator::new(0));
+
+        assert_eq!(samples.shape, Shape::new([2, 2]));
+        let mut row_1 = samples.value[0..2].to_vec();
+        let mut row_2 = samples.value[2..4].to_vec();
+        row_1.sort();
+        row_2.sort();
+        assert_eq!(row_1, vec![1, 2]);
+        assert_eq!(row_2, vec![0, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sampling_too_many_indexes_without_replacement() {
+        let data = Data::<f32, 1>::from([0.0, 1.0, 2.0]);
+
+        data.multinomial(3, false, &mut Generator::new(0));
+    }
+
     #[test]
     fn should_have_right_shape() {
         let data = Data::from([[3.0, 5.0, 6.0]]);
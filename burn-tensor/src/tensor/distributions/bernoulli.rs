@@ -0,0 +1,108 @@
+use super::special::softplus;
+use super::KlDivergence;
+use crate::backend::Backend;
+use crate::{BoolTensor, Distribution, ElementConversion, Tensor};
+
+/// Bernoulli distribution parameterized by the probability of sampling one.
+#[derive(Debug, Clone)]
+pub struct Bernoulli<B: Backend, const D: usize> {
+    pub probs: Tensor<B, D>,
+    logits: Option<Tensor<B, D>>,
+}
+
+impl<B: Backend, const D: usize> Bernoulli<B, D> {
+    /// Create a new Bernoulli distribution from probabilities.
+    pub fn new(probs: Tensor<B, D>) -> Self {
+        Self {
+            probs,
+            logits: None,
+        }
+    }
+
+    /// Create a new Bernoulli distribution from logits, where the probabilities are the sigmoid
+    /// of the logits.
+    ///
+    /// The log probabilities are computed with the softplus of the logits, so that they don't
+    /// underflow for large logits.
+    pub fn from_logits(logits: Tensor<B, D>) -> Self {
+        Self {
+            probs: softplus(&logits.neg()).neg().exp(),
+            logits: Some(logits),
+        }
+    }
+
+    /// Draw a sample of zeros and ones, without gradient.
+    ///
+    /// # Notes
+    ///
+    /// The Bernoulli distribution is discrete, so there is no reparameterized sample.
+    pub fn sample(&self) -> Tensor<B, D> {
+        let distribution = Distribution::Uniform(0.0.to_elem(), 1.0.to_elem());
+        let mask = self.probs.random_like(distribution).lower(&self.probs);
+
+        self.probs.zeros_like().mask_fill(&mask, 1.0).detach()
+    }
+
+    /// Returns the log of the probability of the given value.
+    pub fn log_prob(&self, value: &Tensor<B, D>) -> Tensor<B, D> {
+        let value_zero = value.neg().add_scalar(1.0);
+        let log_one = value.mul(&self.log_prob_one(&value.equal_scalar(0.0)));
+        let log_zero = value_zero.mul(&self.log_prob_zero(&value_zero.equal_scalar(0.0)));
+
+        log_one.add(&log_zero)
+    }
+
+    /// Returns the entropy.
+    pub fn entropy(&self) -> Tensor<B, D> {
+        let (probs_one, probs_zero) = (&self.probs, self.probs_zero());
+
+        probs_one
+            .mul(&self.log_prob_one(&probs_one.equal_scalar(0.0)))
+            .add(&probs_zero.mul(&self.log_prob_zero(&probs_zero.equal_scalar(0.0))))
+            .neg()
+    }
+
+    fn probs_zero(&self) -> Tensor<B, D> {
+        self.probs.neg().add_scalar(1.0)
+    }
+
+    // The log probabilities are computed on demand, so that sampling with probabilities of zero
+    // or one doesn't create infinite values. They are zeros where the `ignored` mask is set, so
+    // that the `0 * log(0)` terms are zeros instead of NaN.
+    fn log_prob_one(&self, ignored: &BoolTensor<B, D>) -> Tensor<B, D> {
+        match &self.logits {
+            Some(logits) => softplus(&logits.neg()).neg().mask_fill(ignored, 0.0),
+            None => self.probs.mask_fill(ignored, 1.0).log(),
+        }
+    }
+
+    fn log_prob_zero(&self, ignored: &BoolTensor<B, D>) -> Tensor<B, D> {
+        match &self.logits {
+            Some(logits) => softplus(logits).neg().mask_fill(ignored, 0.0),
+            None => self.probs_zero().mask_fill(ignored, 1.0).log(),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<Bernoulli<B, D>> for Bernoulli<B, D> {
+    type Output = Tensor<B, D>;
+
+    fn kl_divergence(&self, other: &Bernoulli<B, D>) -> Tensor<B, D> {
+        let (probs_one, probs_zero) = (&self.probs, self.probs_zero());
+        let (ignored_one, ignored_zero) =
+            (probs_one.equal_scalar(0.0), probs_zero.equal_scalar(0.0));
+
+        let kl_one = probs_one.mul(
+            &self
+                .log_prob_one(&ignored_one)
+                .sub(&other.log_prob_one(&ignored_one)),
+        );
+        let kl_zero = probs_zero.mul(
+            &self
+                .log_prob_zero(&ignored_zero)
+                .sub(&other.log_prob_zero(&ignored_zero)),
+        );
+
+        kl_one.add(&kl_zero)
+    }
+}
@@ -0,0 +1,131 @@
+use super::special::{digamma, lbeta};
+use super::KlDivergence;
+use crate::backend::Backend;
+use crate::{Data, ElementConversion, Tensor};
+use rand::Rng;
+
+/// Beta distribution parameterized by its two concentrations.
+#[derive(Debug, Clone)]
+pub struct Beta<B: Backend, const D: usize> {
+    pub alpha: Tensor<B, D>,
+    pub beta: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Beta<B, D> {
+    /// Create a new beta distribution.
+    ///
+    /// # Panics
+    ///
+    /// If the concentrations don't have the same shape.
+    pub fn new(alpha: Tensor<B, D>, beta: Tensor<B, D>) -> Self {
+        assert_eq!(
+            alpha.shape(),
+            beta.shape(),
+            "Parameters must have the same shape"
+        );
+        Self { alpha, beta }
+    }
+
+    /// Draw a sample, without gradient.
+    pub fn sample(&self) -> Tensor<B, D> {
+        self.rsample().detach()
+    }
+
+    /// Draw a reparameterized sample, differentiable with respect to the concentrations.
+    ///
+    /// # Notes
+    ///
+    /// The sample is the ratio of two gamma samples, which are drawn with the Marsaglia-Tsang
+    /// method applied to the concentrations plus one. The accepted normal samples are kept
+    /// constant for the gradients, which is accurate since the acceptance rate is close to one
+    /// for the shifted concentrations.
+    pub fn rsample(&self) -> Tensor<B, D> {
+        let gamma_alpha = rsample_gamma(&self.alpha);
+        let gamma_beta = rsample_gamma(&self.beta);
+
+        gamma_alpha.div(&gamma_alpha.add(&gamma_beta))
+    }
+
+    /// Returns the log of the probability density of the given value.
+    pub fn log_prob(&self, value: &Tensor<B, D>) -> Tensor<B, D> {
+        let log_x = value.log().mul(&self.alpha.sub_scalar(1.0));
+        let log_one_minus_x = value
+            .neg()
+            .add_scalar(1.0)
+            .log()
+            .mul(&self.beta.sub_scalar(1.0));
+
+        log_x
+            .add(&log_one_minus_x)
+            .sub(&lbeta(&self.alpha, &self.beta))
+    }
+
+    /// Returns the differential entropy.
+    pub fn entropy(&self) -> Tensor<B, D> {
+        let total = self.alpha.add(&self.beta);
+
+        lbeta(&self.alpha, &self.beta)
+            .sub(&self.alpha.sub_scalar(1.0).mul(&digamma(&self.alpha)))
+            .sub(&self.beta.sub_scalar(1.0).mul(&digamma(&self.beta)))
+            .add(&total.sub_scalar(2.0).mul(&digamma(&total)))
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<Beta<B, D>> for Beta<B, D> {
+    type Output = Tensor<B, D>;
+
+    fn kl_divergence(&self, other: &Beta<B, D>) -> Tensor<B, D> {
+        let total = self.alpha.add(&self.beta);
+        let total_other = other.alpha.add(&other.beta);
+
+        lbeta(&other.alpha, &other.beta)
+            .sub(&lbeta(&self.alpha, &self.beta))
+            .add(&self.alpha.sub(&other.alpha).mul(&digamma(&self.alpha)))
+            .add(&self.beta.sub(&other.beta).mul(&digamma(&self.beta)))
+            .add(&total_other.sub(&total).mul(&digamma(&total)))
+    }
+}
+
+/// Draw a gamma sample for each concentration with the Marsaglia-Tsang method.
+///
+/// The concentrations are shifted by one, so that the method applies for any concentration, and
+/// the samples are scaled back with `u^(1 / alpha)`.
+fn rsample_gamma<B: Backend, const D: usize>(alpha: &Tensor<B, D>) -> Tensor<B, D> {
+    let device = alpha.device();
+    let mut generator = B::fork_rng(device);
+    let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+
+    let concentrations = alpha.to_data();
+    let mut eps = Vec::with_capacity(concentrations.value.len());
+    let mut uniform = Vec::with_capacity(concentrations.value.len());
+
+    for concentration in concentrations.value.iter() {
+        let d = concentration.to_elem::<f64>() + 1.0 - 1.0 / 3.0;
+        let c = 1.0 / f64::sqrt(9.0 * d);
+
+        loop {
+            let x: f64 = generator.sample(normal);
+            let v = f64::powi(1.0 + c * x, 3);
+            let u = 1.0 - generator.gen::<f64>();
+
+            if v > 0.0 && u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+                eps.push(x);
+                break;
+            }
+        }
+        uniform.push(1.0 - generator.gen::<f64>());
+    }
+
+    let shape = *alpha.shape();
+    let eps = Tensor::<B, D>::from_data_device(Data::new(eps, shape).convert(), device);
+    let uniform = Tensor::<B, D>::from_data_device(Data::new(uniform, shape).convert(), device);
+
+    let d = alpha.add_scalar(1.0 - 1.0 / 3.0);
+    let gamma = eps
+        .div(&d.mul_scalar(9.0).powf(0.5))
+        .add_scalar(1.0)
+        .powf(3.0)
+        .mul(&d);
+
+    gamma.mul(&uniform.log().div(alpha).exp())
+}
@@ -0,0 +1,99 @@
+use super::KlDivergence;
+use crate::backend::Backend;
+use crate::{activation, Data, Tensor};
+
+/// Categorical distribution over the indexes of the last dimension.
+#[derive(Debug, Clone)]
+pub struct Categorical<B: Backend, const D: usize> {
+    /// Normalized log probabilities.
+    pub log_probs: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Categorical<B, D> {
+    /// Create a new categorical distribution from probabilities, which are normalized along the
+    /// last dimension.
+    pub fn new(probs: Tensor<B, D>) -> Self {
+        Self::from_logits(probs.log())
+    }
+
+    /// Create a new categorical distribution from unnormalized log probabilities.
+    pub fn from_logits(logits: Tensor<B, D>) -> Self {
+        Self {
+            log_probs: activation::log_softmax(&logits, D - 1),
+        }
+    }
+
+    /// Returns the normalized probabilities.
+    pub fn probs(&self) -> Tensor<B, D> {
+        self.log_probs.exp()
+    }
+
+    /// Draw a sample of indexes, where the last dimension has a size of one.
+    ///
+    /// # Notes
+    ///
+    /// The categorical distribution is discrete, so there is no reparameterized sample.
+    pub fn sample(&self) -> Tensor<B::IntegerBackend, D> {
+        self.probs().multinomial(1, true)
+    }
+
+    /// Returns the log of the probability of the given indexes, where the last dimension has a
+    /// size of one.
+    ///
+    /// # Panics
+    ///
+    /// If the last dimension of the indexes isn't one, or if an index isn't a valid class.
+    pub fn log_prob(&self, value: &Tensor<B::IntegerBackend, D>) -> Tensor<B, D> {
+        let num_classes = self.log_probs.dims()[D - 1];
+        let mut shape = *self.log_probs.shape();
+        shape.dims[D - 1] = 1;
+        assert_eq!(
+            value.dims(),
+            shape.dims,
+            "Categorical indexes should have a shape of {:?}",
+            shape.dims
+        );
+        let indexes = value.to_data();
+
+        let mut one_hot = Data::<f32, D>::zeros(*self.log_probs.shape());
+        for (i, index) in indexes.value.iter().enumerate() {
+            assert!(
+                (0..num_classes as i64).contains(index),
+                "Categorical index {} is out of range for {} classes",
+                index,
+                num_classes
+            );
+            one_hot.value[i * num_classes + *index as usize] = 1.0;
+        }
+        let one_hot = Tensor::<B, D>::from_data_device(one_hot.convert(), self.log_probs.device());
+
+        // Impossible classes have infinite log probabilities, which are masked instead of being
+        // multiplied by zero.
+        self.log_probs
+            .mul(&one_hot)
+            .mask_fill(&one_hot.equal_scalar(0.0), 0.0)
+            .sum_dim(D - 1)
+    }
+
+    /// Returns the entropy.
+    pub fn entropy(&self) -> Tensor<B, D> {
+        self.plogp(&self.log_probs).sum_dim(D - 1).neg()
+    }
+
+    /// Returns `p * log_probs`, where the impossible classes are zeros.
+    fn plogp(&self, log_probs: &Tensor<B, D>) -> Tensor<B, D> {
+        let probs = self.probs();
+        let impossible = probs.equal_scalar(0.0);
+
+        probs.mul(log_probs).mask_fill(&impossible, 0.0)
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<Categorical<B, D>> for Categorical<B, D> {
+    type Output = Tensor<B, D>;
+
+    fn kl_divergence(&self, other: &Categorical<B, D>) -> Tensor<B, D> {
+        let log_ratio = self.log_probs.sub(&other.log_probs);
+        self.plogp(&log_ratio).sum_dim(D - 1)
+    }
+}
@@ -0,0 +1,12 @@
+/// Kullback-Leibler divergence between two distributions.
+pub trait KlDivergence<Rhs> {
+    type Output;
+
+    /// Returns the divergence `KL(self || other)`.
+    fn kl_divergence(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Returns the Kullback-Leibler divergence `KL(p || q)`.
+pub fn kl_divergence<P: KlDivergence<Q>, Q>(p: &P, q: &Q) -> P::Output {
+    p.kl_divergence(q)
+}
@@ -0,0 +1,12 @@
+mod bernoulli;
+mod beta;
+mod categorical;
+mod kl;
+mod normal;
+mod special;
+
+pub use bernoulli::*;
+pub use beta::*;
+pub use categorical::*;
+pub use kl::*;
+pub use normal::*;
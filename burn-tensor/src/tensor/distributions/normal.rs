@@ -0,0 +1,72 @@
+use super::KlDivergence;
+use crate::backend::Backend;
+use crate::{Distribution, Tensor};
+
+/// Normal distribution parameterized by its mean and standard deviation.
+#[derive(Debug, Clone)]
+pub struct Normal<B: Backend, const D: usize> {
+    pub loc: Tensor<B, D>,
+    pub scale: Tensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> Normal<B, D> {
+    /// Create a new normal distribution.
+    ///
+    /// # Panics
+    ///
+    /// If the mean and the standard deviation don't have the same shape.
+    pub fn new(loc: Tensor<B, D>, scale: Tensor<B, D>) -> Self {
+        assert_eq!(
+            loc.shape(),
+            scale.shape(),
+            "Parameters must have the same shape"
+        );
+        Self { loc, scale }
+    }
+
+    /// Draw a sample, without gradient.
+    pub fn sample(&self) -> Tensor<B, D> {
+        self.rsample().detach()
+    }
+
+    /// Draw a reparameterized sample `loc + scale * eps`, differentiable with respect to the
+    /// parameters.
+    pub fn rsample(&self) -> Tensor<B, D> {
+        let eps = self.loc.random_like(Distribution::Normal(0.0, 1.0));
+        self.loc.add(&self.scale.mul(&eps))
+    }
+
+    /// Returns the log of the probability density of the given value.
+    pub fn log_prob(&self, value: &Tensor<B, D>) -> Tensor<B, D> {
+        let var = self.scale.powf(2.0);
+        let error = value.sub(&self.loc).powf(2.0);
+
+        error
+            .div(&var.mul_scalar(2.0))
+            .add(&self.scale.log())
+            .add_scalar(0.5 * f64::ln(2.0 * std::f64::consts::PI))
+            .neg()
+    }
+
+    /// Returns the differential entropy.
+    pub fn entropy(&self) -> Tensor<B, D> {
+        self.scale
+            .log()
+            .add_scalar(0.5 + 0.5 * f64::ln(2.0 * std::f64::consts::PI))
+    }
+}
+
+impl<B: Backend, const D: usize> KlDivergence<Normal<B, D>> for Normal<B, D> {
+    type Output = Tensor<B, D>;
+
+    fn kl_divergence(&self, other: &Normal<B, D>) -> Tensor<B, D> {
+        let var_ratio = self.scale.div(&other.scale).powf(2.0);
+        let error = self.loc.sub(&other.loc).div(&other.scale).powf(2.0);
+
+        var_ratio
+            .add(&error)
+            .sub(&var_ratio.log())
+            .sub_scalar(1.0)
+            .div_scalar(2.0)
+    }
+}
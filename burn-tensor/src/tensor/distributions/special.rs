@@ -0,0 +1,71 @@
+use crate::backend::Backend;
+use crate::{activation, Tensor};
+
+/// Number of recurrence steps applied before using the asymptotic series.
+const SHIFT: usize = 6;
+
+/// Sum of `ln(x + i)` for each recurrence step.
+fn log_shift<B: Backend, const D: usize>(x: &Tensor<B, D>) -> Tensor<B, D> {
+    (1..SHIFT).fold(x.log(), |sum, i| sum.add(&x.add_scalar(i as f64).log()))
+}
+
+/// Logarithm of the gamma function for positive values.
+///
+/// Computed with the Stirling series so that it is differentiable with the other tensor ops.
+pub(crate) fn lgamma<B: Backend, const D: usize>(x: &Tensor<B, D>) -> Tensor<B, D> {
+    let z = x.add_scalar(SHIFT as f64);
+    let z_inv = z.powf(-1.0);
+    let z_inv2 = z_inv.mul(&z_inv);
+
+    let series = z_inv2
+        .mul_scalar(-1.0 / 1260.0)
+        .add_scalar(1.0 / 360.0)
+        .mul(&z_inv2)
+        .neg()
+        .add_scalar(1.0 / 12.0)
+        .mul(&z_inv);
+
+    z.sub_scalar(0.5)
+        .mul(&z.log())
+        .sub(&z)
+        .add_scalar(0.5 * f64::ln(2.0 * std::f64::consts::PI))
+        .add(&series)
+        .sub(&log_shift(x))
+}
+
+/// Logarithm of the beta function for positive values.
+pub(crate) fn lbeta<B: Backend, const D: usize>(
+    a: &Tensor<B, D>,
+    b: &Tensor<B, D>,
+) -> Tensor<B, D> {
+    lgamma(a).add(&lgamma(b)).sub(&lgamma(&a.add(b)))
+}
+
+/// Digamma function, the derivative of [lgamma], for positive values.
+pub(crate) fn digamma<B: Backend, const D: usize>(x: &Tensor<B, D>) -> Tensor<B, D> {
+    let shift = (1..SHIFT).fold(x.powf(-1.0), |sum, i| {
+        sum.add(&x.add_scalar(i as f64).powf(-1.0))
+    });
+
+    let z = x.add_scalar(SHIFT as f64);
+    let z_inv = z.powf(-1.0);
+    let z_inv2 = z_inv.mul(&z_inv);
+
+    let series = z_inv2
+        .mul_scalar(-1.0 / 252.0)
+        .add_scalar(1.0 / 120.0)
+        .mul(&z_inv2)
+        .sub_scalar(1.0 / 12.0)
+        .mul(&z_inv2);
+
+    z.log().sub(&z_inv.mul_scalar(0.5)).add(&series).sub(&shift)
+}
+
+/// Softplus function `ln(1 + exp(x))`, computed as `max(x, 0) + ln(1 + exp(-|x|))` so that it
+/// doesn't overflow for large values.
+pub(crate) fn softplus<B: Backend, const D: usize>(x: &Tensor<B, D>) -> Tensor<B, D> {
+    let positive = activation::relu(x);
+    let abs = positive.mul_scalar(2.0).sub(x);
+
+    positive.add(&abs.neg().exp().add_scalar(1.0).log())
+}
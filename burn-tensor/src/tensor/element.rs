@@ -1,7 +1,7 @@
 use crate::{tensor::ops::*, Distribution};
+use crate::{Complex32, Generator};
 use half::f16;
 use num_traits::ToPrimitive;
-use rand::prelude::StdRng;
 
 pub trait Element:
     Zeros
@@ -28,7 +28,7 @@ pub trait ElementConversion {
 }
 
 pub trait ElementRandom {
-    fn random(distribution: Distribution<Self>, rng: &mut StdRng) -> Self
+    fn random(distribution: Distribution<Self>, rng: &mut Generator) -> Self
     where
         Self: Sized;
 }
@@ -111,7 +111,7 @@ macro_rules! make_element {
         }
 
         impl ElementRandom for $type {
-            fn random(distribution: Distribution<Self>, rng: &mut StdRng) -> Self {
+            fn random(distribution: Distribution<Self>, rng: &mut Generator) -> Self {
                 $random(distribution, rng)
             }
         }
@@ -148,49 +148,88 @@ macro_rules! make_element {
 make_element!(
     float f64 Precision::Double,
     convert |elem: &dyn ToPrimitive| elem.to_f64().unwrap(),
-    random |distribution: Distribution<f64>, rng: &mut StdRng| distribution.sampler(rng).sample()
+    random |distribution: Distribution<f64>, rng: &mut Generator| distribution.sampler(rng).sample()
 );
 
 make_element!(
     float f32 Precision::Full,
     convert |elem: &dyn ToPrimitive| elem.to_f32().unwrap(),
-    random |distribution: Distribution<f32>, rng: &mut StdRng| distribution.sampler(rng).sample()
+    random |distribution: Distribution<f32>, rng: &mut Generator| distribution.sampler(rng).sample()
 );
 
 make_element!(
     int i64 Precision::Double,
     convert |elem: &dyn ToPrimitive| elem.to_i64().unwrap(),
-    random |distribution: Distribution<i64>, rng: &mut StdRng| distribution.sampler(rng).sample()
+    random |distribution: Distribution<i64>, rng: &mut Generator| distribution.sampler(rng).sample()
 );
 make_element!(
     int i32 Precision::Full,
     convert |elem: &dyn ToPrimitive| elem.to_i32().unwrap(),
-    random |distribution: Distribution<i32>, rng: &mut StdRng| distribution.sampler(rng).sample()
+    random |distribution: Distribution<i32>, rng: &mut Generator| distribution.sampler(rng).sample()
 );
 make_element!(
     int i16 Precision::Half,
     convert |elem: &dyn ToPrimitive| elem.to_i16().unwrap(),
-    random |distribution: Distribution<i16>, rng: &mut StdRng| distribution.sampler(rng).sample()
+    random |distribution: Distribution<i16>, rng: &mut Generator| distribution.sampler(rng).sample()
 );
 make_element!(
     int i8 Precision::Other,
     convert |elem: &dyn ToPrimitive| elem.to_i8().unwrap(),
-    random |distribution: Distribution<i8>, rng: &mut StdRng| distribution.sampler(rng).sample()
+    random |distribution: Distribution<i8>, rng: &mut Generator| distribution.sampler(rng).sample()
 );
 
 make_element!(
     int u8 Precision::Other,
     convert |elem: &dyn ToPrimitive| elem.to_u8().unwrap(),
-    random |distribution: Distribution<u8>, rng: &mut StdRng| distribution.sampler(rng).sample()
+    random |distribution: Distribution<u8>, rng: &mut Generator| distribution.sampler(rng).sample()
 );
 make_element!(
     ty f16 Precision::Half,
     zero <f16 as num_traits::Zero>::zero(),
     one <f16 as num_traits::One>::one(),
     convert |elem: &dyn ToPrimitive| f16::from_f32(elem.to_f32().unwrap()),
-    random |distribution: Distribution<f16>, rng: &mut StdRng| {
+    random |distribution: Distribution<f16>, rng: &mut Generator| {
         let distribution: Distribution<f32> = distribution.convert();
         let sample = distribution.sampler(rng).sample();
         f16::from_elem(sample)
     }
 );
+
+// Complex values implement the value traits, but they aren't elements: they aren't ordered, and
+// they can't be converted to real elements without choosing a part, which is done explicitly with
+// their `re` and `im` fields.
+impl Zeros for Complex32 {
+    fn zeros(&self) -> Complex32 {
+        Complex32::new(0.0, 0.0)
+    }
+}
+
+impl Ones for Complex32 {
+    fn ones(&self) -> Complex32 {
+        Complex32::new(1.0, 0.0)
+    }
+}
+
+impl ElementValue for Complex32 {
+    fn inf() -> Self {
+        Complex32::new(f32::INFINITY, 0.0)
+    }
+    fn inf_neg() -> Self {
+        Complex32::new(f32::NEG_INFINITY, 0.0)
+    }
+    fn nan() -> Self {
+        Complex32::new(f32::NAN, f32::NAN)
+    }
+    fn zero() -> Self {
+        Complex32::new(0.0, 0.0)
+    }
+    fn one() -> Self {
+        Complex32::new(1.0, 0.0)
+    }
+}
+
+impl ElementPrecision for Complex32 {
+    fn precision() -> Precision {
+        Precision::Full
+    }
+}
@@ -0,0 +1,129 @@
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+/// A pseudo random number generator used to sample tensors.
+///
+/// Each generator has its own state, so components sampling with their own generator are
+/// reproducible regardless of the other random operations executed concurrently.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut generator = Generator::new(42);
+/// let tensor = Tensor::<B, 2>::random_with([2, 3], Distribution::Standard, &mut generator);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Generator {
+    rng: ChaCha12Rng,
+}
+
+/// The state of a [generator](Generator), which can be saved to resume the same sequence of
+/// random numbers later.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RngState {
+    seed: [u8; 32],
+    stream: u64,
+    word_pos: u128,
+}
+
+impl Generator {
+    /// Create a new generator from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: ChaCha12Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Create a new generator seeded from the operating system.
+    pub fn from_entropy() -> Self {
+        Self {
+            rng: ChaCha12Rng::from_entropy(),
+        }
+    }
+
+    /// Create a new generator seeded from the current one.
+    ///
+    /// The forked generator is independent from the current one, which is advanced so that
+    /// forking again returns a different generator.
+    pub fn fork(&mut self) -> Self {
+        Self {
+            rng: ChaCha12Rng::from_rng(&mut self.rng).unwrap(),
+        }
+    }
+
+    /// Returns the current state of the generator.
+    pub fn state(&self) -> RngState {
+        RngState {
+            seed: self.rng.get_seed(),
+            stream: self.rng.get_stream(),
+            word_pos: self.rng.get_word_pos(),
+        }
+    }
+
+    /// Restore the generator to the given state.
+    pub fn set_state(&mut self, state: &RngState) {
+        *self = Self::from_state(state);
+    }
+
+    /// Create a generator from the given state.
+    pub fn from_state(state: &RngState) -> Self {
+        let mut rng = ChaCha12Rng::from_seed(state.seed);
+        rng.set_stream(state.stream);
+        rng.set_word_pos(state.word_pos);
+
+        Self { rng }
+    }
+}
+
+impl RngCore for Generator {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_reproducible_with_the_same_seed() {
+        let mut generator_1 = Generator::new(42);
+        let mut generator_2 = Generator::new(42);
+
+        assert_eq!(generator_1.next_u64(), generator_2.next_u64());
+    }
+
+    #[test]
+    fn should_resume_from_state() {
+        let mut generator = Generator::new(42);
+        generator.next_u32();
+        let state = generator.state();
+        let expected = generator.next_u64();
+
+        let mut generator = Generator::from_state(&state);
+
+        assert_eq!(generator.next_u64(), expected);
+    }
+
+    #[test]
+    fn should_fork_independent_generators() {
+        let mut generator = Generator::new(42);
+
+        let mut fork_1 = generator.fork();
+        let mut fork_2 = generator.fork();
+
+        assert_ne!(fork_1.next_u64(), fork_2.next_u64());
+    }
+}
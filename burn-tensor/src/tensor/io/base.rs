@@ -0,0 +1,247 @@
+use crate::Element;
+use half::f16;
+use std::any::TypeId;
+
+/// Errors that can occur when reading or writing tensor files.
+#[derive(Debug)]
+pub enum IoError {
+    Io(std::io::Error),
+    InvalidFormat(String),
+    UnsupportedDType(String),
+    InvalidConversion(String),
+}
+
+impl std::fmt::Display for IoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut message = "IO error => ".to_string();
+
+        match self {
+            Self::Io(err) => {
+                message += format!("{}", err).as_str();
+            }
+            Self::InvalidFormat(err) => {
+                message += format!("Invalid format: {}", err).as_str();
+            }
+            Self::UnsupportedDType(err) => {
+                message += format!("Unsupported data type: {}", err).as_str();
+            }
+            Self::InvalidConversion(err) => {
+                message += format!("Invalid conversion: {}", err).as_str();
+            }
+        };
+
+        f.write_str(message.as_str())
+    }
+}
+
+impl std::error::Error for IoError {}
+
+impl From<std::io::Error> for IoError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Data types that can be stored in tensor files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DType {
+    Bool,
+    U8,
+    I8,
+    I16,
+    I32,
+    I64,
+    F16,
+    F32,
+    F64,
+}
+
+impl DType {
+    /// Returns the data type of the given element, if supported.
+    pub fn of<P: 'static>() -> Option<Self> {
+        let id = TypeId::of::<P>();
+
+        [
+            (TypeId::of::<bool>(), Self::Bool),
+            (TypeId::of::<u8>(), Self::U8),
+            (TypeId::of::<i8>(), Self::I8),
+            (TypeId::of::<i16>(), Self::I16),
+            (TypeId::of::<i32>(), Self::I32),
+            (TypeId::of::<i64>(), Self::I64),
+            (TypeId::of::<f16>(), Self::F16),
+            (TypeId::of::<f32>(), Self::F32),
+            (TypeId::of::<f64>(), Self::F64),
+        ]
+        .into_iter()
+        .find(|(type_id, _)| *type_id == id)
+        .map(|(_, dtype)| dtype)
+    }
+
+    /// Returns the number of bytes taken by one element.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Bool | Self::U8 | Self::I8 => 1,
+            Self::I16 | Self::F16 => 2,
+            Self::I32 | Self::F32 => 4,
+            Self::I64 | Self::F64 => 8,
+        }
+    }
+
+    /// If the data type is a floating point type.
+    pub fn is_float(&self) -> bool {
+        matches!(self, Self::F16 | Self::F32 | Self::F64)
+    }
+
+    fn int_range(&self) -> (i64, i64) {
+        match self {
+            Self::Bool => (0, 1),
+            Self::U8 => (u8::MIN as i64, u8::MAX as i64),
+            Self::I8 => (i8::MIN as i64, i8::MAX as i64),
+            Self::I16 => (i16::MIN as i64, i16::MAX as i64),
+            Self::I32 => (i32::MIN as i64, i32::MAX as i64),
+            _ => (i64::MIN, i64::MAX),
+        }
+    }
+}
+
+/// Number of bytes of the elements of the given shape, or `None` when it overflows.
+pub(crate) fn num_bytes(shape: &[usize], dtype: DType) -> Option<usize> {
+    shape
+        .iter()
+        .try_fold(dtype.size(), |size, dim| size.checked_mul(*dim))
+}
+
+/// A single value decoded from a file, before being converted to the requested element type.
+#[derive(Clone, Copy, Debug)]
+enum Scalar {
+    Int(i64),
+    Float(f64),
+}
+
+/// Decode the raw bytes of the given type into elements.
+///
+/// Floating point values can be read into any element type supporting floating points, while
+/// integer values are checked to fit in the requested element type.
+pub(crate) fn decode<P: Element>(
+    bytes: &[u8],
+    dtype: DType,
+    little_endian: bool,
+) -> Result<Vec<P>, IoError> {
+    let target = DType::of::<P>()
+        .ok_or_else(|| IoError::UnsupportedDType(format!("{:?}", std::any::type_name::<P>())))?;
+
+    if dtype.is_float() && !target.is_float() {
+        return Err(IoError::InvalidConversion(format!(
+            "Can't read {:?} values into {:?} elements",
+            dtype, target
+        )));
+    }
+
+    let chunks = bytes.chunks_exact(dtype.size());
+    if !chunks.remainder().is_empty() {
+        return Err(IoError::InvalidFormat(format!(
+            "Expected a multiple of {} bytes, got {}",
+            dtype.size(),
+            bytes.len()
+        )));
+    }
+
+    let (min, max) = target.int_range();
+
+    chunks
+        .map(|chunk| {
+            let mut buffer = [0u8; 8];
+            let buffer = &mut buffer[..chunk.len()];
+            buffer.copy_from_slice(chunk);
+            if !little_endian {
+                buffer.reverse();
+            }
+
+            match decode_scalar(buffer, dtype) {
+                Scalar::Float(value) => Ok(P::from_elem(value)),
+                Scalar::Int(value) if target.is_float() => Ok(P::from_elem(value)),
+                Scalar::Int(value) if value >= min && value <= max => Ok(P::from_elem(value)),
+                Scalar::Int(value) => Err(IoError::InvalidConversion(format!(
+                    "Value {} doesn't fit in {:?}",
+                    value, target
+                ))),
+            }
+        })
+        .collect()
+}
+
+fn decode_scalar(bytes: &[u8], dtype: DType) -> Scalar {
+    match dtype {
+        DType::Bool => Scalar::Int((bytes[0] != 0) as i64),
+        DType::U8 => Scalar::Int(bytes[0] as i64),
+        DType::I8 => Scalar::Int(bytes[0] as i8 as i64),
+        DType::I16 => Scalar::Int(i16::from_le_bytes(bytes.try_into().unwrap()) as i64),
+        DType::I32 => Scalar::Int(i32::from_le_bytes(bytes.try_into().unwrap()) as i64),
+        DType::I64 => Scalar::Int(i64::from_le_bytes(bytes.try_into().unwrap())),
+        DType::F16 => Scalar::Float(f16::from_le_bytes(bytes.try_into().unwrap()).to_f64()),
+        DType::F32 => Scalar::Float(f32::from_le_bytes(bytes.try_into().unwrap()) as f64),
+        DType::F64 => Scalar::Float(f64::from_le_bytes(bytes.try_into().unwrap())),
+    }
+}
+
+/// Encode the elements as little endian bytes, returning the data type used.
+pub(crate) fn encode<P: Element>(values: &[P]) -> Result<(DType, Vec<u8>), IoError> {
+    let dtype = DType::of::<P>()
+        .ok_or_else(|| IoError::UnsupportedDType(format!("{:?}", std::any::type_name::<P>())))?;
+    let mut bytes = Vec::with_capacity(values.len() * dtype.size());
+
+    for value in values {
+        match dtype {
+            DType::Bool | DType::U8 => bytes.push(value.to_elem::<u8>()),
+            DType::I8 => bytes.extend(value.to_elem::<i8>().to_le_bytes()),
+            DType::I16 => bytes.extend(value.to_elem::<i16>().to_le_bytes()),
+            DType::I32 => bytes.extend(value.to_elem::<i32>().to_le_bytes()),
+            DType::I64 => bytes.extend(value.to_elem::<i64>().to_le_bytes()),
+            DType::F16 => bytes.extend(value.to_elem::<f16>().to_le_bytes()),
+            DType::F32 => bytes.extend(value.to_elem::<f32>().to_le_bytes()),
+            DType::F64 => bytes.extend(value.to_elem::<f64>().to_le_bytes()),
+        }
+    }
+
+    Ok((dtype, bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_and_decode_elements() {
+        let values = vec![1.5f32, -2.0, 3.25];
+
+        let (dtype, bytes) = encode(&values).unwrap();
+        let decoded: Vec<f64> = decode(&bytes, dtype, true).unwrap();
+
+        assert_eq!(dtype, DType::F32);
+        assert_eq!(decoded, vec![1.5, -2.0, 3.25]);
+    }
+
+    #[test]
+    fn should_not_read_floats_into_integers() {
+        let (dtype, bytes) = encode(&[1.0f32]).unwrap();
+
+        let result = decode::<i64>(&bytes, dtype, true);
+
+        assert!(matches!(result, Err(IoError::InvalidConversion(_))));
+    }
+
+    #[test]
+    fn should_not_overflow_the_number_of_bytes() {
+        assert_eq!(num_bytes(&[2, 3], DType::F32), Some(24));
+        assert_eq!(num_bytes(&[usize::MAX, 2], DType::U8), None);
+    }
+
+    #[test]
+    fn should_check_integer_range() {
+        let (dtype, bytes) = encode(&[300i64]).unwrap();
+
+        let result = decode::<u8>(&bytes, dtype, true);
+
+        assert!(matches!(result, Err(IoError::InvalidConversion(_))));
+    }
+}
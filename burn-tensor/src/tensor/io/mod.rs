@@ -0,0 +1,9 @@
+mod base;
+mod npy;
+mod npz;
+mod safetensors;
+
+pub use base::{DType, IoError};
+pub use npy::*;
+pub use npz::*;
+pub use safetensors::*;
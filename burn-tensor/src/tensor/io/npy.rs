@@ -0,0 +1,293 @@
+use super::base::{decode, encode, num_bytes, DType, IoError};
+use crate::{DataSerialize, Element};
+use std::path::Path;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Read a NumPy `.npy` file.
+///
+/// # Notes
+///
+/// The file values are converted into the requested element type, see [from_npy_bytes].
+pub fn read_npy<P: Element, F: AsRef<Path>>(file: F) -> Result<DataSerialize<P>, IoError> {
+    from_npy_bytes(&std::fs::read(file)?)
+}
+
+/// Write the data into a NumPy `.npy` file.
+pub fn write_npy<P: Element, F: AsRef<Path>>(
+    file: F,
+    data: &DataSerialize<P>,
+) -> Result<(), IoError> {
+    std::fs::write(file, to_npy_bytes(data)?)?;
+    Ok(())
+}
+
+/// Parse the content of a NumPy `.npy` file.
+///
+/// # Notes
+///
+/// Floating point values can be read into any floating point element type, integer and boolean
+/// values can be read into any element type they fit in. Arrays stored in Fortran order are
+/// converted to the row-major layout used by [Data](crate::Data).
+pub fn from_npy_bytes<P: Element>(bytes: &[u8]) -> Result<DataSerialize<P>, IoError> {
+    if bytes.len() < 10 || &bytes[..6] != MAGIC {
+        return Err(IoError::InvalidFormat(
+            "Missing NumPy magic string".to_string(),
+        ));
+    }
+
+    let (header_len, header_start) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 if bytes.len() >= 12 => (
+            u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+            12,
+        ),
+        version => {
+            return Err(IoError::InvalidFormat(format!(
+                "Unsupported NumPy format version {}",
+                version
+            )))
+        }
+    };
+
+    let truncated = || IoError::InvalidFormat("Truncated header".to_string());
+    let data_start = header_len.checked_add(header_start).ok_or_else(truncated)?;
+    let header = bytes.get(header_start..data_start).ok_or_else(truncated)?;
+    let header = NpyHeader::parse(&String::from_utf8_lossy(header))?;
+
+    let data = num_bytes(&header.shape, header.dtype)
+        .and_then(|size| data_start.checked_add(size))
+        .and_then(|data_end| bytes.get(data_start..data_end))
+        .ok_or_else(|| {
+            IoError::InvalidFormat(format!("Expected data of shape {:?}", header.shape))
+        })?;
+
+    let mut value = decode(data, header.dtype, header.little_endian)?;
+    if header.fortran_order {
+        value = fortran_to_c_order(value, &header.shape);
+    }
+
+    Ok(DataSerialize {
+        value,
+        shape: header.shape,
+    })
+}
+
+/// Serialize the data with the NumPy `.npy` format.
+pub fn to_npy_bytes<P: Element>(data: &DataSerialize<P>) -> Result<Vec<u8>, IoError> {
+    let (dtype, values) = encode(&data.value)?;
+
+    let shape = match data.shape.len() {
+        1 => format!("({},)", data.shape[0]),
+        _ => format!(
+            "({})",
+            data.shape
+                .iter()
+                .map(|dim| dim.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr(dtype),
+        shape
+    );
+
+    // The header is padded with spaces so that the data is aligned on 64 bytes.
+    let unpadded_len = MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat((64 - unpadded_len % 64) % 64));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header.len() + values.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(&values);
+
+    Ok(bytes)
+}
+
+struct NpyHeader {
+    dtype: DType,
+    little_endian: bool,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+impl NpyHeader {
+    fn parse(header: &str) -> Result<Self, IoError> {
+        let invalid = || IoError::InvalidFormat(format!("Invalid header {}", header.trim()));
+
+        let descr = header_value(header, "descr").ok_or_else(invalid)?;
+        let quote = descr.chars().next().ok_or_else(invalid)?;
+        let descr = descr[1..].split(quote).next().ok_or_else(invalid)?;
+        let (little_endian, dtype) = parse_descr(descr)?;
+
+        let fortran_order = match header_value(header, "fortran_order") {
+            Some(value) => value.starts_with("True"),
+            None => return Err(invalid()),
+        };
+
+        let shape = header_value(header, "shape").ok_or_else(invalid)?;
+        let shape = shape
+            .trim_start_matches('(')
+            .split(')')
+            .next()
+            .ok_or_else(invalid)?
+            .split(',')
+            .map(str::trim)
+            .filter(|dim| !dim.is_empty())
+            .map(|dim| dim.parse::<usize>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            dtype,
+            little_endian,
+            fortran_order,
+            shape,
+        })
+    }
+}
+
+/// Returns the text following the given key in the header dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    ["'", "\""].iter().find_map(|quote| {
+        let pattern = format!("{quote}{key}{quote}");
+        let start = header.find(&pattern)? + pattern.len();
+        let value = header[start..].trim_start().strip_prefix(':')?;
+
+        Some(value.trim_start())
+    })
+}
+
+fn parse_descr(descr: &str) -> Result<(bool, DType), IoError> {
+    let (endianness, kind) = descr.split_at(1);
+    let little_endian = match endianness {
+        "<" | "|" => true,
+        "=" => cfg!(target_endian = "little"),
+        ">" => false,
+        _ => return Err(IoError::UnsupportedDType(descr.to_string())),
+    };
+
+    let dtype = match kind {
+        "b1" => DType::Bool,
+        "u1" => DType::U8,
+        "i1" => DType::I8,
+        "i2" => DType::I16,
+        "i4" => DType::I32,
+        "i8" => DType::I64,
+        "f2" => DType::F16,
+        "f4" => DType::F32,
+        "f8" => DType::F64,
+        _ => return Err(IoError::UnsupportedDType(descr.to_string())),
+    };
+
+    Ok((little_endian, dtype))
+}
+
+fn descr(dtype: DType) -> &'static str {
+    match dtype {
+        DType::Bool => "|b1",
+        DType::U8 => "|u1",
+        DType::I8 => "|i1",
+        DType::I16 => "<i2",
+        DType::I32 => "<i4",
+        DType::I64 => "<i8",
+        DType::F16 => "<f2",
+        DType::F32 => "<f4",
+        DType::F64 => "<f8",
+    }
+}
+
+fn fortran_to_c_order<P: Copy>(values: Vec<P>, shape: &[usize]) -> Vec<P> {
+    let mut output = Vec::with_capacity(values.len());
+    let mut index = vec![0; shape.len()];
+
+    for _ in 0..values.len() {
+        let mut offset = 0;
+        let mut stride = 1;
+        for (i, dim) in shape.iter().enumerate() {
+            offset += index[i] * stride;
+            stride *= dim;
+        }
+        output.push(values[offset]);
+
+        // Increment the multi-dimensional index in row-major order.
+        for i in (0..shape.len()).rev() {
+            index[i] += 1;
+            if index[i] < shape[i] {
+                break;
+            }
+            index[i] = 0;
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    #[test]
+    fn should_read_numpy_file() {
+        let data = read_npy::<f32, _>(fixture("f64_2x3.npy")).unwrap();
+
+        assert_eq!(data.shape, vec![2, 3]);
+        assert_eq!(data.value, vec![0.0, 1.5, 2.0, 3.0, 4.0, -5.5]);
+    }
+
+    #[test]
+    fn should_read_fortran_order() {
+        let data = read_npy::<i64, _>(fixture("i32_2x3_fortran.npy")).unwrap();
+
+        assert_eq!(data.shape, vec![2, 3]);
+        assert_eq!(data.value, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn should_write_the_same_bytes_as_numpy() {
+        let data = DataSerialize {
+            value: vec![0.0f64, 1.5, 2.0, 3.0, 4.0, -5.5],
+            shape: vec![2, 3],
+        };
+
+        let bytes = to_npy_bytes(&data).unwrap();
+
+        assert_eq!(bytes, std::fs::read(fixture("f64_2x3.npy")).unwrap());
+    }
+
+    #[test]
+    fn should_not_overflow_with_invalid_shapes() {
+        let header = format!(
+            "{{'descr': '<f4', 'fortran_order': False, 'shape': ({}, 2), }}\n",
+            usize::MAX
+        );
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+
+        let result = from_npy_bytes::<f32>(&bytes);
+
+        assert!(matches!(result, Err(IoError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn should_support_round_trip() {
+        let data = DataSerialize {
+            value: vec![1i32, -2, 3],
+            shape: vec![3],
+        };
+
+        let data_actual = from_npy_bytes::<i32>(&to_npy_bytes(&data).unwrap()).unwrap();
+
+        assert_eq!(data, data_actual);
+    }
+}
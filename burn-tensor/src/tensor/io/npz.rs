@@ -0,0 +1,123 @@
+use super::base::IoError;
+use super::npy::{from_npy_bytes, to_npy_bytes};
+use crate::{DataSerialize, Element};
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek, Write};
+use std::path::Path;
+
+impl From<zip::result::ZipError> for IoError {
+    fn from(err: zip::result::ZipError) -> Self {
+        match err {
+            zip::result::ZipError::Io(err) => Self::Io(err),
+            err => Self::InvalidFormat(format!("{}", err)),
+        }
+    }
+}
+
+/// Read all arrays of a NumPy `.npz` archive, indexed by their names.
+///
+/// Both compressed and uncompressed archives are supported.
+pub fn read_npz<P: Element, F: AsRef<Path>>(
+    file: F,
+) -> Result<HashMap<String, DataSerialize<P>>, IoError> {
+    read_npz_from(std::fs::File::open(file)?)
+}
+
+/// Write the arrays into an uncompressed NumPy `.npz` archive, like `numpy.savez`.
+pub fn write_npz<P: Element, F: AsRef<Path>>(
+    file: F,
+    arrays: &HashMap<String, DataSerialize<P>>,
+) -> Result<(), IoError> {
+    std::fs::write(file, to_npz_bytes(arrays)?)?;
+    Ok(())
+}
+
+/// Parse the content of a NumPy `.npz` archive.
+pub fn from_npz_bytes<P: Element>(
+    bytes: &[u8],
+) -> Result<HashMap<String, DataSerialize<P>>, IoError> {
+    read_npz_from(Cursor::new(bytes))
+}
+
+/// Serialize the arrays with the NumPy `.npz` format.
+pub fn to_npz_bytes<P: Element>(
+    arrays: &HashMap<String, DataSerialize<P>>,
+) -> Result<Vec<u8>, IoError> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options =
+        zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+    // Sorted to keep the archive content deterministic.
+    let mut names: Vec<_> = arrays.keys().collect();
+    names.sort();
+
+    for name in names {
+        writer.start_file(format!("{}.npy", name), options)?;
+        writer.write_all(&to_npy_bytes(&arrays[name])?)?;
+    }
+
+    Ok(writer.finish()?.into_inner())
+}
+
+fn read_npz_from<P: Element, R: Read + Seek>(
+    reader: R,
+) -> Result<HashMap<String, DataSerialize<P>>, IoError> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let mut arrays = HashMap::with_capacity(archive.len());
+
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        let name = file.name().trim_end_matches(".npy").to_string();
+
+        // The size of an entry comes from the archive, so it isn't trusted to reserve memory.
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+
+        arrays.insert(name, from_npy_bytes(&bytes)?);
+    }
+
+    Ok(arrays)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    #[test]
+    fn should_read_compressed_archive() {
+        let arrays = read_npz::<f32, _>(fixture("arrays.npz")).unwrap();
+
+        assert_eq!(arrays.len(), 2);
+        assert_eq!(arrays["weight"].shape, vec![2, 2]);
+        assert_eq!(arrays["weight"].value, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(arrays["bias"].shape, vec![2]);
+        assert_eq!(arrays["bias"].value, vec![0.5, -0.5]);
+    }
+
+    #[test]
+    fn should_support_round_trip() {
+        let mut arrays = HashMap::new();
+        arrays.insert(
+            "weight".to_string(),
+            DataSerialize {
+                value: vec![1.0f32, 2.0, 3.0, 4.0],
+                shape: vec![2, 2],
+            },
+        );
+        arrays.insert(
+            "bias".to_string(),
+            DataSerialize {
+                value: vec![0.5f32, -0.5],
+                shape: vec![2],
+            },
+        );
+
+        let arrays_actual = from_npz_bytes::<f32>(&to_npz_bytes(&arrays).unwrap()).unwrap();
+
+        assert_eq!(arrays, arrays_actual);
+    }
+}
@@ -0,0 +1,203 @@
+use super::base::{decode, encode, num_bytes, DType, IoError};
+use crate::{DataSerialize, Element};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+const METADATA_KEY: &str = "__metadata__";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TensorInfo {
+    dtype: String,
+    shape: Vec<usize>,
+    data_offsets: [usize; 2],
+}
+
+/// Read all tensors of a [safetensors](https://github.com/huggingface/safetensors) file, indexed
+/// by their names.
+///
+/// # Notes
+///
+/// The file values are converted into the requested element type with the same rules as
+/// [from_npy_bytes](super::from_npy_bytes). The `__metadata__` entry is ignored.
+pub fn read_safetensors<P: Element, F: AsRef<Path>>(
+    file: F,
+) -> Result<HashMap<String, DataSerialize<P>>, IoError> {
+    from_safetensors_bytes(&std::fs::read(file)?)
+}
+
+/// Write the tensors into a [safetensors](https://github.com/huggingface/safetensors) file.
+pub fn write_safetensors<P: Element, F: AsRef<Path>>(
+    file: F,
+    tensors: &HashMap<String, DataSerialize<P>>,
+) -> Result<(), IoError> {
+    std::fs::write(file, to_safetensors_bytes(tensors)?)?;
+    Ok(())
+}
+
+/// Parse the content of a safetensors file.
+pub fn from_safetensors_bytes<P: Element>(
+    bytes: &[u8],
+) -> Result<HashMap<String, DataSerialize<P>>, IoError> {
+    let header_len = bytes
+        .get(..8)
+        .map(|len| u64::from_le_bytes(len.try_into().unwrap()))
+        .ok_or_else(|| IoError::InvalidFormat("Missing header size".to_string()))?;
+    let data_start = usize::try_from(header_len)
+        .ok()
+        .and_then(|len| len.checked_add(8))
+        .filter(|data_start| *data_start <= bytes.len())
+        .ok_or_else(|| IoError::InvalidFormat("Truncated header".to_string()))?;
+    let (header, data) = bytes[8..].split_at(data_start - 8);
+
+    let header: BTreeMap<String, serde_json::Value> = serde_json::from_slice(header)
+        .map_err(|err| IoError::InvalidFormat(format!("{:?}", err)))?;
+
+    let mut tensors = HashMap::with_capacity(header.len());
+
+    for (name, info) in header {
+        if name == METADATA_KEY {
+            continue;
+        }
+
+        let info: TensorInfo = serde_json::from_value(info)
+            .map_err(|err| IoError::InvalidFormat(format!("{:?}", err)))?;
+        let [start, end] = info.data_offsets;
+        let bytes = data.get(start..end).ok_or_else(|| {
+            IoError::InvalidFormat(format!("Invalid data offsets for tensor {}", name))
+        })?;
+
+        let dtype = parse_dtype(&info.dtype)?;
+        if num_bytes(&info.shape, dtype) != Some(bytes.len()) {
+            return Err(IoError::InvalidFormat(format!(
+                "Tensor {} of shape {:?} has {} bytes",
+                name,
+                info.shape,
+                bytes.len()
+            )));
+        }
+
+        let value = decode(bytes, dtype, true)?;
+        tensors.insert(
+            name,
+            DataSerialize {
+                value,
+                shape: info.shape,
+            },
+        );
+    }
+
+    Ok(tensors)
+}
+
+/// Serialize the tensors with the safetensors format.
+pub fn to_safetensors_bytes<P: Element>(
+    tensors: &HashMap<String, DataSerialize<P>>,
+) -> Result<Vec<u8>, IoError> {
+    // Sorted to keep the file content deterministic.
+    let tensors: BTreeMap<_, _> = tensors.iter().collect();
+
+    let mut header = BTreeMap::new();
+    let mut data = Vec::new();
+
+    for (name, tensor) in tensors {
+        let (dtype, bytes) = encode(&tensor.value)?;
+        let start = data.len();
+        data.extend(bytes);
+
+        let info = TensorInfo {
+            dtype: format!("{:?}", dtype).to_uppercase(),
+            shape: tensor.shape.clone(),
+            data_offsets: [start, data.len()],
+        };
+        header.insert(name.as_str(), info);
+    }
+
+    let mut header = serde_json::to_string(&header)
+        .map_err(|err| IoError::InvalidFormat(format!("{:?}", err)))?;
+
+    // The header is padded with spaces so that the data is aligned on 8 bytes.
+    header.push_str(&" ".repeat((8 - header.len() % 8) % 8));
+
+    let mut bytes = Vec::with_capacity(8 + header.len() + data.len());
+    bytes.extend((header.len() as u64).to_le_bytes());
+    bytes.extend(header.as_bytes());
+    bytes.extend(data);
+
+    Ok(bytes)
+}
+
+fn parse_dtype(dtype: &str) -> Result<DType, IoError> {
+    match dtype {
+        "BOOL" => Ok(DType::Bool),
+        "U8" => Ok(DType::U8),
+        "I8" => Ok(DType::I8),
+        "I16" => Ok(DType::I16),
+        "I32" => Ok(DType::I32),
+        "I64" => Ok(DType::I64),
+        "F16" => Ok(DType::F16),
+        "F32" => Ok(DType::F32),
+        "F64" => Ok(DType::F64),
+        _ => Err(IoError::UnsupportedDType(dtype.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    #[test]
+    fn should_read_safetensors_file() {
+        let tensors = read_safetensors::<f32, _>(fixture("tensors.safetensors")).unwrap();
+
+        assert_eq!(tensors.len(), 2);
+        assert_eq!(tensors["weight"].shape, vec![2, 2]);
+        assert_eq!(tensors["weight"].value, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(tensors["bias"].shape, vec![2]);
+        assert_eq!(tensors["bias"].value, vec![0.5, -0.5]);
+    }
+
+    #[test]
+    fn should_not_overflow_with_invalid_header_size() {
+        let mut bytes = u64::MAX.to_le_bytes().to_vec();
+        bytes.extend_from_slice(b"{}");
+
+        let result = from_safetensors_bytes::<f32>(&bytes);
+
+        assert!(matches!(result, Err(IoError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn should_not_overflow_with_invalid_shapes() {
+        let header = format!(
+            "{{\"weight\":{{\"dtype\":\"F32\",\"shape\":[{},2],\"data_offsets\":[0,0]}}}}",
+            usize::MAX
+        );
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(header.as_bytes());
+
+        let result = from_safetensors_bytes::<f32>(&bytes);
+
+        assert!(matches!(result, Err(IoError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn should_support_round_trip() {
+        let mut tensors = HashMap::new();
+        tensors.insert(
+            "embedding".to_string(),
+            DataSerialize {
+                value: vec![1i64, 2, 3, 4, 5, 6],
+                shape: vec![3, 2],
+            },
+        );
+
+        let bytes = to_safetensors_bytes(&tensors).unwrap();
+        let tensors_actual = from_safetensors_bytes::<i64>(&bytes).unwrap();
+
+        assert_eq!(tensors, tensors_actual);
+    }
+}
@@ -0,0 +1,74 @@
+/// Create a tensor from a list of elements, where the rank and the shape are inferred from the
+/// nesting of the brackets.
+///
+/// The elements are converted into the element type of the backend and the tensor is created on
+/// the default device, unless a device is given after a semicolon.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let tensor: Tensor<B, 2> = tensor![[1.0, 2.0], [3.0, 4.0]];
+/// let tensor: Tensor<B, 2> = tensor![[1.0, 2.0], [3.0, 4.0]; device];
+/// let tensor: Tensor<B, 1> = tensor![1, 2, 3; device];
+/// ```
+///
+/// # Notes
+///
+/// Tensors of up to 4 dimensions are supported.
+#[macro_export]
+macro_rules! tensor {
+    ([$($first:tt)*] $(, [$($rest:tt)*])* $(,)? ; $device:expr) => {
+        $crate::Tensor::from_data_device(
+            $crate::Data::<_, { $crate::__tensor_rank!([[$($first)*]]) }>::from([
+                [$($first)*] $(, [$($rest)*])*
+            ])
+            .convert(),
+            $device,
+        )
+    };
+    ($($elems:expr),+ $(,)? ; $device:expr) => {
+        $crate::Tensor::from_data_device(
+            $crate::Data::<_, 1>::from([$($elems),+]).convert(),
+            $device,
+        )
+    };
+    ($($elems:tt)*) => {
+        $crate::Tensor::from_data(
+            $crate::Data::<_, { $crate::__tensor_rank!([$($elems)*]) }>::from([$($elems)*])
+                .convert(),
+        )
+    };
+}
+
+/// Returns the number of nested brackets of the first element, which is the rank of the tensor.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tensor_rank {
+    ([[$($inner:tt)*] $($rest:tt)*]) => {
+        1 + $crate::__tensor_rank!([$($inner)*])
+    };
+    ([$($elems:tt)*]) => {
+        1
+    };
+}
+
+/// Assert that two tensors are close, see [all_close](crate::Tensor::all_close).
+///
+/// The default tolerances are `rtol = 1e-5` and `atol = 1e-8`, they can be overridden with
+/// `assert_tensor_close!(actual, expected, rtol = 1e-3, atol = 1e-6)`.
+///
+/// # Panics
+///
+/// If the shapes are different or if any element isn't close, with a message reporting the
+/// first mismatching index and the maximum absolute and relative errors.
+#[macro_export]
+macro_rules! assert_tensor_close {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::assert_tensor_close!($actual, $expected, rtol = 1e-5, atol = 1e-8)
+    };
+    ($actual:expr, $expected:expr, rtol = $rtol:expr, atol = $atol:expr $(,)?) => {
+        $actual
+            .to_data()
+            .assert_close(&$expected.to_data(), $rtol, $atol)
+    };
+}
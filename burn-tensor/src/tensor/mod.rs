@@ -3,18 +3,31 @@ pub(crate) mod stats;
 
 mod base;
 mod bool_tensor;
+mod complex;
 mod data;
 mod element;
+mod generator;
+mod macros;
+mod print;
+mod quantization;
 mod shape;
+mod sparse;
 
 pub use base::*;
 pub use bool_tensor::*;
+pub use complex::*;
 pub use data::*;
 pub use element::*;
+pub use generator::*;
+pub use print::{print_options, set_print_options, PrintOptions};
+pub use quantization::*;
 pub use shape::*;
+pub use sparse::SparseTensor;
 
 pub mod activation;
 pub mod backend;
+pub mod distributions;
+pub mod io;
 pub mod loss;
 pub mod module;
 
@@ -1,4 +1,5 @@
 use crate::{backend::Backend, Tensor};
+use std::sync::Arc;
 
 pub fn embedding<B>(weights: &Tensor<B, 2>, indexes: &Tensor<B::IntegerBackend, 2>) -> Tensor<B, 3>
 where
@@ -6,3 +7,28 @@ where
 {
     Tensor::new(B::embedding(&weights.value, &indexes.value))
 }
+
+/// Applies the function on the input tensor without keeping its intermediate tensors for the
+/// backward pass.
+///
+/// With an autodiff backend, only the input is kept and the function is recomputed during the
+/// backward pass, with the same random state, to compute its gradients.
+///
+/// # Notes
+///
+/// The function is stored in the graph of the output, which can outlive the scope calling
+/// `checkpoint` since tensors own their graph, so the function can't borrow from that scope and
+/// must be `'static`. Modules used by the function are moved into it instead, which is cheap
+/// since cloning a module shares the data of its tensors.
+pub fn checkpoint<B, F, const D1: usize, const D2: usize>(
+    function: F,
+    input: &Tensor<B, D1>,
+) -> Tensor<B, D2>
+where
+    B: Backend,
+    F: Fn(Tensor<B, D1>) -> Tensor<B, D2> + Send + Sync + 'static,
+{
+    let function = move |tensor| function(Tensor::new(tensor)).value;
+
+    Tensor::new(B::checkpoint(Arc::new(function), &input.value))
+}
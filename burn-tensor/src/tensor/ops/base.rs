@@ -1,5 +1,18 @@
-use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion};
+use super::{fft_packed, rfft_packed};
+use crate::tensor::quantization::{QuantizationScheme, QuantizedData};
+use crate::tensor::sparse::coo_to_data;
+use crate::{
+    backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, ElementValue, Generator,
+};
 use std::ops::Range;
+use std::sync::Arc;
+
+/// Function applied on a segment of a computation graph, see [checkpoint](ModuleOps::checkpoint).
+pub type CheckpointFunction<B, const D1: usize, const D2: usize> = Arc<
+    dyn Fn(<B as Backend>::TensorPrimitive<D1>) -> <B as Backend>::TensorPrimitive<D2>
+        + Send
+        + Sync,
+>;
 
 pub trait ModuleOps<B: Backend> {
     fn embedding(
@@ -11,6 +24,16 @@ pub trait ModuleOps<B: Backend> {
         output: &B::TensorPrimitive<3>,
         indexes: &<B::IntegerBackend as Backend>::TensorPrimitive<2>,
     ) -> B::TensorPrimitive<2>;
+    /// Applies the function on the input tensor.
+    ///
+    /// Backends recording the forward pass can store only the input of the function and
+    /// recompute it when its output is needed, trading computation for memory.
+    fn checkpoint<const D1: usize, const D2: usize>(
+        function: CheckpointFunction<B, D1, D2>,
+        input: &B::TensorPrimitive<D1>,
+    ) -> B::TensorPrimitive<D2> {
+        function(input.clone())
+    }
 }
 
 pub trait TensorOps<B: Backend> {
@@ -27,6 +50,14 @@ pub trait TensorOps<B: Backend> {
         distribution: Distribution<B::Elem>,
         device: B::Device,
     ) -> B::TensorPrimitive<D>;
+    fn random_with<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        generator: &mut Generator,
+        device: B::Device,
+    ) -> B::TensorPrimitive<D> {
+        Self::from_data(Data::random(shape, distribution, generator), device)
+    }
     fn zeros<const D: usize>(shape: Shape<D>, device: B::Device) -> B::TensorPrimitive<D> {
         Self::from_data(Data::zeros(shape), device)
     }
@@ -36,9 +67,15 @@ pub trait TensorOps<B: Backend> {
     fn shape<const D: usize>(tensor: &B::TensorPrimitive<D>) -> &Shape<D>;
     fn to_data<const D: usize>(tensor: &B::TensorPrimitive<D>) -> Data<B::Elem, D>;
     fn into_data<const D: usize>(tensor: B::TensorPrimitive<D>) -> Data<B::Elem, D>;
+    /// Returns the elements of the tensor in row-major order without copying them, when they are
+    /// stored contiguously in memory.
+    fn as_slice<const D: usize>(_tensor: &B::TensorPrimitive<D>) -> Option<&[B::Elem]> {
+        None
+    }
     fn bool_shape<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> &Shape<D>;
     fn bool_to_data<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> Data<bool, D>;
     fn bool_into_data<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> Data<bool, D>;
+    fn bool_device<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> B::Device;
     fn bool_to_device<const D: usize>(
         tensor: &B::BoolTensorPrimitive<D>,
         device: B::Device,
@@ -129,6 +166,67 @@ pub trait TensorOps<B: Backend> {
         lhs: &B::TensorPrimitive<D>,
         rhs: &B::TensorPrimitive<D>,
     ) -> B::TensorPrimitive<D>;
+    fn spmm(
+        indices: &<B::IntegerBackend as Backend>::TensorPrimitive<2>,
+        values: &B::TensorPrimitive<1>,
+        shape: Shape<2>,
+        dense: &B::TensorPrimitive<2>,
+    ) -> B::TensorPrimitive<2> {
+        let indices = B::IntegerBackend::to_data(indices);
+        let sparse = coo_to_data(&indices, &Self::to_data(values), shape);
+        let sparse = Self::from_data(sparse, Self::device(dense));
+
+        Self::matmul(&sparse, dense)
+    }
+    fn quantize<const D: usize>(
+        tensor: &B::TensorPrimitive<D>,
+        scheme: QuantizationScheme,
+    ) -> QuantizedData<D> {
+        QuantizedData::quantize(&Self::to_data(tensor), scheme)
+    }
+    fn dequantize<const D: usize>(
+        data: &QuantizedData<D>,
+        device: B::Device,
+    ) -> B::TensorPrimitive<D> {
+        Self::from_data(data.dequantize(), device)
+    }
+    fn quantized_matmul(
+        lhs: &B::TensorPrimitive<2>,
+        rhs: &QuantizedData<2>,
+    ) -> B::TensorPrimitive<2> {
+        let rhs = Self::dequantize(rhs, Self::device(lhs));
+        Self::matmul(lhs, &rhs)
+    }
+    /// Complex values are packed with the real parts followed by the imaginary parts along the
+    /// last dimension.
+    fn fft<const D: usize>(tensor: &B::TensorPrimitive<D>, inverse: bool) -> B::TensorPrimitive<D> {
+        let data = Self::to_data(tensor);
+        let size = data.shape.dims[D - 1];
+        let mut output = vec![B::Elem::zero(); data.value.len()];
+
+        for (input, output) in data.value.chunks(size).zip(output.chunks_mut(size)) {
+            fft_packed(input, output, inverse);
+        }
+
+        Self::from_data(Data::new(output, data.shape), Self::device(tensor))
+    }
+    fn rfft<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let data = Self::to_data(tensor);
+        let size = data.shape.dims[D - 1];
+        let mut shape = data.shape;
+        shape.dims[D - 1] = 2 * (size / 2 + 1);
+        let mut output = vec![B::Elem::zero(); shape.num_elements()];
+
+        for (input, output) in data
+            .value
+            .chunks(size)
+            .zip(output.chunks_mut(shape.dims[D - 1]))
+        {
+            rfft_packed(input, output);
+        }
+
+        Self::from_data(Data::new(output, shape), Self::device(tensor))
+    }
     fn neg<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn transpose<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
         Self::swap_dims(tensor, D - 2, D - 1)
@@ -197,6 +295,15 @@ pub trait TensorOps<B: Backend> {
         rhs: &B::Elem,
     ) -> B::BoolTensorPrimitive<D>;
     fn detach<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn require_grad<const D: usize>(
+        tensor: &B::TensorPrimitive<D>,
+        _require_grad: bool,
+    ) -> B::TensorPrimitive<D> {
+        tensor.clone()
+    }
+    fn is_require_grad<const D: usize>(_tensor: &B::TensorPrimitive<D>) -> bool {
+        false
+    }
     fn mean<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<1>;
     fn sum<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<1>;
     fn mean_dim<const D: usize>(
@@ -219,12 +326,94 @@ pub trait TensorOps<B: Backend> {
         tensor: &B::TensorPrimitive<D>,
         dim: usize,
     ) -> <B::IntegerBackend as Backend>::TensorPrimitive<D>;
+    fn multinomial<const D: usize>(
+        tensor: &B::TensorPrimitive<D>,
+        num_samples: usize,
+        replacement: bool,
+        generator: &mut Generator,
+    ) -> <B::IntegerBackend as Backend>::TensorPrimitive<D> {
+        let data = Self::to_data(tensor).multinomial(num_samples, replacement, generator);
+        B::IntegerBackend::from_data(data, Self::device(tensor))
+    }
     fn exp<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn log<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn powf<const D: usize>(tensor: &B::TensorPrimitive<D>, value: f32) -> B::TensorPrimitive<D>;
     fn erf<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn cat<const D: usize>(tensors: &[B::TensorPrimitive<D>], dim: usize) -> B::TensorPrimitive<D>;
     fn relu<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    /// Same as [add](TensorOps::add), but takes ownership of the tensors.
+    ///
+    /// The by-value operations let backends reuse the memory of inputs that aren't used anywhere
+    /// else, their default implementations simply call the borrowing operations.
+    fn add_owned<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        Self::add(&lhs, &rhs)
+    }
+    fn add_scalar_owned<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: &B::Elem,
+    ) -> B::TensorPrimitive<D> {
+        Self::add_scalar(&lhs, rhs)
+    }
+    fn sub_owned<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        Self::sub(&lhs, &rhs)
+    }
+    fn sub_scalar_owned<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: &B::Elem,
+    ) -> B::TensorPrimitive<D> {
+        Self::sub_scalar(&lhs, rhs)
+    }
+    fn mul_owned<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        Self::mul(&lhs, &rhs)
+    }
+    fn mul_scalar_owned<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: &B::Elem,
+    ) -> B::TensorPrimitive<D> {
+        Self::mul_scalar(&lhs, rhs)
+    }
+    fn div_owned<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        Self::div(&lhs, &rhs)
+    }
+    fn div_scalar_owned<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: &B::Elem,
+    ) -> B::TensorPrimitive<D> {
+        Self::div_scalar(&lhs, rhs)
+    }
+    fn neg_owned<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        Self::neg(&tensor)
+    }
+    fn exp_owned<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        Self::exp(&tensor)
+    }
+    fn log_owned<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        Self::log(&tensor)
+    }
+    fn erf_owned<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        Self::erf(&tensor)
+    }
+    fn relu_owned<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        Self::relu(&tensor)
+    }
+    fn powf_owned<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        value: f32,
+    ) -> B::TensorPrimitive<D> {
+        Self::powf(&tensor, value)
+    }
 }
 
 pub trait Zeros {
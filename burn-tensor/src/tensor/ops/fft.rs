@@ -0,0 +1,158 @@
+use crate::{Complex32, Element, ElementConversion};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// Computes in place the discrete Fourier transform of the values.
+///
+/// The inverse transform is normalized by `1 / n`. Lengths that are powers of two use the
+/// radix-2 Cooley-Tukey algorithm, other lengths fall back to a direct `O(n²)` evaluation.
+pub fn fft_slice(values: &mut [Complex32], inverse: bool) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+
+    let sign = match inverse {
+        true => 1.0,
+        false => -1.0,
+    };
+
+    match n.is_power_of_two() {
+        true => fft_radix2(values, sign),
+        false => dft(values, sign),
+    }
+
+    if inverse {
+        let scale = 1.0 / n as f32;
+        values.iter_mut().for_each(|value| *value *= scale);
+    }
+}
+
+/// Applies [fft_slice] on packed complex values, where the real parts are followed by the
+/// imaginary parts.
+pub fn fft_packed<E: Element>(input: &[E], output: &mut [E], inverse: bool) {
+    let n = input.len() / 2;
+    let mut values: Vec<_> = (0..n)
+        .map(|i| Complex32::new(input[i].to_elem(), input[n + i].to_elem()))
+        .collect();
+
+    fft_slice(&mut values, inverse);
+    write_packed(&values, output);
+}
+
+/// Computes the transform of real values, keeping only the `n / 2 + 1` non-redundant frequencies
+/// packed in the output.
+pub fn rfft_packed<E: Element>(input: &[E], output: &mut [E]) {
+    let mut values: Vec<_> = input
+        .iter()
+        .map(|value| Complex32::new(value.to_elem(), 0.0))
+        .collect();
+
+    fft_slice(&mut values, false);
+    write_packed(&values[..input.len() / 2 + 1], output);
+}
+
+fn write_packed<E: Element>(values: &[Complex32], output: &mut [E]) {
+    let (re, im) = output.split_at_mut(values.len());
+
+    for (i, value) in values.iter().enumerate() {
+        re[i] = value.re.to_elem();
+        im[i] = value.im.to_elem();
+    }
+}
+
+fn fft_radix2(values: &mut [Complex32], sign: f64) {
+    let n = values.len();
+    let bits = n.trailing_zeros();
+
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2.0 * PI / len as f64;
+
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let twiddle = Complex64::from_polar(1.0, angle * k as f64);
+                let twiddle = Complex32::new(twiddle.re as f32, twiddle.im as f32);
+                let even = values[start + k];
+                let odd = values[start + k + len / 2] * twiddle;
+
+                values[start + k] = even + odd;
+                values[start + k + len / 2] = even - odd;
+            }
+        }
+
+        len *= 2;
+    }
+}
+
+fn dft(values: &mut [Complex32], sign: f64) {
+    let n = values.len();
+    let input = values.to_vec();
+
+    for (k, value) in values.iter_mut().enumerate() {
+        let mut sum = Complex64::new(0.0, 0.0);
+
+        for (j, x) in input.iter().enumerate() {
+            let angle = sign * 2.0 * PI * ((k * j) % n) as f64 / n as f64;
+            sum += Complex64::new(x.re as f64, x.im as f64) * Complex64::from_polar(1.0, angle);
+        }
+
+        *value = Complex32::new(sum.re as f32, sum.im as f32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[Complex32], expected: &[Complex32]) {
+        for (a, b) in actual.iter().zip(expected) {
+            assert!((a - b).norm() < 1e-4, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn radix2_should_match_dft() {
+        let input: Vec<_> = (0..8)
+            .map(|i| Complex32::new(i as f32, (i * i) as f32 / 10.0))
+            .collect();
+        let mut expected = input.clone();
+        let mut actual = input;
+
+        dft(&mut expected, -1.0);
+        fft_slice(&mut actual, false);
+
+        assert_close(&actual, &expected);
+    }
+
+    #[test]
+    fn inverse_should_recover_input() {
+        let input: Vec<_> = (0..6).map(|i| Complex32::new(i as f32, -1.0)).collect();
+        let mut values = input.clone();
+
+        fft_slice(&mut values, false);
+        fft_slice(&mut values, true);
+
+        assert_close(&values, &input);
+    }
+
+    #[test]
+    fn rfft_should_keep_non_redundant_frequencies() {
+        let mut output = vec![0.0f32; 6];
+
+        rfft_packed(&[1.0f32, 2.0, 3.0, 4.0], &mut output);
+
+        crate::Data::new(output, crate::Shape::new([6])).assert_close(
+            &crate::Data::from([10.0, -2.0, -2.0, 0.0, 2.0, 0.0]),
+            0.0,
+            1e-6,
+        );
+    }
+}
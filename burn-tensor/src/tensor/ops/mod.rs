@@ -1,3 +1,5 @@
 mod base;
+mod fft;
 
 pub use base::*;
+pub use fft::*;
@@ -0,0 +1,264 @@
+use crate::backend::Backend;
+use crate::{BoolTensor, Data, Tensor};
+use std::fmt::{Debug, Display, Formatter, Result, Write};
+use std::sync::RwLock;
+
+static PRINT_OPTIONS: RwLock<PrintOptions> = RwLock::new(PrintOptions::const_default());
+
+/// Options used when displaying a tensor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// Total number of elements above which the tensor is summarized.
+    pub threshold: usize,
+    /// Number of elements kept at the beginning and the end of each dimension when summarizing.
+    pub edge_items: usize,
+    /// Number of digits after the decimal point of floating point elements, `None` uses the
+    /// default formatting.
+    ///
+    /// The precision of the formatter, e.g. `format!("{:.3}", tensor)`, takes priority.
+    pub precision: Option<usize>,
+}
+
+impl PrintOptions {
+    const fn const_default() -> Self {
+        Self {
+            threshold: 1000,
+            edge_items: 3,
+            precision: None,
+        }
+    }
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Set the options used when displaying tensors.
+pub fn set_print_options(options: PrintOptions) {
+    let mut current = PRINT_OPTIONS.write().unwrap();
+    *current = options;
+}
+
+/// Returns the options used when displaying tensors.
+pub fn print_options() -> PrintOptions {
+    *PRINT_OPTIONS.read().unwrap()
+}
+
+/// Format the data as nested lists, where elements are aligned on the widest one.
+///
+/// When the number of elements exceeds the threshold, only the edge items of each dimension are
+/// kept and the others are elided with `...`.
+pub(crate) fn format_data<P: Debug, const D: usize>(
+    data: &Data<P, D>,
+    options: &PrintOptions,
+    indent: usize,
+) -> String {
+    let summarize = data.shape.num_elements() > options.threshold;
+    let printer = DataPrinter {
+        values: &data.value,
+        dims: &data.shape.dims,
+        strides: strides(&data.shape.dims),
+        edge_items: match summarize {
+            true => Some(options.edge_items),
+            false => None,
+        },
+        precision: options.precision,
+    };
+
+    let mut width = 0;
+    printer.visit(0, 0, &mut |value| {
+        width = usize::max(width, printer.format_elem(value).chars().count())
+    });
+
+    let mut output = String::new();
+    printer.write(&mut output, 0, 0, width, indent);
+    output
+}
+
+struct DataPrinter<'a, P> {
+    values: &'a [P],
+    dims: &'a [usize],
+    strides: Vec<usize>,
+    edge_items: Option<usize>,
+    precision: Option<usize>,
+}
+
+impl<'a, P: Debug> DataPrinter<'a, P> {
+    /// Returns the indexes displayed along a dimension, `None` marks the elided elements.
+    fn indexes(&self, dim: usize) -> Vec<Option<usize>> {
+        let size = self.dims[dim];
+
+        match self.edge_items {
+            Some(edge_items) if size > 2 * edge_items => (0..edge_items)
+                .map(Some)
+                .chain(std::iter::once(None))
+                .chain((size - edge_items..size).map(Some))
+                .collect(),
+            _ => (0..size).map(Some).collect(),
+        }
+    }
+
+    fn format_elem(&self, value: &P) -> String {
+        match self.precision {
+            Some(precision) => format!("{value:.precision$?}"),
+            None => format!("{value:?}"),
+        }
+    }
+
+    fn visit<F: FnMut(&P)>(&self, dim: usize, offset: usize, callback: &mut F) {
+        for index in self.indexes(dim).into_iter().flatten() {
+            let offset = offset + index * self.strides[dim];
+
+            if dim == self.dims.len() - 1 {
+                callback(&self.values[offset]);
+            } else {
+                self.visit(dim + 1, offset, callback);
+            }
+        }
+    }
+
+    fn write(&self, output: &mut String, dim: usize, offset: usize, width: usize, indent: usize) {
+        let is_last_dim = dim == self.dims.len() - 1;
+        let separator = match is_last_dim {
+            true => ", ".to_string(),
+            false => {
+                let num_new_lines = self.dims.len() - dim - 1;
+                format!(
+                    ",{}{}",
+                    "\n".repeat(num_new_lines),
+                    " ".repeat(indent + dim + 1)
+                )
+            }
+        };
+
+        output.push('[');
+        for (i, index) in self.indexes(dim).into_iter().enumerate() {
+            if i > 0 {
+                output.push_str(&separator);
+            }
+
+            match index {
+                Some(index) if is_last_dim => {
+                    let value = self.format_elem(&self.values[offset + index]);
+                    write!(output, "{value:>width$}").unwrap();
+                }
+                Some(index) => {
+                    let offset = offset + index * self.strides[dim];
+                    self.write(output, dim + 1, offset, width, indent);
+                }
+                None => output.push_str("..."),
+            }
+        }
+        output.push(']');
+    }
+}
+
+pub(crate) fn strides(dims: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; dims.len()];
+
+    for i in (0..dims.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * dims[i + 1];
+    }
+
+    strides
+}
+
+fn elem_name<P>() -> &'static str {
+    let name = std::any::type_name::<P>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn write_tensor<B: Backend, P: Debug, const D: usize>(
+    f: &mut Formatter<'_>,
+    name: &str,
+    data: Data<P, D>,
+    device: B::Device,
+    options: PrintOptions,
+) -> Result {
+    let prefix = "  data: ";
+    let data_formatted = format_data(&data, &options, prefix.len());
+
+    writeln!(f, "{name} {{")?;
+    writeln!(f, "{prefix}{data_formatted},")?;
+    writeln!(f, "  shape: {:?},", data.shape.dims)?;
+    writeln!(f, "  device: {device:?},")?;
+    writeln!(f, "  backend: {},", B::name())?;
+    writeln!(f, "  dtype: {},", elem_name::<P>())?;
+    write!(f, "}}")
+}
+
+impl<B: Backend, const D: usize> Display for Tensor<B, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut options = print_options();
+        if let Some(precision) = f.precision() {
+            options.precision = Some(precision);
+        }
+
+        write_tensor::<B, _, D>(f, "Tensor", self.to_data(), self.device(), options)
+    }
+}
+
+impl<B: Backend, const D: usize> Display for BoolTensor<B, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        // The precision would truncate the boolean values.
+        let options = PrintOptions {
+            precision: None,
+            ..print_options()
+        };
+
+        write_tensor::<B, _, D>(f, "BoolTensor", self.to_data(), self.device(), options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Shape;
+
+    #[test]
+    fn should_align_elements() {
+        let data = Data::<f32, 2>::from([[1.0, -20.5], [3.25, 4.0]]);
+
+        let output = format_data(&data, &PrintOptions::default(), 0);
+
+        assert_eq!(output, "[[  1.0, -20.5],\n [ 3.25,   4.0]]");
+    }
+
+    #[test]
+    fn should_apply_precision() {
+        let data = Data::<f32, 1>::from([1.0, 2.5]);
+        let options = PrintOptions {
+            precision: Some(2),
+            ..Default::default()
+        };
+
+        let output = format_data(&data, &options, 0);
+
+        assert_eq!(output, "[1.00, 2.50]");
+    }
+
+    #[test]
+    fn should_separate_blocks_with_blank_lines() {
+        let data = Data::<i32, 3>::from([[[1, 2]], [[3, 4]]]);
+
+        let output = format_data(&data, &PrintOptions::default(), 2);
+
+        assert_eq!(output, "[[[1, 2]],\n\n   [[3, 4]]]");
+    }
+
+    #[test]
+    fn should_summarize_large_tensors() {
+        let data = Data::<i32, 2>::new((0..100).collect(), Shape::new([10, 10]));
+        let options = PrintOptions {
+            threshold: 10,
+            edge_items: 1,
+            precision: None,
+        };
+
+        let output = format_data(&data, &options, 0);
+
+        assert_eq!(output, "[[ 0, ...,  9],\n ...,\n [90, ..., 99]]");
+    }
+}
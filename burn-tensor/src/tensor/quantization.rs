@@ -0,0 +1,261 @@
+use super::print::strides;
+use crate::backend::{ADBackend, Backend};
+use crate::{Data, Element, ElementConversion, Shape, Tensor};
+
+/// The granularity of the quantization parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum QuantizationScheme {
+    /// A single scale and zero-point for the whole tensor.
+    PerTensor,
+    /// One scale and zero-point for each index of the given dimension.
+    PerChannel(usize),
+}
+
+/// Parameters of the affine int8 quantization, where `x = scale * (q - zero_point)`.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct QuantizationParams {
+    /// The granularity of the parameters.
+    pub scheme: QuantizationScheme,
+    /// The scale of each channel, a single one for [per-tensor](QuantizationScheme::PerTensor)
+    /// quantization.
+    pub scale: Vec<f32>,
+    /// The zero-point of each channel, a single one for
+    /// [per-tensor](QuantizationScheme::PerTensor) quantization.
+    pub zero_point: Vec<i8>,
+}
+
+/// Data quantized to int8 with its quantization parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantizedData<const D: usize> {
+    pub values: Data<i8, D>,
+    pub params: QuantizationParams,
+}
+
+impl<const D: usize> QuantizedData<D> {
+    /// Quantize the data, the parameters being computed from the minimum and maximum values of
+    /// each channel.
+    ///
+    /// # Panics
+    ///
+    /// If the channel dimension of a [per-channel](QuantizationScheme::PerChannel) scheme is out
+    /// of bounds.
+    pub fn quantize<E: Element>(data: &Data<E, D>, scheme: QuantizationScheme) -> Self {
+        let num_channels = num_channels(&data.shape, scheme);
+        let mut min = vec![0.0f32; num_channels];
+        let mut max = vec![0.0f32; num_channels];
+
+        let values: Vec<f32> = data.value.iter().map(|value| value.to_elem()).collect();
+        let channel = channel_fn(&data.shape, scheme);
+
+        for (i, value) in values.iter().enumerate() {
+            let channel = channel(i);
+            min[channel] = f32::min(min[channel], *value);
+            max[channel] = f32::max(max[channel], *value);
+        }
+
+        let (scale, zero_point): (Vec<_>, Vec<_>) = min
+            .into_iter()
+            .zip(max)
+            .map(|(min, max)| {
+                let scale = match (max - min) / 255.0 {
+                    scale if scale > 0.0 => scale,
+                    _ => 1.0,
+                };
+                let zero_point = (i8::MIN as f32 - min / scale).round();
+
+                (
+                    scale,
+                    zero_point.clamp(i8::MIN as f32, i8::MAX as f32) as i8,
+                )
+            })
+            .unzip();
+
+        let values = values
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let channel = channel(i);
+                let value = (value / scale[channel]).round() + zero_point[channel] as f32;
+
+                value.clamp(i8::MIN as f32, i8::MAX as f32) as i8
+            })
+            .collect();
+
+        Self {
+            values: Data::new(values, data.shape),
+            params: QuantizationParams {
+                scheme,
+                scale,
+                zero_point,
+            },
+        }
+    }
+
+    /// Returns the approximated values of the original data.
+    pub fn dequantize<E: Element>(&self) -> Data<E, D> {
+        let channel = channel_fn(&self.values.shape, self.params.scheme);
+
+        let values = self
+            .values
+            .value
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let channel = channel(i);
+                let zero_point = self.params.zero_point[channel] as f32;
+
+                (self.params.scale[channel] * (*value as f32 - zero_point)).to_elem()
+            })
+            .collect();
+
+        Data::new(values, self.values.shape)
+    }
+}
+
+/// A tensor quantized to int8, mostly useful to reduce the memory and compute used at inference.
+#[derive(Clone, Debug)]
+pub struct QuantizedTensor<B: Backend, const D: usize> {
+    data: QuantizedData<D>,
+    device: B::Device,
+}
+
+impl<B: Backend, const D: usize> QuantizedTensor<B, D> {
+    /// Create a quantized tensor from quantized data.
+    pub fn from_data_device(data: QuantizedData<D>, device: B::Device) -> Self {
+        Self { data, device }
+    }
+
+    /// Returns the quantized data.
+    pub fn data(&self) -> &QuantizedData<D> {
+        &self.data
+    }
+
+    /// Returns the device the tensor is dequantized on.
+    pub fn device(&self) -> B::Device {
+        self.device
+    }
+
+    /// Returns a new tensor dequantized on the given device.
+    pub fn to_device(&self, device: B::Device) -> Self {
+        Self {
+            data: self.data.clone(),
+            device,
+        }
+    }
+
+    /// Returns the shape of the tensor.
+    pub fn shape(&self) -> &Shape<D> {
+        &self.data.values.shape
+    }
+
+    /// Returns the floating point tensor approximated by the quantized values.
+    pub fn dequantize(&self) -> Tensor<B, D> {
+        Tensor::new(B::dequantize(&self.data, self.device))
+    }
+
+    /// Returns the same quantized tensor on the inner backend.
+    pub fn inner(&self) -> QuantizedTensor<B::InnerBackend, D>
+    where
+        B: ADBackend,
+    {
+        QuantizedTensor::from_data_device(self.data.clone(), self.device)
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Quantize the tensor to int8 with the given scheme.
+    pub fn quantize(&self, scheme: QuantizationScheme) -> QuantizedTensor<B, D> {
+        QuantizedTensor {
+            data: B::quantize(&self.value, scheme),
+            device: self.device(),
+        }
+    }
+}
+
+impl<B: Backend> Tensor<B, 2> {
+    /// Applies the matrix multiplication with a quantized tensor.
+    ///
+    /// Backends may compute it with int8 arithmetic, in which case the current tensor is
+    /// quantized on the fly.
+    ///
+    /// # Panics
+    ///
+    /// If the number of columns of the current tensor differs from the number of rows of the
+    /// quantized tensor.
+    pub fn matmul_quantized(&self, rhs: &QuantizedTensor<B, 2>) -> Tensor<B, 2> {
+        if self.dims()[1] != rhs.shape().dims[0] {
+            panic!(
+                "Can't multiply a tensor of shape {:?} with a quantized tensor of shape {:?}",
+                self.dims(),
+                rhs.shape().dims
+            );
+        }
+
+        Tensor::new(B::quantized_matmul(&self.value, &rhs.data))
+    }
+}
+
+fn num_channels<const D: usize>(shape: &Shape<D>, scheme: QuantizationScheme) -> usize {
+    match scheme {
+        QuantizationScheme::PerTensor => 1,
+        QuantizationScheme::PerChannel(dim) if dim < D => shape.dims[dim],
+        QuantizationScheme::PerChannel(dim) => panic!(
+            "Can't quantize along dimension {} a tensor of shape {:?}",
+            dim, shape.dims
+        ),
+    }
+}
+
+/// Returns the function mapping the index of an element to the index of its channel.
+fn channel_fn<const D: usize>(
+    shape: &Shape<D>,
+    scheme: QuantizationScheme,
+) -> impl Fn(usize) -> usize {
+    let size = num_channels(shape, scheme);
+    let stride = match scheme {
+        QuantizationScheme::PerTensor => 1,
+        QuantizationScheme::PerChannel(dim) => strides(&shape.dims)[dim],
+    };
+
+    move |index| (index / stride) % size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_quantize_per_tensor() {
+        let data = Data::<f32, 1>::from([-127.0, 0.0, 64.0, 128.0]);
+
+        let quantized = QuantizedData::quantize(&data, QuantizationScheme::PerTensor);
+
+        assert_eq!(quantized.params.scale, vec![1.0]);
+        assert_eq!(quantized.params.zero_point, vec![-1]);
+        assert_eq!(quantized.values, Data::from([-128, -1, 63, 127]));
+    }
+
+    #[test]
+    fn should_quantize_per_channel() {
+        let data = Data::<f32, 2>::from([[1.0, -4.0], [2.0, 8.0]]);
+
+        let quantized = QuantizedData::quantize(&data, QuantizationScheme::PerChannel(1));
+
+        assert_eq!(quantized.params.scale.len(), 2);
+        assert_eq!(quantized.params.zero_point, vec![-128, -43]);
+        quantized.dequantize::<f32>().assert_close(
+            &data,
+            0.0,
+            quantized.params.scale[1] as f64 / 2.0,
+        );
+    }
+
+    #[test]
+    fn should_keep_zero_exact() {
+        let data = Data::<f32, 1>::from([0.0, 0.0]);
+
+        let quantized = QuantizedData::quantize(&data, QuantizationScheme::PerTensor);
+
+        assert_eq!(quantized.dequantize::<f32>(), data);
+    }
+}
@@ -0,0 +1,168 @@
+use super::print::strides;
+use crate::backend::Backend;
+use crate::{Data, Element, ElementConversion, Shape, Tensor};
+
+/// A sparse tensor stored in the coordinate (COO) format.
+///
+/// Only the non-zero elements are stored: `indices` has the shape `[nnz, D]` where each row is
+/// the position of the element with the same index in `values`. Duplicated positions are summed.
+#[derive(Debug, Clone)]
+pub struct SparseTensor<B: Backend, const D: usize> {
+    indices: Tensor<B::IntegerBackend, 2>,
+    values: Tensor<B, 1>,
+    shape: Shape<D>,
+}
+
+impl<B: Backend, const D: usize> SparseTensor<B, D> {
+    /// Create a new sparse tensor from the positions and values of its non-zero elements.
+    ///
+    /// # Panics
+    ///
+    /// If the number of indices and values differ, if the indices don't have `D` columns, or if an
+    /// index is out of the bounds of the shape.
+    pub fn new(
+        indices: Tensor<B::IntegerBackend, 2>,
+        values: Tensor<B, 1>,
+        shape: Shape<D>,
+    ) -> Self {
+        let [nnz, rank] = indices.dims();
+
+        if rank != D {
+            panic!("Expected indices with {} columns, got {}", D, rank);
+        }
+        if nnz != values.dims()[0] {
+            panic!(
+                "Expected as many indices as values, got {} indices and {} values",
+                nnz,
+                values.dims()[0]
+            );
+        }
+
+        let data = indices.to_data();
+        for (i, position) in data.value.chunks(D).enumerate() {
+            let in_bounds = position
+                .iter()
+                .zip(shape.dims.iter())
+                .all(|(index, dim)| *index >= 0 && (*index as usize) < *dim);
+
+            if !in_bounds {
+                panic!(
+                    "Index {:?} of element {} is out of bounds for shape {:?}",
+                    position, i, shape.dims
+                );
+            }
+        }
+
+        Self {
+            indices,
+            values,
+            shape,
+        }
+    }
+
+    /// Create a sparse tensor from the non-zero elements of a dense tensor.
+    pub fn from_dense(tensor: &Tensor<B, D>) -> Self {
+        let data = tensor.to_data();
+        let dims = data.shape.dims;
+        let strides = strides(&dims);
+
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+
+        for (i, value) in data.value.into_iter().enumerate() {
+            if value.to_elem::<f64>() == 0.0 {
+                continue;
+            }
+
+            for (dim, stride) in strides.iter().enumerate() {
+                indices.push(((i / stride) % dims[dim]) as i64);
+            }
+            values.push(value);
+        }
+
+        let nnz = values.len();
+        let device = tensor.device();
+
+        Self {
+            indices: Tensor::from_data_device(Data::new(indices, Shape::new([nnz, D])), device),
+            values: Tensor::from_data_device(Data::new(values, Shape::new([nnz])), device),
+            shape: data.shape,
+        }
+    }
+
+    /// Returns the dense tensor with the same values.
+    pub fn to_dense(&self) -> Tensor<B, D> {
+        let data = coo_to_data(&self.indices.to_data(), &self.values.to_data(), self.shape);
+
+        Tensor::from_data_device(data, self.values.device())
+    }
+
+    /// Returns the positions of the non-zero elements, with the shape `[nnz, D]`.
+    pub fn indices(&self) -> &Tensor<B::IntegerBackend, 2> {
+        &self.indices
+    }
+
+    /// Returns the values of the non-zero elements, with the shape `[nnz]`.
+    pub fn values(&self) -> &Tensor<B, 1> {
+        &self.values
+    }
+
+    /// Returns the shape of the tensor.
+    pub fn shape(&self) -> &Shape<D> {
+        &self.shape
+    }
+
+    /// Returns the number of stored elements.
+    pub fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+}
+
+impl<B: Backend> SparseTensor<B, 2> {
+    /// Applies the matrix multiplication of the sparse tensor with a dense tensor.
+    ///
+    /// The result is differentiable with respect to the dense tensor only.
+    ///
+    /// # Panics
+    ///
+    /// If the number of columns of the sparse tensor differs from the number of rows of the dense
+    /// tensor.
+    pub fn spmm(&self, dense: &Tensor<B, 2>) -> Tensor<B, 2> {
+        if self.shape.dims[1] != dense.dims()[0] {
+            panic!(
+                "Can't multiply a sparse tensor of shape {:?} with a tensor of shape {:?}",
+                self.shape.dims,
+                dense.dims()
+            );
+        }
+
+        Tensor::new(B::spmm(
+            &self.indices.value,
+            &self.values.value,
+            self.shape,
+            &dense.value,
+        ))
+    }
+}
+
+/// Build the dense data of a sparse tensor, summing the duplicated positions.
+pub(crate) fn coo_to_data<E: Element, const D: usize>(
+    indices: &Data<i64, 2>,
+    values: &Data<E, 1>,
+    shape: Shape<D>,
+) -> Data<E, D> {
+    let strides = strides(&shape.dims);
+    let mut dense = vec![0.0; shape.num_elements()];
+
+    for (index, value) in indices.value.chunks(D).zip(values.value.iter()) {
+        let position: usize = index
+            .iter()
+            .zip(strides.iter())
+            .map(|(index, stride)| *index as usize * stride)
+            .sum();
+
+        dense[position] += value.to_elem::<f64>();
+    }
+
+    Data::new(dense, shape).convert()
+}
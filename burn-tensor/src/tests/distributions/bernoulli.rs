@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(bernoulli)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{kl_divergence, Bernoulli};
+    use burn_tensor::{Data, Tensor};
+    use std::f32::consts::LN_2;
+
+    #[test]
+    fn should_compute_log_prob() {
+        let bernoulli = Bernoulli::<TestBackend, 1>::new(Tensor::from_floats([0.3, 0.3]));
+        let value = Tensor::from_floats([1.0, 0.0]);
+
+        let log_prob = bernoulli.log_prob(&value);
+
+        log_prob
+            .into_data()
+            .assert_approx_eq(&Data::from([-1.203973, -0.356675]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy_and_kl_divergence() {
+        let p = Bernoulli::<TestBackend, 1>::from_logits(Tensor::from_floats([-0.847298]));
+        let q = Bernoulli::<TestBackend, 1>::new(Tensor::from_floats([0.5]));
+
+        p.entropy()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.610864]), 4);
+        kl_divergence(&p, &q)
+            .into_data()
+            .assert_approx_eq(&Data::from([0.082283]), 4);
+    }
+
+    #[test]
+    fn should_not_underflow_with_large_logits() {
+        let bernoulli =
+            Bernoulli::<TestBackend, 1>::from_logits(Tensor::from_floats([-200.0, 200.0]));
+        let value = Tensor::from_floats([1.0, 0.0]);
+
+        let log_prob = bernoulli.log_prob(&value);
+
+        log_prob
+            .into_data()
+            .assert_approx_eq(&Data::from([-200.0, -200.0]), 3);
+    }
+
+    #[test]
+    fn should_compute_log_prob_with_probs_of_zero_and_one() {
+        let bernoulli = Bernoulli::<TestBackend, 1>::new(Tensor::from_floats([0.0, 1.0, 0.3]));
+        let value = Tensor::from_floats([0.0, 1.0, 1.0]);
+
+        let log_prob = bernoulli.log_prob(&value);
+
+        log_prob
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0, -1.203973]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy_and_kl_divergence_with_probs_of_zero_and_one() {
+        let p = Bernoulli::<TestBackend, 1>::new(Tensor::from_floats([0.0, 1.0, 0.0]));
+        let q = Bernoulli::<TestBackend, 1>::new(Tensor::from_floats([0.5, 0.5, 0.0]));
+
+        p.entropy()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0, 0.0]), 4);
+        kl_divergence(&p, &q)
+            .into_data()
+            .assert_approx_eq(&Data::from([LN_2, LN_2, 0.0]), 4);
+    }
+
+    #[test]
+    fn should_sample_zeros_and_ones() {
+        let bernoulli = Bernoulli::<TestBackend, 1>::new(Tensor::from_floats([0.0, 1.0, 0.5]));
+
+        let sample = bernoulli.sample().into_data();
+
+        assert_eq!(sample.value[0], 0.0);
+        assert_eq!(sample.value[1], 1.0);
+        assert!(sample.value[2] == 0.0 || sample.value[2] == 1.0);
+    }
+}
@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(beta)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{kl_divergence, Beta};
+    use burn_tensor::{Data, Tensor};
+
+    fn beta() -> Beta<TestBackend, 1> {
+        Beta::new(Tensor::from_floats([2.0]), Tensor::from_floats([3.0]))
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let log_prob = beta().log_prob(&Tensor::from_floats([0.5]));
+
+        log_prob
+            .into_data()
+            .assert_approx_eq(&Data::from([0.405465]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy_and_kl_divergence() {
+        let uniform = Beta::new(Tensor::from_floats([1.0]), Tensor::from_floats([1.0]));
+
+        beta()
+            .entropy()
+            .into_data()
+            .assert_approx_eq(&Data::from([-0.234907]), 4);
+        kl_divergence(&beta(), &uniform)
+            .into_data()
+            .assert_approx_eq(&Data::from([0.234907]), 4);
+    }
+
+    #[test]
+    fn should_sample_with_the_right_mean() {
+        let beta = Beta::<TestBackend, 1>::new(
+            Tensor::ones([2000]).mul_scalar(2.0),
+            Tensor::ones([2000]).mul_scalar(3.0),
+        );
+
+        let sample = beta.sample().into_data();
+        let mean = sample.value.iter().sum::<f32>() / 2000.0;
+
+        assert!(sample.value.iter().all(|x| *x > 0.0 && *x < 1.0));
+        assert!((mean - 0.4).abs() < 0.03, "mean {}", mean);
+    }
+}
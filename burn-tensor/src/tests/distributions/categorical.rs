@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(categorical)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{kl_divergence, Categorical};
+    use burn_tensor::{Data, Tensor};
+    use std::f32::consts::LN_2;
+
+    #[test]
+    fn should_compute_log_prob() {
+        let categorical = Categorical::<TestBackend, 2>::new(Tensor::from_floats([
+            [0.2, 0.3, 0.5],
+            [2.0, 0.0, 2.0],
+        ]));
+        let value = Tensor::from_ints([[2], [0]]);
+
+        let log_prob = categorical.log_prob(&value);
+
+        log_prob
+            .into_data()
+            .assert_approx_eq(&Data::from([[-LN_2], [-LN_2]]), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Categorical index 3 is out of range for 3 classes")]
+    fn should_panic_with_out_of_range_index() {
+        let categorical =
+            Categorical::<TestBackend, 2>::new(Tensor::from_floats([[0.2, 0.3, 0.5]]));
+
+        categorical.log_prob(&Tensor::from_ints([[3]]));
+    }
+
+    #[test]
+    fn should_compute_entropy_and_kl_divergence() {
+        let p = Categorical::<TestBackend, 2>::new(Tensor::from_floats([[0.2, 0.3, 0.5]]));
+        let q = Categorical::<TestBackend, 2>::from_logits(Tensor::zeros([1, 3]));
+
+        p.entropy()
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.029653]]), 4);
+        kl_divergence(&p, &q)
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.068959]]), 4);
+    }
+
+    #[test]
+    fn should_ignore_impossible_classes() {
+        let categorical =
+            Categorical::<TestBackend, 2>::new(Tensor::from_floats([[0.0, 1.0, 1.0]]));
+
+        let entropy = categorical.entropy();
+
+        entropy
+            .into_data()
+            .assert_approx_eq(&Data::from([[LN_2]]), 4);
+        assert_eq!(categorical.sample().dims(), [1, 1]);
+    }
+}
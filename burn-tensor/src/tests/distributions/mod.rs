@@ -0,0 +1,4 @@
+mod bernoulli;
+mod beta;
+mod categorical;
+mod normal;
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(normal)]
+mod tests {
+    use super::*;
+    use burn_tensor::distributions::{kl_divergence, Normal};
+    use burn_tensor::{Data, Tensor};
+
+    fn normal() -> Normal<TestBackend, 1> {
+        Normal::new(
+            Tensor::from_floats([0.0, 1.0]),
+            Tensor::from_floats([1.0, 2.0]),
+        )
+    }
+
+    #[test]
+    fn should_compute_log_prob() {
+        let value = Tensor::from_floats([0.0, 2.0]);
+
+        let log_prob = normal().log_prob(&value);
+
+        log_prob
+            .into_data()
+            .assert_approx_eq(&Data::from([-0.918939, -1.737086]), 4);
+    }
+
+    #[test]
+    fn should_compute_entropy() {
+        let entropy = normal().entropy();
+
+        entropy
+            .into_data()
+            .assert_approx_eq(&Data::from([1.418939, 2.112086]), 4);
+    }
+
+    #[test]
+    fn should_compute_kl_divergence() {
+        let p =
+            Normal::<TestBackend, 1>::new(Tensor::from_floats([0.0]), Tensor::from_floats([1.0]));
+        let q =
+            Normal::<TestBackend, 1>::new(Tensor::from_floats([1.0]), Tensor::from_floats([2.0]));
+
+        let kl = kl_divergence(&p, &q);
+
+        kl.into_data().assert_approx_eq(&Data::from([0.443147]), 4);
+    }
+
+    #[test]
+    fn should_sample_with_the_right_shape() {
+        let sample = normal().sample();
+
+        assert_eq!(sample.dims(), [2]);
+    }
+}
@@ -1,4 +1,5 @@
 mod activation;
+mod distributions;
 mod module;
 mod ops;
 mod stats;
@@ -11,6 +12,12 @@ macro_rules! testgen_all {
         burn_tensor::testgen_relu!();
         burn_tensor::testgen_softmax!();
 
+        // test distributions
+        burn_tensor::testgen_bernoulli!();
+        burn_tensor::testgen_beta!();
+        burn_tensor::testgen_categorical!();
+        burn_tensor::testgen_normal!();
+
         // test module
         burn_tensor::testgen_module_forward!();
 
@@ -18,18 +25,26 @@ macro_rules! testgen_all {
         burn_tensor::testgen_add!();
         burn_tensor::testgen_aggregation!();
         burn_tensor::testgen_arg!();
+        burn_tensor::testgen_close!();
+        burn_tensor::testgen_create!();
+        burn_tensor::testgen_display!();
         burn_tensor::testgen_div!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
+        burn_tensor::testgen_fft!();
         burn_tensor::testgen_index!();
         burn_tensor::testgen_map_comparison!();
         burn_tensor::testgen_mask!();
         burn_tensor::testgen_matmul!();
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_neg!();
+        burn_tensor::testgen_owned!();
         burn_tensor::testgen_powf!();
+        burn_tensor::testgen_quantization!();
+        burn_tensor::testgen_random!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_sparse!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_transpose!();
 
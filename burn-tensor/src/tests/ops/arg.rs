@@ -5,12 +5,31 @@ mod tests {
 
     #[test]
     fn test_argmax_2d() {
-        let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
 
         let data_actual = tensor.argmax(1);
 
         let data_expected = Data::from([[2], [2]]);
         assert_eq!(data_expected, data_actual.to_data());
     }
+
+    #[test]
+    fn test_argmax_2d_dim_0() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.0, 5.0, 2.0], [3.0, 4.0, 1.0]]);
+
+        let data_actual = tensor.argmax(0);
+
+        let data_expected = Data::from([[1, 0, 0]]);
+        assert_eq!(data_expected, data_actual.to_data());
+    }
+
+    #[test]
+    fn test_argmin_2d_dim_0() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.0, 5.0, 2.0], [3.0, 4.0, 1.0]]);
+
+        let data_actual = tensor.argmin(0);
+
+        let data_expected = Data::from([[0, 1, 1]]);
+        assert_eq!(data_expected, data_actual.to_data());
+    }
 }
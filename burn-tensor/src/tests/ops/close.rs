@@ -0,0 +1,31 @@
+#[burn_tensor_testgen::testgen(close)]
+mod tests {
+    use super::*;
+    use burn_tensor::{assert_tensor_close, Tensor};
+
+    #[test]
+    fn should_support_all_close() {
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0], [100.0, 1000.0]]);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0], [100.001, 1000.01]]);
+
+        assert!(tensor_1.all_close(&tensor_2, 1e-4, 1e-6));
+        assert!(!tensor_1.all_close(&tensor_2, 1e-6, 1e-6));
+    }
+
+    #[test]
+    fn should_assert_tensors_are_close() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        assert_tensor_close!(tensor.clone() / 3.0 * 3.0, tensor);
+        assert_tensor_close!(tensor.clone() + 0.01, tensor, rtol = 0.0, atol = 0.02);
+    }
+
+    #[test]
+    #[should_panic(expected = "first mismatch at index [1, 1]")]
+    fn should_panic_with_mismatching_index() {
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 5.0]]);
+
+        assert_tensor_close!(tensor_1, tensor_2);
+    }
+}
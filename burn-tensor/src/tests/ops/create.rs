@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(create)]
+mod tests {
+    use super::*;
+    use burn_tensor::{tensor, Data, Tensor};
+
+    #[test]
+    fn should_create_tensor_from_macro() {
+        let tensor: Tensor<TestBackend, 2> = tensor![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+        let data_expected = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(data_expected, tensor.into_data());
+    }
+
+    #[test]
+    fn should_infer_rank_from_macro() {
+        let tensor_1d: Tensor<TestBackend, 1> = tensor![1, 2, 3];
+        let tensor_3d: Tensor<TestBackend, 3> = tensor![[[1.0], [2.0]], [[3.0], [4.0]]];
+
+        assert_eq!(tensor_1d.dims(), [3]);
+        assert_eq!(tensor_3d.dims(), [2, 2, 1]);
+        assert_eq!(tensor_1d.into_data(), Data::from([1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn should_create_tensor_from_macro_on_device() {
+        let device = <TestBackend as burn_tensor::backend::Backend>::Device::default();
+
+        let tensor_1d: Tensor<TestBackend, 1> = tensor![1, -2, 3; device];
+        let tensor_2d: Tensor<TestBackend, 2> = tensor![[1.0, 2.0], [3.0, 4.0]; device];
+
+        assert_eq!(format!("{:?}", tensor_2d.device()), format!("{:?}", device));
+        assert_eq!(tensor_1d.into_data(), Data::from([1.0, -2.0, 3.0]));
+        assert_eq!(tensor_2d.into_data(), Data::from([[1.0, 2.0], [3.0, 4.0]]));
+    }
+
+    #[test]
+    fn should_create_tensor_from_floats() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.5, 2.0], [3.0, 4.5]]);
+
+        let data_expected = Data::from([[1.5, 2.0], [3.0, 4.5]]);
+        assert_eq!(data_expected, tensor.into_data());
+    }
+
+    #[test]
+    fn should_create_tensor_from_ints() {
+        let tensor = Tensor::<TestBackend, 1>::from_ints([1, -2, 3]);
+
+        let data_expected = Data::from([1.0, -2.0, 3.0]);
+        assert_eq!(data_expected, tensor.into_data());
+    }
+
+    #[test]
+    fn should_create_tensor_from_vec() {
+        let tensor: Tensor<TestBackend, 1> = vec![1.0, 2.0, 3.0].into();
+
+        let data_expected = Data::from([1.0, 2.0, 3.0]);
+        assert_eq!(data_expected, tensor.into_data());
+    }
+}
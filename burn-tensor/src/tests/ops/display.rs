@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(display)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{BoolTensor, Data, Tensor};
+
+    #[test]
+    fn should_display_tensor() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.5], [-3.0, 4.0]]));
+
+        let output = format!("{:.1}", tensor);
+
+        let expected = format!(
+            "Tensor {{\n  data: [[ 1.0,  2.5],\n         [-3.0,  4.0]],\n  shape: [2, 2],\n  device: {:?},\n  backend: {},\n  dtype: {},\n}}",
+            tensor.device(),
+            TestBackend::name(),
+            std::any::type_name::<<TestBackend as Backend>::Elem>()
+        );
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn should_display_bool_tensor() {
+        let tensor = BoolTensor::<TestBackend, 1>::from_data(Data::from([true, false]));
+
+        let output = format!("{}", tensor);
+
+        let expected = format!(
+            "BoolTensor {{\n  data: [ true, false],\n  shape: [2],\n  device: {:?},\n  backend: {},\n  dtype: bool,\n}}",
+            tensor.device(),
+            TestBackend::name(),
+        );
+        assert_eq!(output, expected);
+    }
+}
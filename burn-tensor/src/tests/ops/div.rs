@@ -5,10 +5,8 @@ mod tests {
 
     #[test]
     fn should_support_div_ops() {
-        let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let data_2 = Data::from([[1.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
-        let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
 
         let output = tensor_1 / tensor_2;
 
@@ -19,9 +17,8 @@ mod tests {
 
     #[test]
     fn should_support_div_scalar_ops() {
-        let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
         let scalar = 2.0;
-        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
 
         let output = tensor / scalar;
 
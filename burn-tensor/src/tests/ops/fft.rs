@@ -0,0 +1,113 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex32, ComplexTensor, Data, Tensor};
+
+    #[test]
+    fn should_support_rfft() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0, 4.0], [1.0, 0.0, 0.0, 0.0]]);
+
+        let output = tensor.rfft();
+
+        output.re().to_data().assert_close(
+            &Data::from([[10.0, -2.0, -2.0], [1.0, 1.0, 1.0]]),
+            0.0,
+            1e-5,
+        );
+        output.im().to_data().assert_close(
+            &Data::from([[0.0, 2.0, 0.0], [0.0, 0.0, 0.0]]),
+            0.0,
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn should_support_rfft_odd_length() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, 3.0]);
+
+        let output = tensor.rfft();
+
+        let sqrt_3 = 3.0f32.sqrt();
+        output
+            .re()
+            .to_data()
+            .assert_close(&Data::from([6.0, -1.5]), 0.0, 1e-5);
+        output
+            .im()
+            .to_data()
+            .assert_close(&Data::from([0.0, sqrt_3 / 2.0]), 0.0, 1e-5);
+    }
+
+    #[test]
+    fn fft_should_match_rfft_for_real_values() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.5, -1.0, 2.0, 3.0, 0.0, 1.0]);
+
+        let output = ComplexTensor::from_real(tensor.clone()).fft();
+        let output_real = tensor.rfft();
+
+        output
+            .re()
+            .index([0..4])
+            .to_data()
+            .assert_close(&output_real.re().to_data(), 0.0, 1e-5);
+        output
+            .im()
+            .index([0..4])
+            .to_data()
+            .assert_close(&output_real.im().to_data(), 0.0, 1e-5);
+    }
+
+    #[test]
+    fn ifft_should_recover_input() {
+        let data = Data::<Complex32, 2>::from([
+            [Complex32::new(1.0, -1.0), Complex32::new(2.0, 0.5)],
+            [Complex32::new(0.0, 3.0), Complex32::new(-4.0, 1.0)],
+        ]);
+        let tensor = ComplexTensor::<TestBackend, 2>::from_data(data.clone());
+
+        let output = tensor.fft().ifft().to_data();
+
+        for (actual, expected) in output.value.iter().zip(data.value.iter()) {
+            assert!((actual - expected).norm() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn should_support_complex_arithmetic() {
+        let lhs =
+            ComplexTensor::<TestBackend, 1>::from_data(Data::from([Complex32::new(1.0, 2.0)]));
+        let rhs =
+            ComplexTensor::<TestBackend, 1>::from_data(Data::from([Complex32::new(3.0, -1.0)]));
+
+        assert_eq!(
+            lhs.mul(&rhs).to_data(),
+            Data::from([Complex32::new(5.0, 5.0)])
+        );
+        assert_eq!(
+            lhs.add(&rhs.conj()).to_data(),
+            Data::from([Complex32::new(4.0, 3.0)])
+        );
+        assert_eq!(rhs.norm_sqr().into_data(), Data::from([10.0]));
+    }
+
+    #[test]
+    fn should_support_stft() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]]);
+        let window = Tensor::<TestBackend, 1>::from_floats([1.0, 0.0, 1.0, 0.0]);
+
+        let output = tensor.stft(4, 2, Some(&window));
+
+        assert_eq!(output.dims(), [1, 2, 3]);
+        output.re().to_data().assert_close(
+            &Data::from([[[4.0, -2.0, 4.0], [8.0, -2.0, 8.0]]]),
+            0.0,
+            1e-5,
+        );
+        output.im().to_data().assert_close(
+            &Data::from([[[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]]),
+            0.0,
+            1e-5,
+        );
+    }
+}
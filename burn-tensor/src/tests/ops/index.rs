@@ -15,8 +15,7 @@ mod tests {
 
     #[test]
     fn should_support_partial_indexing_1d() {
-        let data = Data::from([0.0, 1.0, 2.0]);
-        let tensor = Tensor::<TestBackend, 1>::from_data(data);
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.0, 1.0, 2.0]);
 
         let data_actual = tensor.index([1..3]).into_data();
 
@@ -38,8 +37,7 @@ mod tests {
 
     #[test]
     fn should_support_partial_indexing_2d() {
-        let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
 
         let data_actual = tensor.index([0..2, 0..2]).into_data();
 
@@ -49,11 +47,8 @@ mod tests {
 
     #[test]
     fn should_support_indexe_assign_1d() {
-        let data = Data::from([0.0, 1.0, 2.0]);
-        let data_assigned = Data::from([10.0, 5.0]);
-
-        let tensor = Tensor::<TestBackend, 1>::from_data(data);
-        let tensor_assigned = Tensor::<TestBackend, 1>::from_data(data_assigned);
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.0, 1.0, 2.0]);
+        let tensor_assigned = Tensor::<TestBackend, 1>::from_floats([10.0, 5.0]);
 
         let data_actual = tensor.index_assign([0..2], &tensor_assigned).into_data();
 
@@ -63,11 +58,8 @@ mod tests {
 
     #[test]
     fn should_support_indexe_assign_2d() {
-        let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let data_assigned = Data::from([[10.0, 5.0]]);
-
-        let tensor = Tensor::<TestBackend, 2>::from_data(data);
-        let tensor_assigned = Tensor::<TestBackend, 2>::from_data(data_assigned);
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor_assigned = Tensor::<TestBackend, 2>::from_floats([[10.0, 5.0]]);
 
         let data_actual = tensor
             .index_assign([1..2, 0..2], &tensor_assigned)
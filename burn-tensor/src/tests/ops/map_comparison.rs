@@ -5,8 +5,7 @@ mod tests {
 
     #[test]
     fn test_greater_scalar() {
-        let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
 
         let data_actual = tensor_1.greater_scalar(4.0);
 
@@ -16,8 +15,7 @@ mod tests {
 
     #[test]
     fn test_greater_equal_scalar() {
-        let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
 
         let data_actual = tensor_1.greater_equal_scalar(4.0);
 
@@ -27,10 +25,8 @@ mod tests {
 
     #[test]
     fn test_greater() {
-        let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let data_2 = Data::from([[1.0, 1.0, 1.0], [4.0, 3.0, 50.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
-        let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0, 1.0], [4.0, 3.0, 50.0]]);
 
         let data_actual = tensor_1.greater(&tensor_2);
 
@@ -40,10 +36,8 @@ mod tests {
 
     #[test]
     fn test_greater_equal() {
-        let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let data_2 = Data::from([[1.0, 1.0, 1.0], [4.0, 3.0, 50.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
-        let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0, 1.0], [4.0, 3.0, 50.0]]);
 
         let data_actual = tensor_1.greater_equal(&tensor_2);
 
@@ -53,8 +47,7 @@ mod tests {
 
     #[test]
     fn test_lower_scalar() {
-        let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
 
         let data_actual = tensor_1.lower_scalar(4.0);
 
@@ -64,8 +57,7 @@ mod tests {
 
     #[test]
     fn test_lower_equal_scalar() {
-        let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
 
         let data_actual = tensor_1.lower_equal_scalar(4.0);
 
@@ -75,10 +67,8 @@ mod tests {
 
     #[test]
     fn test_lower() {
-        let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let data_2 = Data::from([[1.0, 1.0, 1.0], [4.0, 3.0, 50.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
-        let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0, 1.0], [4.0, 3.0, 50.0]]);
 
         let data_actual = tensor_1.lower(&tensor_2);
 
@@ -88,10 +78,8 @@ mod tests {
 
     #[test]
     fn test_lower_equal() {
-        let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let data_2 = Data::from([[1.0, 1.0, 1.0], [4.0, 3.0, 50.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
-        let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0, 1.0], [4.0, 3.0, 50.0]]);
 
         let data_actual = tensor_1.lower_equal(&tensor_2);
 
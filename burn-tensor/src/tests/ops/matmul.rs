@@ -5,10 +5,8 @@ mod tests {
 
     #[test]
     fn test_matmul_d2() {
-        let data_1 = Data::from([[1.0, 7.0], [2.0, 3.0], [1.0, 5.0]]);
-        let data_2 = Data::from([[4.0, 7.0, 5.0], [2.0, 3.0, 5.0]]);
-        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
-        let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_floats([[1.0, 7.0], [2.0, 3.0], [1.0, 5.0]]);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_floats([[4.0, 7.0, 5.0], [2.0, 3.0, 5.0]]);
 
         let tensor_3 = tensor_1.matmul(&tensor_2);
 
@@ -20,10 +18,8 @@ mod tests {
 
     #[test]
     fn test_matmul_d3() {
-        let data_1 = Data::from([[[1.0, 7.0], [2.0, 3.0]]]);
-        let data_2 = Data::from([[[4.0, 7.0], [2.0, 3.0]]]);
-        let tensor_1 = Tensor::<TestBackend, 3>::from_data(data_1);
-        let tensor_2 = Tensor::<TestBackend, 3>::from_data(data_2);
+        let tensor_1 = Tensor::<TestBackend, 3>::from_floats([[[1.0, 7.0], [2.0, 3.0]]]);
+        let tensor_2 = Tensor::<TestBackend, 3>::from_floats([[[4.0, 7.0], [2.0, 3.0]]]);
 
         let tensor_3 = tensor_1.matmul(&tensor_2);
 
@@ -32,4 +28,46 @@ mod tests {
             Data::from([[[18.0, 28.0], [14.0, 23.0]]])
         );
     }
+
+    #[test]
+    fn test_matmul_broadcast_lhs() {
+        let tensor_1 = Tensor::<TestBackend, 3>::from_floats([[[1.0, 7.0], [2.0, 3.0]]]);
+        let tensor_2 = Tensor::<TestBackend, 3>::from_floats([
+            [[4.0, 7.0], [2.0, 3.0]],
+            [[1.0, 0.0], [0.0, 1.0]],
+        ]);
+
+        let tensor_3 = tensor_1.matmul(&tensor_2);
+
+        assert_eq!(
+            tensor_3.into_data(),
+            Data::from([[[18.0, 28.0], [14.0, 23.0]], [[1.0, 7.0], [2.0, 3.0]]])
+        );
+    }
+
+    #[test]
+    fn test_matmul_broadcast_each_batch_dim() {
+        let tensor_1 = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0]]], [[[3.0, 4.0]]]]);
+        let tensor_2 = Tensor::<TestBackend, 4>::from_floats([[[[1.0], [0.0]], [[0.0], [1.0]]]]);
+
+        let tensor_3 = tensor_1.matmul(&tensor_2);
+
+        assert_eq!(
+            tensor_3.into_data(),
+            Data::from([[[[1.0]], [[2.0]]], [[[3.0]], [[4.0]]]])
+        );
+    }
+
+    #[test]
+    fn test_matmul_transposed() {
+        let tensor_1 = Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0], [7.0, 3.0]]]);
+        let tensor_2 = Tensor::<TestBackend, 3>::from_floats([[[4.0, 7.0], [2.0, 3.0]]]);
+
+        let tensor_3 = tensor_1.transpose().matmul(&tensor_2);
+
+        assert_eq!(
+            tensor_3.into_data(),
+            Data::from([[[18.0, 28.0], [14.0, 23.0]]])
+        );
+    }
 }
@@ -1,17 +1,25 @@
 mod add;
 mod aggregation;
 mod arg;
+mod close;
+mod create;
+mod display;
 mod div;
 mod erf;
 mod exp;
+mod fft;
 mod index;
 mod map_comparison;
 mod mask;
 mod matmul;
 mod mul;
 mod neg;
+mod owned;
 mod powf;
+mod quantization;
+mod random;
 mod repeat;
 mod reshape;
+mod sparse;
 mod sub;
 mod transpose;
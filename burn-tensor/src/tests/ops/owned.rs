@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(owned)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_match_borrowed_ops() {
+        let data = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let lhs = Tensor::<TestBackend, 2>::from_data(data.clone());
+        let rhs = Tensor::<TestBackend, 2>::from_data(data);
+
+        let expected = lhs.add(&rhs).mul_scalar(2.0).exp().log().neg().sub(&lhs);
+        let actual = -((lhs.clone() + rhs) * 2.0).exp_owned().log_owned() - lhs;
+
+        actual
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_broadcast_into_owned_tensor() {
+        let lhs = Tensor::<TestBackend, 2>::from_floats([[1.0], [2.0]]);
+        let rhs = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = lhs / rhs;
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.5, 0.3333], [0.5, 0.4, 0.3333]]), 3);
+    }
+
+    #[test]
+    fn should_not_modify_shared_tensors() {
+        let lhs = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0]);
+        let rhs = Tensor::<TestBackend, 1>::from_floats([3.0, 4.0]);
+
+        let output = lhs.clone() - rhs.clone();
+        let output_powf = output.clone().powf_owned(2.0);
+
+        assert_eq!(lhs.into_data(), Data::from([1.0, 2.0]));
+        assert_eq!(rhs.into_data(), Data::from([3.0, 4.0]));
+        assert_eq!(output.into_data(), Data::from([-2.0, -2.0]));
+        assert_eq!(output_powf.into_data(), Data::from([4.0, 4.0]));
+    }
+}
@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(quantization)]
+mod tests {
+    use super::*;
+    use burn_tensor::{QuantizationScheme, Tensor};
+
+    #[test]
+    fn should_support_quantize_dequantize() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[-1.0, 0.5], [2.0, 3.0]]);
+
+        let quantized = tensor.quantize(QuantizationScheme::PerTensor);
+        let tensor_actual = quantized.dequantize();
+
+        let atol = quantized.data().params.scale[0] as f64 / 2.0;
+        tensor_actual
+            .to_data()
+            .assert_close(&tensor.to_data(), 0.0, atol);
+    }
+
+    #[test]
+    fn should_support_quantized_matmul_per_channel() {
+        let lhs = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 0.5], [0.0, 3.0, -1.5]]);
+        let rhs = Tensor::<TestBackend, 2>::from_floats([[0.2, -4.0], [1.0, 8.0], [-0.6, 2.0]]);
+
+        let output = lhs.matmul_quantized(&rhs.quantize(QuantizationScheme::PerChannel(1)));
+
+        output
+            .to_data()
+            .assert_close(&lhs.matmul(&rhs).to_data(), 0.0, 0.1);
+    }
+
+    #[test]
+    fn should_support_quantized_matmul_per_row() {
+        let lhs = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 0.5], [0.0, 3.0, -1.5]]);
+        let rhs = Tensor::<TestBackend, 2>::from_floats([[0.2, -4.0], [1.0, 8.0], [-0.6, 2.0]]);
+
+        let output = lhs.matmul_quantized(&rhs.quantize(QuantizationScheme::PerChannel(0)));
+
+        output
+            .to_data()
+            .assert_close(&lhs.matmul(&rhs).to_data(), 0.0, 0.1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_channel_dim_out_of_bounds() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0]);
+
+        tensor.quantize(QuantizationScheme::PerChannel(1));
+    }
+}
@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(random)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Data, Distribution, Generator, Tensor};
+
+    #[test]
+    fn should_be_reproducible_with_the_same_generator_seed() {
+        let tensor_1 = Tensor::<TestBackend, 2>::random_with(
+            [3, 4],
+            Distribution::Standard,
+            &mut Generator::new(42),
+        );
+        let tensor_2 = Tensor::<TestBackend, 2>::random_with(
+            [3, 4],
+            Distribution::Standard,
+            &mut Generator::new(42),
+        );
+
+        assert_eq!(tensor_1.into_data(), tensor_2.into_data());
+    }
+
+    #[test]
+    fn should_advance_the_generator() {
+        let mut generator = Generator::new(42);
+
+        let tensor_1 =
+            Tensor::<TestBackend, 1>::random_with([8], Distribution::Standard, &mut generator);
+        let tensor_2 =
+            Tensor::<TestBackend, 1>::random_with([8], Distribution::Standard, &mut generator);
+
+        assert_ne!(tensor_1.into_data(), tensor_2.into_data());
+    }
+
+    #[test]
+    fn should_restore_the_rng_state_of_the_generator() {
+        // A dedicated generator is used, since the generator of the device is shared with the
+        // other tests running in parallel.
+        let mut generator = Generator::new(42);
+        let state = generator.state();
+        let tensor_1 =
+            Tensor::<TestBackend, 1>::random_with([8], Distribution::Standard, &mut generator);
+
+        let mut generator = Generator::from_state(&state);
+        let tensor_2 =
+            Tensor::<TestBackend, 1>::random_with([8], Distribution::Standard, &mut generator);
+
+        assert_eq!(tensor_1.into_data(), tensor_2.into_data());
+    }
+
+    #[test]
+    fn should_sample_multinomial_indexes() {
+        let probs = Tensor::<TestBackend, 2>::from_floats([[0.0, 0.0, 1.0], [0.0, 3.0, 0.0]]);
+
+        let indexes = probs.multinomial_with(4, true, &mut Generator::new(42));
+
+        assert_eq!(
+            indexes.into_data(),
+            Data::from([[2, 2, 2, 2], [1, 1, 1, 1]])
+        );
+    }
+
+    #[test]
+    fn should_sample_distinct_indexes_without_replacement() {
+        let probs = Tensor::<TestBackend, 1>::from_floats([1.0, 1.0, 1.0, 1.0]);
+
+        let indexes = probs.multinomial(4, false);
+
+        let mut indexes = indexes.into_data().value;
+        indexes.sort();
+        assert_eq!(indexes, vec![0, 1, 2, 3]);
+    }
+}
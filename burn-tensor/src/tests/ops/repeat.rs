@@ -5,8 +5,7 @@ mod tests {
 
     #[test]
     fn should_support_repeat_ops() {
-        let data = Data::from([[0.0, 1.0, 2.0]]);
-        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0]]);
 
         let data_actual = tensor.repeat(0, 4).into_data();
 
@@ -5,8 +5,7 @@ mod tests {
 
     #[test]
     fn should_support_reshape_1d() {
-        let data = Data::from([0.0, 1.0, 2.0]);
-        let tensor = Tensor::<TestBackend, 1>::from_data(data);
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.0, 1.0, 2.0]);
 
         let data_actual = tensor.reshape([1, 3]).into_data();
 
@@ -16,8 +15,7 @@ mod tests {
 
     #[test]
     fn should_support_reshape_2d() {
-        let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
-        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
 
         let data_actual = tensor.reshape([6]).into_data();
 
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Data, Shape, SparseTensor, Tensor};
+
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+    type IntTensor<const D: usize> = Tensor<<TestBackend as Backend>::IntegerBackend, D>;
+
+    #[test]
+    fn should_convert_from_and_to_dense() {
+        let tensor = TestTensor::from_floats([[0.0, 2.0, 0.0], [3.0, 0.0, 4.0]]);
+
+        let sparse = SparseTensor::from_dense(&tensor);
+
+        assert_eq!(sparse.nnz(), 3);
+        assert_eq!(
+            sparse.indices().to_data(),
+            Data::from([[0, 1], [1, 0], [1, 2]])
+        );
+        assert_eq!(sparse.values().to_data(), Data::from([2.0, 3.0, 4.0]));
+        assert_eq!(sparse.to_dense().into_data(), tensor.into_data());
+    }
+
+    #[test]
+    fn should_sum_duplicated_indices() {
+        let indices = IntTensor::from_data(Data::from([[0, 1], [0, 1], [1, 0]]));
+        let values = TestTensor::from_floats([1.0, 2.0, 5.0]);
+
+        let sparse = SparseTensor::new(indices, values, Shape::new([2, 2]));
+
+        assert_eq!(
+            sparse.to_dense().into_data(),
+            Data::from([[0.0, 3.0], [5.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_spmm() {
+        let indices = IntTensor::from_data(Data::from([[0, 0], [1, 2], [2, 1], [2, 1]]));
+        let values = TestTensor::from_floats([2.0, -1.0, 1.0, 3.0]);
+        let sparse = SparseTensor::new(indices, values, Shape::new([3, 3]));
+        let dense = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let output = sparse.spmm(&dense);
+
+        let output_expected = sparse.to_dense().matmul(&dense);
+        assert_eq!(
+            output.into_data(),
+            Data::from([[2.0, 4.0], [-5.0, -6.0], [12.0, 16.0]])
+        );
+        assert_eq!(
+            output_expected.into_data(),
+            Data::from([[2.0, 4.0], [-5.0, -6.0], [12.0, 16.0]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_on_spmm_shape_mismatch() {
+        let sparse = SparseTensor::from_dense(&TestTensor::from_floats([[1.0, 0.0]]));
+        let dense = TestTensor::from_floats([[1.0, 2.0, 3.0]]);
+
+        sparse.spmm(&dense);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index [2, 0] of element 1 is out of bounds for shape [2, 2]")]
+    fn should_panic_on_out_of_bounds_indices() {
+        let indices = IntTensor::from_data(Data::from([[0, 1], [2, 0]]));
+        let values = TestTensor::from_floats([1.0, 2.0]);
+
+        SparseTensor::new(indices, values, Shape::new([2, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected as many indices as values")]
+    fn should_panic_when_indices_and_values_differ() {
+        let indices = IntTensor::from_data(Data::from([[0, 1], [1, 0]]));
+        let values = TestTensor::from_floats([1.0]);
+
+        SparseTensor::new(indices, values, Shape::new([2, 2]));
+    }
+}
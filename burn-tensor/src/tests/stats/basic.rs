@@ -5,8 +5,8 @@ mod tests {
 
     #[test]
     fn test_var() {
-        let data = Data::from([[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]]);
-        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]]);
 
         let data_actual = tensor.var(1).into_data();
 
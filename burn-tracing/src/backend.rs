@@ -0,0 +1,51 @@
+use burn_tensor::backend::Backend;
+use burn_tensor::{Generator, RngState};
+
+use crate::tensor::TracedTensor;
+
+/// Backend decorator recording the operations executed by the inner backend into a
+/// [graph](crate::Graph).
+///
+/// The operations are only recorded while a [tracer](crate::Tracer) is active on the current
+/// thread, the backend otherwise behaving as the inner one.
+///
+/// Boolean tensors aren't traced: the masks used by operations are recorded as constants, with
+/// the values they had during the tracing.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TracingBackend<B> {
+    _b: B,
+}
+
+impl<B: Backend> Backend for TracingBackend<B> {
+    type Device = B::Device;
+    type Elem = B::Elem;
+    type FullPrecisionElem = B::FullPrecisionElem;
+    type IntegerBackend = TracingBackend<B::IntegerBackend>;
+    type FullPrecisionBackend = TracingBackend<B::FullPrecisionBackend>;
+    type TensorPrimitive<const D: usize> = TracedTensor<B, D>;
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<D>;
+
+    fn ad_enabled() -> bool {
+        false
+    }
+
+    fn name() -> String {
+        format!("tracing<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn fork_rng(device: B::Device) -> Generator {
+        B::fork_rng(device)
+    }
+
+    fn get_rng_state(device: B::Device) -> RngState {
+        B::get_rng_state(device)
+    }
+
+    fn set_rng_state(device: B::Device, state: RngState) {
+        B::set_rng_state(device, state)
+    }
+}
@@ -0,0 +1,324 @@
+use burn::module::ParamId;
+use burn_tensor::{f16, Distribution, Element};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::TypeId;
+use std::ops::Range;
+
+/// Position of a node in its [graph](Graph).
+pub type NodeId = usize;
+
+/// Operations performed by the forward pass of a model, recorded by a [tracer](crate::Tracer).
+///
+/// Each node comes after the nodes of its inputs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Graph {
+    /// The nodes in the order of their execution.
+    pub nodes: Vec<Node>,
+    /// The inputs of the model, see [input](crate::Tracer::input).
+    pub inputs: Vec<NodeId>,
+    /// The outputs of the model, see [output](crate::Tracer::output).
+    pub outputs: Vec<NodeId>,
+}
+
+impl Graph {
+    /// Returns the nodes of the parameters of the model with their ids.
+    pub fn params(&self) -> impl Iterator<Item = (NodeId, &ParamId)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(position, node)| match &node.op {
+                Op::Param { id } => Some((position, id)),
+                _ => None,
+            })
+    }
+
+    /// Serialize the graph to json.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Deserialize a graph from json.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Tensor computed by an operation from the tensors of other nodes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Node {
+    pub op: Op,
+    pub inputs: Vec<NodeId>,
+    pub shape: Vec<usize>,
+    pub elem: ElemType,
+}
+
+/// Operations of the [tracing backend](crate::TracingBackend).
+///
+/// The inputs are given in the order of the arguments of the corresponding
+/// [tensor operation](burn_tensor::ops::TensorOps), the node holding the shape of the output.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Op {
+    /// Input of the model.
+    Input,
+    /// Parameter of the model, associated with the id of its [param](burn::module::Param).
+    Param {
+        id: ParamId,
+    },
+    /// Tensor created before the tracing, or whose values aren't computed by traced operations,
+    /// such as tensors created from data and masks.
+    Constant {
+        #[serde(with = "float_values")]
+        values: Vec<f64>,
+    },
+    Empty,
+    Fill {
+        #[serde(with = "float")]
+        value: f64,
+    },
+    Random {
+        distribution: RandomDistribution,
+    },
+    Arange {
+        start: usize,
+        end: usize,
+    },
+    Repeat {
+        dim: usize,
+        times: usize,
+    },
+    Add,
+    AddScalar {
+        #[serde(with = "float")]
+        value: f64,
+    },
+    Sub,
+    SubScalar {
+        #[serde(with = "float")]
+        value: f64,
+    },
+    Mul,
+    MulScalar {
+        #[serde(with = "float")]
+        value: f64,
+    },
+    Div,
+    DivScalar {
+        #[serde(with = "float")]
+        value: f64,
+    },
+    MatMul,
+    /// Sparse matrix in coordinate format, given by its indices and values, multiplied by a dense
+    /// matrix.
+    Spmm,
+    Fft {
+        inverse: bool,
+    },
+    Rfft,
+    Neg,
+    SwapDims {
+        dim1: usize,
+        dim2: usize,
+    },
+    Reshape,
+    Index {
+        ranges: Vec<Range<usize>>,
+    },
+    IndexAssign {
+        ranges: Vec<Range<usize>>,
+    },
+    MaskFill {
+        #[serde(with = "float")]
+        value: f64,
+    },
+    Mean,
+    Sum,
+    MeanDim {
+        dim: usize,
+    },
+    SumDim {
+        dim: usize,
+    },
+    /// Conversion to the element type of the node, such as to and from full precision.
+    Cast,
+    ArgMax {
+        dim: usize,
+    },
+    ArgMin {
+        dim: usize,
+    },
+    Multinomial {
+        num_samples: usize,
+        replacement: bool,
+    },
+    Exp,
+    Log,
+    Powf {
+        value: f32,
+    },
+    Erf,
+    Cat {
+        dim: usize,
+    },
+    Relu,
+    Embedding,
+    EmbeddingBackward,
+}
+
+/// Distribution of a [random](Op::Random) tensor.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RandomDistribution {
+    Standard,
+    Bernoulli {
+        prob: f64,
+    },
+    Uniform {
+        low: f64,
+        high: f64,
+    },
+    Normal {
+        mean: f64,
+        std: f64,
+    },
+    TruncatedNormal {
+        mean: f64,
+        std: f64,
+        low: f64,
+        high: f64,
+    },
+    Exponential {
+        rate: f64,
+    },
+    Gumbel {
+        location: f64,
+        scale: f64,
+    },
+}
+
+impl<P: Element> From<Distribution<P>> for RandomDistribution {
+    fn from(distribution: Distribution<P>) -> Self {
+        match distribution {
+            Distribution::Standard => Self::Standard,
+            Distribution::Bernoulli(prob) => Self::Bernoulli { prob },
+            Distribution::Uniform(low, high) => Self::Uniform {
+                low: low.to_elem(),
+                high: high.to_elem(),
+            },
+            Distribution::Normal(mean, std) => Self::Normal { mean, std },
+            Distribution::TruncatedNormal(mean, std, low, high) => Self::TruncatedNormal {
+                mean,
+                std,
+                low,
+                high,
+            },
+            Distribution::Exponential(rate) => Self::Exponential { rate },
+            Distribution::Gumbel(location, scale) => Self::Gumbel { location, scale },
+        }
+    }
+}
+
+/// Element type of the tensor of a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ElemType {
+    Float16,
+    Float32,
+    Float64,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    Bool,
+}
+
+impl ElemType {
+    pub(crate) fn of<E: 'static>() -> Self {
+        let id = TypeId::of::<E>();
+
+        if id == TypeId::of::<f16>() {
+            Self::Float16
+        } else if id == TypeId::of::<f32>() {
+            Self::Float32
+        } else if id == TypeId::of::<f64>() {
+            Self::Float64
+        } else if id == TypeId::of::<i8>() {
+            Self::Int8
+        } else if id == TypeId::of::<i16>() {
+            Self::Int16
+        } else if id == TypeId::of::<i32>() {
+            Self::Int32
+        } else if id == TypeId::of::<i64>() {
+            Self::Int64
+        } else if id == TypeId::of::<u8>() {
+            Self::UInt8
+        } else if id == TypeId::of::<bool>() {
+            Self::Bool
+        } else {
+            panic!(
+                "Can't trace tensors of element type {}",
+                std::any::type_name::<E>()
+            )
+        }
+    }
+}
+
+/// Json doesn't have infinite and NaN values, they are serialized as strings instead.
+#[derive(Clone, Copy)]
+struct Float(f64);
+
+impl Serialize for Float {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            value if value.is_finite() => serializer.serialize_f64(value),
+            value if value.is_nan() => serializer.serialize_str("NaN"),
+            value if value > 0.0 => serializer.serialize_str("inf"),
+            _ => serializer.serialize_str("-inf"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Float {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Number(f64),
+            Text(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Number(value) => Ok(Float(value)),
+            Repr::Text(text) => match text.as_str() {
+                "NaN" => Ok(Float(f64::NAN)),
+                "inf" => Ok(Float(f64::INFINITY)),
+                "-inf" => Ok(Float(f64::NEG_INFINITY)),
+                _ => Err(serde::de::Error::custom(format!("invalid float {}", text))),
+            },
+        }
+    }
+}
+
+mod float {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        Float(*value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        Float::deserialize(deserializer).map(|value| value.0)
+    }
+}
+
+mod float_values {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(values: &[f64], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(|value| Float(*value)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+        let values = Vec::<Float>::deserialize(deserializer)?;
+
+        Ok(values.into_iter().map(|value| value.0).collect())
+    }
+}
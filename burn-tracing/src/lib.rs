@@ -0,0 +1,17 @@
+pub(crate) mod ops;
+pub(crate) mod tensor;
+pub(crate) mod tracer;
+
+mod backend;
+mod graph;
+pub use backend::*;
+pub use graph::*;
+pub use tracer::{trace, Tracer};
+
+#[cfg(test)]
+mod tests {
+    type TestBackend = crate::TracingBackend<burn_ndarray::NdArrayBackend<f32>>;
+
+    burn_tensor::testgen_all!();
+    burn_autodiff::testgen_all!();
+}
@@ -0,0 +1,25 @@
+use crate::graph::Op;
+use crate::tensor::TracedTensor;
+use crate::tracer::{input, record};
+use crate::TracingBackend;
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::*;
+
+type IntTensor<B, const D: usize> = TracedTensor<<B as Backend>::IntegerBackend, D>;
+
+impl<B: Backend> ModuleOps<TracingBackend<B>> for TracingBackend<B> {
+    fn embedding(weights: &TracedTensor<B, 2>, indexes: &IntTensor<B, 2>) -> TracedTensor<B, 3> {
+        let output = B::embedding(&weights.tensor, &indexes.tensor);
+        record::<B, 3>(Op::Embedding, vec![input(weights), input(indexes)], output)
+    }
+
+    fn embedding_backward(
+        weights: &TracedTensor<B, 2>,
+        output: &TracedTensor<B, 3>,
+        indexes: &IntTensor<B, 2>,
+    ) -> TracedTensor<B, 2> {
+        let inputs = vec![input(weights), input(output), input(indexes)];
+        let output = B::embedding_backward(&weights.tensor, &output.tensor, &indexes.tensor);
+        record::<B, 2>(Op::EmbeddingBackward, inputs, output)
+    }
+}
@@ -0,0 +1,403 @@
+use crate::graph::Op;
+use crate::tensor::TracedTensor;
+use crate::tracer::{bool_input, dequantized, input, record};
+use crate::TracingBackend;
+use burn_tensor::backend::Backend;
+use burn_tensor::{
+    ops::*, Data, Distribution, ElementConversion, Generator, QuantizationScheme, QuantizedData,
+    Shape,
+};
+use std::ops::Range;
+
+type Tensor<B, const D: usize> = TracedTensor<B, D>;
+type BoolTensor<B, const D: usize> = <B as Backend>::BoolTensorPrimitive<D>;
+type IntTensor<B, const D: usize> = TracedTensor<<B as Backend>::IntegerBackend, D>;
+type FullPrecisionTensor<B, const D: usize> = TracedTensor<<B as Backend>::FullPrecisionBackend, D>;
+
+impl<B: Backend, const D: usize> std::ops::Add<TracedTensor<B, D>> for TracedTensor<B, D> {
+    type Output = TracedTensor<B, D>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        TracingBackend::add(&self, &rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> Zeros for TracedTensor<B, D> {
+    fn zeros(&self) -> Self {
+        record::<B, D>(Op::Fill { value: 0.0 }, Vec::new(), self.tensor.zeros())
+    }
+}
+
+impl<B: Backend, const D: usize> Ones for TracedTensor<B, D> {
+    fn ones(&self) -> Self {
+        record::<B, D>(Op::Fill { value: 1.0 }, Vec::new(), self.tensor.ones())
+    }
+}
+
+impl<B: Backend> TensorOps<TracingBackend<B>> for TracingBackend<B> {
+    fn from_data<const D: usize>(data: Data<B::Elem, D>, device: B::Device) -> Tensor<B, D> {
+        TracedTensor::new(B::from_data(data, device))
+    }
+
+    fn from_data_bool<const D: usize>(data: Data<bool, D>, device: B::Device) -> BoolTensor<B, D> {
+        B::from_data_bool(data, device)
+    }
+
+    fn random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        device: B::Device,
+    ) -> Tensor<B, D> {
+        let op = Op::Random {
+            distribution: distribution.into(),
+        };
+        record::<B, D>(op, Vec::new(), B::random(shape, distribution, device))
+    }
+
+    fn random_with<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution<B::Elem>,
+        generator: &mut Generator,
+        device: B::Device,
+    ) -> Tensor<B, D> {
+        let op = Op::Random {
+            distribution: distribution.into(),
+        };
+        let output = B::random_with(shape, distribution, generator, device);
+        record::<B, D>(op, Vec::new(), output)
+    }
+
+    fn zeros<const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+        record::<B, D>(Op::Fill { value: 0.0 }, Vec::new(), B::zeros(shape, device))
+    }
+
+    fn ones<const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+        record::<B, D>(Op::Fill { value: 1.0 }, Vec::new(), B::ones(shape, device))
+    }
+
+    fn shape<const D: usize>(tensor: &Tensor<B, D>) -> &Shape<D> {
+        B::shape(&tensor.tensor)
+    }
+
+    fn to_data<const D: usize>(tensor: &Tensor<B, D>) -> Data<B::Elem, D> {
+        B::to_data(&tensor.tensor)
+    }
+
+    fn into_data<const D: usize>(tensor: Tensor<B, D>) -> Data<B::Elem, D> {
+        B::into_data(tensor.tensor)
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<B, D>) -> &Shape<D> {
+        B::bool_shape(tensor)
+    }
+
+    fn bool_to_data<const D: usize>(tensor: &BoolTensor<B, D>) -> Data<bool, D> {
+        B::bool_to_data(tensor)
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<B, D>) -> Data<bool, D> {
+        B::bool_into_data(tensor)
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<B, D>) -> B::Device {
+        B::bool_device(tensor)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: &BoolTensor<B, D>,
+        device: B::Device,
+    ) -> BoolTensor<B, D> {
+        B::bool_to_device(tensor, device)
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: &BoolTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<B, D2> {
+        B::bool_reshape(tensor, shape)
+    }
+
+    fn device<const D: usize>(tensor: &Tensor<B, D>) -> B::Device {
+        B::device(&tensor.tensor)
+    }
+
+    fn to_device<const D: usize>(tensor: &Tensor<B, D>, device: B::Device) -> Tensor<B, D> {
+        tensor.same(B::to_device(&tensor.tensor, device))
+    }
+
+    fn arange(range: Range<usize>, device: B::Device) -> IntTensor<B, 1> {
+        let op = Op::Arange {
+            start: range.start,
+            end: range.end,
+        };
+        record::<B::IntegerBackend, 1>(op, Vec::new(), B::arange(range, device))
+    }
+
+    fn empty<const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+        record::<B, D>(Op::Empty, Vec::new(), B::empty(shape, device))
+    }
+
+    fn repeat<const D: usize>(tensor: &Tensor<B, D>, dim: usize, times: usize) -> Tensor<B, D> {
+        let output = B::repeat(&tensor.tensor, dim, times);
+        record::<B, D>(Op::Repeat { dim, times }, vec![input(tensor)], output)
+    }
+
+    fn add<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let output = B::add(&lhs.tensor, &rhs.tensor);
+        record::<B, D>(Op::Add, vec![input(lhs), input(rhs)], output)
+    }
+
+    fn add_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let op = Op::AddScalar {
+            value: rhs.to_elem(),
+        };
+        record::<B, D>(op, vec![input(lhs)], B::add_scalar(&lhs.tensor, rhs))
+    }
+
+    fn sub<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let output = B::sub(&lhs.tensor, &rhs.tensor);
+        record::<B, D>(Op::Sub, vec![input(lhs), input(rhs)], output)
+    }
+
+    fn sub_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let op = Op::SubScalar {
+            value: rhs.to_elem(),
+        };
+        record::<B, D>(op, vec![input(lhs)], B::sub_scalar(&lhs.tensor, rhs))
+    }
+
+    fn mul<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let output = B::mul(&lhs.tensor, &rhs.tensor);
+        record::<B, D>(Op::Mul, vec![input(lhs), input(rhs)], output)
+    }
+
+    fn mul_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let op = Op::MulScalar {
+            value: rhs.to_elem(),
+        };
+        record::<B, D>(op, vec![input(lhs)], B::mul_scalar(&lhs.tensor, rhs))
+    }
+
+    fn div<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let output = B::div(&lhs.tensor, &rhs.tensor);
+        record::<B, D>(Op::Div, vec![input(lhs), input(rhs)], output)
+    }
+
+    fn div_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> Tensor<B, D> {
+        let op = Op::DivScalar {
+            value: rhs.to_elem(),
+        };
+        record::<B, D>(op, vec![input(lhs)], B::div_scalar(&lhs.tensor, rhs))
+    }
+
+    fn matmul<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> Tensor<B, D> {
+        let output = B::matmul(&lhs.tensor, &rhs.tensor);
+        record::<B, D>(Op::MatMul, vec![input(lhs), input(rhs)], output)
+    }
+
+    fn spmm(
+        indices: &IntTensor<B, 2>,
+        values: &Tensor<B, 1>,
+        shape: Shape<2>,
+        dense: &Tensor<B, 2>,
+    ) -> Tensor<B, 2> {
+        let output = B::spmm(&indices.tensor, &values.tensor, shape, &dense.tensor);
+        let inputs = vec![input(indices), input(values), input(dense)];
+        record::<B, 2>(Op::Spmm, inputs, output)
+    }
+
+    fn quantize<const D: usize>(
+        tensor: &Tensor<B, D>,
+        scheme: QuantizationScheme,
+    ) -> QuantizedData<D> {
+        B::quantize(&tensor.tensor, scheme)
+    }
+
+    fn dequantize<const D: usize>(data: &QuantizedData<D>, device: B::Device) -> Tensor<B, D> {
+        dequantized(data, B::dequantize(data, device))
+    }
+
+    fn fft<const D: usize>(tensor: &Tensor<B, D>, inverse: bool) -> Tensor<B, D> {
+        let output = B::fft(&tensor.tensor, inverse);
+        record::<B, D>(Op::Fft { inverse }, vec![input(tensor)], output)
+    }
+
+    fn rfft<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        record::<B, D>(Op::Rfft, vec![input(tensor)], B::rfft(&tensor.tensor))
+    }
+
+    fn neg<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        record::<B, D>(Op::Neg, vec![input(tensor)], B::neg(&tensor.tensor))
+    }
+
+    fn swap_dims<const D: usize>(tensor: &Tensor<B, D>, dim1: usize, dim2: usize) -> Tensor<B, D> {
+        let output = B::swap_dims(&tensor.tensor, dim1, dim2);
+        record::<B, D>(Op::SwapDims { dim1, dim2 }, vec![input(tensor)], output)
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: &Tensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> Tensor<B, D2> {
+        let output = B::reshape(&tensor.tensor, shape);
+        record::<B, D2>(Op::Reshape, vec![input(tensor)], output)
+    }
+
+    fn index<const D1: usize, const D2: usize>(
+        tensor: &Tensor<B, D1>,
+        indexes: [Range<usize>; D2],
+    ) -> Tensor<B, D1> {
+        let op = Op::Index {
+            ranges: indexes.to_vec(),
+        };
+        record::<B, D1>(op, vec![input(tensor)], B::index(&tensor.tensor, indexes))
+    }
+
+    fn index_assign<const D1: usize, const D2: usize>(
+        tensor: &Tensor<B, D1>,
+        indexes: [Range<usize>; D2],
+        value: &Tensor<B, D1>,
+    ) -> Tensor<B, D1> {
+        let op = Op::IndexAssign {
+            ranges: indexes.to_vec(),
+        };
+        let output = B::index_assign(&tensor.tensor, indexes, &value.tensor);
+        record::<B, D1>(op, vec![input(tensor), input(value)], output)
+    }
+
+    fn mask_fill<const D: usize>(
+        tensor: &Tensor<B, D>,
+        mask: &BoolTensor<B, D>,
+        value: B::Elem,
+    ) -> Tensor<B, D> {
+        let op = Op::MaskFill {
+            value: value.to_elem(),
+        };
+        let output = B::mask_fill(&tensor.tensor, mask, value);
+        record::<B, D>(op, vec![input(tensor), bool_input::<B, D>(mask)], output)
+    }
+
+    fn equal<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        B::equal(&lhs.tensor, &rhs.tensor)
+    }
+
+    fn equal_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        B::equal_scalar(&lhs.tensor, rhs)
+    }
+
+    fn greater<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        B::greater(&lhs.tensor, &rhs.tensor)
+    }
+
+    fn greater_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        B::greater_scalar(&lhs.tensor, rhs)
+    }
+
+    fn greater_equal<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        B::greater_equal(&lhs.tensor, &rhs.tensor)
+    }
+
+    fn greater_equal_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        B::greater_equal_scalar(&lhs.tensor, rhs)
+    }
+
+    fn lower<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        B::lower(&lhs.tensor, &rhs.tensor)
+    }
+
+    fn lower_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        B::lower_scalar(&lhs.tensor, rhs)
+    }
+
+    fn lower_equal<const D: usize>(lhs: &Tensor<B, D>, rhs: &Tensor<B, D>) -> BoolTensor<B, D> {
+        B::lower_equal(&lhs.tensor, &rhs.tensor)
+    }
+
+    fn lower_equal_scalar<const D: usize>(lhs: &Tensor<B, D>, rhs: &B::Elem) -> BoolTensor<B, D> {
+        B::lower_equal_scalar(&lhs.tensor, rhs)
+    }
+
+    fn detach<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        tensor.same(B::detach(&tensor.tensor))
+    }
+
+    fn mean<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, 1> {
+        record::<B, 1>(Op::Mean, vec![input(tensor)], B::mean(&tensor.tensor))
+    }
+
+    fn sum<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, 1> {
+        record::<B, 1>(Op::Sum, vec![input(tensor)], B::sum(&tensor.tensor))
+    }
+
+    fn mean_dim<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+        let output = B::mean_dim(&tensor.tensor, dim);
+        record::<B, D>(Op::MeanDim { dim }, vec![input(tensor)], output)
+    }
+
+    fn sum_dim<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+        let output = B::sum_dim(&tensor.tensor, dim);
+        record::<B, D>(Op::SumDim { dim }, vec![input(tensor)], output)
+    }
+
+    fn to_full_precision<const D: usize>(tensor: &Tensor<B, D>) -> FullPrecisionTensor<B, D> {
+        let output = B::to_full_precision(&tensor.tensor);
+        record::<B::FullPrecisionBackend, D>(Op::Cast, vec![input(tensor)], output)
+    }
+
+    fn from_full_precision<const D: usize>(tensor: &FullPrecisionTensor<B, D>) -> Tensor<B, D> {
+        let output = B::from_full_precision(&tensor.tensor);
+        record::<B, D>(Op::Cast, vec![input(tensor)], output)
+    }
+
+    fn argmax<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        let output = B::argmax(&tensor.tensor, dim);
+        record::<B::IntegerBackend, D>(Op::ArgMax { dim }, vec![input(tensor)], output)
+    }
+
+    fn argmin<const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        let output = B::argmin(&tensor.tensor, dim);
+        record::<B::IntegerBackend, D>(Op::ArgMin { dim }, vec![input(tensor)], output)
+    }
+
+    fn multinomial<const D: usize>(
+        tensor: &Tensor<B, D>,
+        num_samples: usize,
+        replacement: bool,
+        generator: &mut Generator,
+    ) -> IntTensor<B, D> {
+        let op = Op::Multinomial {
+            num_samples,
+            replacement,
+        };
+        let output = B::multinomial(&tensor.tensor, num_samples, replacement, generator);
+        record::<B::IntegerBackend, D>(op, vec![input(tensor)], output)
+    }
+
+    fn exp<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        record::<B, D>(Op::Exp, vec![input(tensor)], B::exp(&tensor.tensor))
+    }
+
+    fn log<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        record::<B, D>(Op::Log, vec![input(tensor)], B::log(&tensor.tensor))
+    }
+
+    fn powf<const D: usize>(tensor: &Tensor<B, D>, value: f32) -> Tensor<B, D> {
+        let output = B::powf(&tensor.tensor, value);
+        record::<B, D>(Op::Powf { value }, vec![input(tensor)], output)
+    }
+
+    fn erf<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        record::<B, D>(Op::Erf, vec![input(tensor)], B::erf(&tensor.tensor))
+    }
+
+    fn cat<const D: usize>(tensors: &[Tensor<B, D>], dim: usize) -> Tensor<B, D> {
+        let inputs = tensors.iter().map(input).collect();
+        let tensors: Vec<_> = tensors.iter().map(|tensor| tensor.tensor.clone()).collect();
+
+        record::<B, D>(Op::Cat { dim }, inputs, B::cat(&tensors, dim))
+    }
+
+    fn relu<const D: usize>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+        record::<B, D>(Op::Relu, vec![input(tensor)], B::relu(&tensor.tensor))
+    }
+}
@@ -0,0 +1,37 @@
+use burn_tensor::backend::Backend;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Unique id of a tensor, shared by its clones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct TensorId(u64);
+
+impl TensorId {
+    pub(crate) fn new() -> Self {
+        Self(COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TracedTensor<B: Backend, const D: usize> {
+    pub tensor: B::TensorPrimitive<D>,
+    pub(crate) id: TensorId,
+}
+
+impl<B: Backend, const D: usize> TracedTensor<B, D> {
+    pub(crate) fn new(tensor: B::TensorPrimitive<D>) -> Self {
+        Self {
+            tensor,
+            id: TensorId::new(),
+        }
+    }
+
+    /// Returns a tensor with the same id, for operations not changing the values of the tensor.
+    pub(crate) fn same(&self, tensor: B::TensorPrimitive<D>) -> Self {
+        Self {
+            tensor,
+            id: self.id,
+        }
+    }
+}
@@ -0,0 +1,528 @@
+use crate::graph::{ElemType, Graph, Node, NodeId, Op};
+use crate::tensor::{TensorId, TracedTensor};
+use crate::TracingBackend;
+use burn::module::{Module, ModuleVisitor, ParamId};
+use burn_tensor::backend::Backend;
+use burn_tensor::{Data, Element, QuantizationScheme, QuantizedData, Tensor};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+thread_local! {
+    static TRACE: RefCell<Option<Trace>> = const { RefCell::new(None) };
+}
+
+/// Records the operations of the [tracing backend](TracingBackend) executed on the current
+/// thread into a [graph](Graph).
+///
+/// A single tracer can be active on a thread, the tracing ending when the tracer is finished or
+/// dropped.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let tracer = Tracer::start();
+/// tracer.register(&model);
+///
+/// let input = tracer.input(input);
+/// let output = model.forward(input);
+/// tracer.output(&output);
+///
+/// let graph = tracer.finish();
+/// ```
+pub struct Tracer {
+    // The trace belongs to the thread starting it.
+    _thread: PhantomData<*const ()>,
+}
+
+impl Tracer {
+    /// Start tracing the operations executed on the current thread.
+    ///
+    /// # Panics
+    ///
+    /// If a tracer is already active on the current thread.
+    pub fn start() -> Self {
+        TRACE.with(|trace| {
+            let mut trace = trace.borrow_mut();
+
+            if trace.is_some() {
+                panic!("A tracer is already active on the current thread");
+            }
+
+            *trace = Some(Trace::default());
+        });
+
+        Self {
+            _thread: PhantomData,
+        }
+    }
+
+    /// Register the parameters of the module, which are recorded with their [ids](ParamId).
+    ///
+    /// The tensors used by the forward pass without being registered are recorded as constants.
+    ///
+    /// # Panics
+    ///
+    /// If the module doesn't [visit](Module::visit) all of its parameters, which would be
+    /// recorded as constants.
+    pub fn register<B: Backend, M: Module<Backend = TracingBackend<B>>>(&self, module: &M) {
+        let mut registry = ParamRegistry::default();
+        module.visit(&mut registry);
+
+        if registry.num_params != module.num_params() {
+            panic!(
+                "The module has {} parameters, but only {} are visited, Module::visit should be \
+                 implemented to visit all of them",
+                module.num_params(),
+                registry.num_params
+            );
+        }
+    }
+
+    /// Register an input of the model, which is returned unchanged.
+    ///
+    /// The input should be created before the tracing starts, the operations creating it being
+    /// recorded otherwise.
+    ///
+    /// The integer tensors of a backend `B`, such as indexes, are the tensors of
+    /// `TracingBackend<B::IntegerBackend>`.
+    pub fn input<B: Backend, const D: usize>(
+        &self,
+        tensor: Tensor<TracingBackend<B>, D>,
+    ) -> Tensor<TracingBackend<B>, D> {
+        let primitive = tensor.clone().into_primitive();
+
+        with_trace(|trace| {
+            let node = trace.push(Op::Input, Vec::new(), &primitive, Some(primitive.id));
+            trace.inputs.push(node);
+        });
+
+        tensor
+    }
+
+    /// Register an output of the model.
+    pub fn output<B: Backend, const D: usize>(&self, tensor: &Tensor<TracingBackend<B>, D>) {
+        let primitive = tensor.clone().into_primitive();
+        let node = input(&primitive);
+
+        with_trace(|trace| trace.outputs.extend(node));
+    }
+
+    /// Finish tracing, returning the recorded graph.
+    pub fn finish(self) -> Graph {
+        let trace = TRACE.with(|trace| trace.borrow_mut().take()).unwrap();
+
+        Graph {
+            nodes: trace.nodes,
+            inputs: trace.inputs,
+            outputs: trace.outputs,
+        }
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        TRACE.with(|trace| trace.borrow_mut().take());
+    }
+}
+
+/// Trace the forward pass of a module with a single input and output.
+pub fn trace<B, M, F, const D1: usize, const D2: usize>(
+    module: &M,
+    input: Tensor<TracingBackend<B>, D1>,
+    forward: F,
+) -> Graph
+where
+    B: Backend,
+    M: Module<Backend = TracingBackend<B>>,
+    F: FnOnce(&M, Tensor<TracingBackend<B>, D1>) -> Tensor<TracingBackend<B>, D2>,
+{
+    let tracer = Tracer::start();
+    tracer.register(module);
+
+    let input = tracer.input(input);
+    let output = forward(module, input);
+    tracer.output(&output);
+
+    tracer.finish()
+}
+
+#[derive(Default)]
+struct Trace {
+    nodes: Vec<Node>,
+    inputs: Vec<NodeId>,
+    outputs: Vec<NodeId>,
+    tensors: HashMap<TensorId, NodeId>,
+    dequantized: HashMap<QuantizedKey, TensorId>,
+}
+
+/// Quantized data, compared by value.
+#[derive(PartialEq, Eq, Hash)]
+struct QuantizedKey {
+    shape: Vec<usize>,
+    values: Vec<i8>,
+    scheme: QuantizationScheme,
+    scale: Vec<u32>,
+    zero_point: Vec<i8>,
+}
+
+impl QuantizedKey {
+    fn new<const D: usize>(data: &QuantizedData<D>) -> Self {
+        Self {
+            shape: data.values.shape.dims.to_vec(),
+            values: data.values.value.clone(),
+            scheme: data.params.scheme,
+            scale: data
+                .params
+                .scale
+                .iter()
+                .map(|scale| scale.to_bits())
+                .collect(),
+            zero_point: data.params.zero_point.clone(),
+        }
+    }
+}
+
+impl Trace {
+    fn push<B: Backend, const D: usize>(
+        &mut self,
+        op: Op,
+        inputs: Vec<NodeId>,
+        tensor: &TracedTensor<B, D>,
+        id: Option<TensorId>,
+    ) -> NodeId {
+        self.push_node(
+            Node {
+                op,
+                inputs,
+                shape: B::shape(&tensor.tensor).dims.to_vec(),
+                elem: ElemType::of::<B::Elem>(),
+            },
+            id,
+        )
+    }
+
+    fn push_node(&mut self, node: Node, id: Option<TensorId>) -> NodeId {
+        let position = self.nodes.len();
+        self.nodes.push(node);
+
+        if let Some(id) = id {
+            self.tensors.insert(id, position);
+        }
+
+        position
+    }
+}
+
+fn with_trace<R>(func: impl FnOnce(&mut Trace) -> R) -> Option<R> {
+    TRACE.with(|trace| trace.borrow_mut().as_mut().map(func))
+}
+
+/// Returns the node of a tensor used by an operation, recording the tensors unknown to the trace
+/// as constants.
+///
+/// Nothing is returned when the operations aren't traced.
+pub(crate) fn input<B: Backend, const D: usize>(tensor: &TracedTensor<B, D>) -> Option<NodeId> {
+    with_trace(|trace| match trace.tensors.get(&tensor.id) {
+        Some(node) => *node,
+        None => {
+            let values = values(&B::to_data(&tensor.tensor));
+            trace.push(Op::Constant { values }, Vec::new(), tensor, Some(tensor.id))
+        }
+    })
+}
+
+/// Returns the node of a boolean tensor used by an operation, which is always a constant.
+pub(crate) fn bool_input<B: Backend, const D: usize>(
+    tensor: &B::BoolTensorPrimitive<D>,
+) -> Option<NodeId> {
+    with_trace(|trace| {
+        let data = B::bool_to_data(tensor);
+        let node = Node {
+            op: Op::Constant {
+                values: data.value.iter().map(|value| *value as u8 as f64).collect(),
+            },
+            inputs: Vec::new(),
+            shape: data.shape.dims.to_vec(),
+            elem: ElemType::Bool,
+        };
+
+        trace.push_node(node, None)
+    })
+}
+
+/// Returns the tensor dequantizing quantized data.
+///
+/// Quantized tensors keep their data instead of a tensor, so the dequantizations of the same data
+/// share an id while tracing, a quantized param being registered and used by the forward pass
+/// through different dequantizations.
+pub(crate) fn dequantized<B: Backend, const D: usize>(
+    data: &QuantizedData<D>,
+    tensor: B::TensorPrimitive<D>,
+) -> TracedTensor<B, D> {
+    let id = with_trace(|trace| {
+        *trace
+            .dequantized
+            .entry(QuantizedKey::new(data))
+            .or_insert_with(TensorId::new)
+    });
+
+    match id {
+        Some(id) => TracedTensor { tensor, id },
+        None => TracedTensor::new(tensor),
+    }
+}
+
+/// Record the output of an operation.
+pub(crate) fn record<B: Backend, const D: usize>(
+    op: Op,
+    inputs: Vec<Option<NodeId>>,
+    tensor: B::TensorPrimitive<D>,
+) -> TracedTensor<B, D> {
+    let output = TracedTensor::new(tensor);
+
+    with_trace(|trace| {
+        let inputs = inputs.into_iter().flatten().collect();
+        trace.push(op, inputs, &output, Some(output.id));
+    });
+
+    output
+}
+
+fn values<E: Element, const D: usize>(data: &Data<E, D>) -> Vec<f64> {
+    data.value.iter().map(|value| value.to_elem()).collect()
+}
+
+#[derive(Default)]
+struct ParamRegistry {
+    num_params: usize,
+}
+
+impl<B: Backend> ModuleVisitor<TracingBackend<B>> for ParamRegistry {
+    fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<TracingBackend<B>, D>) {
+        let primitive = tensor.clone().into_primitive();
+        self.num_params += tensor.shape().num_elements();
+
+        with_trace(|trace| {
+            let op = Op::Param { id: id.clone() };
+            trace.push(op, Vec::new(), &primitive, Some(primitive.id));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::module::{LoadingError, Param, State, StateNamed};
+    use burn::nn::{Linear, LinearConfig};
+    use burn::optim::Optimizer;
+    use burn_tensor::backend::ADBackend;
+    use burn_tensor::{BoolTensor, Data};
+
+    type TestBackend = TracingBackend<burn_ndarray::NdArrayBackend<f32>>;
+
+    #[derive(Default)]
+    struct ParamIds {
+        ids: Vec<ParamId>,
+    }
+
+    impl<B: Backend> ModuleVisitor<B> for ParamIds {
+        fn visit<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+            self.ids.push(id.clone());
+        }
+    }
+
+    #[test]
+    fn should_record_params_with_their_ids() {
+        let linear = Linear::<TestBackend>::new(&LinearConfig::new(4, 3));
+        let input = Tensor::<TestBackend, 2>::zeros([2, 4]);
+
+        let graph = trace(&linear, input, |linear, input| linear.forward(input));
+
+        let mut ids = ParamIds::default();
+        linear.visit(&mut ids);
+        let params: Vec<_> = graph.params().collect();
+        assert_eq!(params, vec![(0, &ids.ids[0]), (1, &ids.ids[1])]);
+        assert_eq!(graph.inputs, vec![2]);
+
+        let ops: Vec<_> = graph.nodes.iter().map(|node| &node.op).collect();
+        assert_eq!(
+            ops[3..],
+            [&Op::Reshape, &Op::MatMul, &Op::Reshape, &Op::Add]
+        );
+        assert_eq!(graph.nodes[3].inputs, vec![0]);
+        assert_eq!(graph.nodes[4].inputs, vec![2, 3]);
+        assert_eq!(graph.nodes[6].inputs, vec![4, 5]);
+        assert_eq!(graph.nodes[6].shape, vec![2, 3]);
+        assert_eq!(graph.nodes[6].elem, ElemType::Float32);
+        assert_eq!(graph.outputs, vec![6]);
+    }
+
+    #[test]
+    fn should_record_quantized_params_with_their_ids() {
+        let weight = Tensor::<TestBackend, 2>::ones([4, 3]);
+        let weight = Param::new(weight.quantize(QuantizationScheme::PerTensor));
+        let input = Tensor::<TestBackend, 2>::zeros([2, 4]);
+
+        let graph = trace(&weight, input, |weight, input| {
+            input.matmul_quantized(weight)
+        });
+
+        let mut ids = ParamIds::default();
+        weight.visit(&mut ids);
+        let params: Vec<_> = graph.params().collect();
+        assert_eq!(params, vec![(0, &ids.ids[0])]);
+        assert_eq!(graph.nodes[2].op, Op::MatMul);
+        assert_eq!(graph.nodes[2].inputs, vec![1, 0]);
+    }
+
+    /// Module implemented by hand, without visiting its parameters.
+    #[derive(Debug)]
+    struct Unvisited<B: Backend> {
+        linear: Linear<B>,
+    }
+
+    impl<B: Backend> std::fmt::Display for Unvisited<B> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Unvisited")
+        }
+    }
+
+    impl<B: Backend> Module for Unvisited<B> {
+        type Backend = B;
+
+        fn devices(&self) -> Vec<B::Device> {
+            self.linear.devices()
+        }
+
+        fn to_device(&mut self, device: B::Device) {
+            self.linear.to_device(device)
+        }
+
+        fn load(&mut self, state: &State<B::Elem>) -> Result<(), LoadingError> {
+            self.linear.load(state)
+        }
+
+        fn state(&self) -> State<B::Elem> {
+            self.linear.state()
+        }
+
+        fn detach(&mut self) {
+            self.linear.detach()
+        }
+
+        fn num_params(&self) -> usize {
+            self.linear.num_params()
+        }
+
+        fn update_params<O: Optimizer<Backend = B>>(&mut self, grads: &B::Gradients, optim: &mut O)
+        where
+            B: ADBackend,
+        {
+            self.linear.update_params(grads, optim)
+        }
+
+        fn load_optim_state<O: Optimizer<Backend = B>>(
+            &self,
+            optim: &mut O,
+            state_optim: &StateNamed<B::Elem>,
+        ) where
+            B: ADBackend,
+        {
+            self.linear.load_optim_state(optim, state_optim)
+        }
+
+        fn register_optim_state<O: Optimizer<Backend = B>>(
+            &self,
+            optim: &O,
+            state_optim: &mut StateNamed<B::Elem>,
+        ) where
+            B: ADBackend,
+        {
+            self.linear.register_optim_state(optim, state_optim)
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The module has 15 parameters, but only 0 are visited")]
+    fn should_not_register_modules_without_visiting_their_params() {
+        let module = Unvisited {
+            linear: Linear::<TestBackend>::new(&LinearConfig::new(4, 3)),
+        };
+        let input = Tensor::<TestBackend, 2>::zeros([2, 4]);
+
+        trace(&module, input, |module, input| module.linear.forward(input));
+    }
+
+    #[test]
+    fn should_record_unknown_tensors_as_constants() {
+        let scale = Tensor::<TestBackend, 1>::from_data(Data::from([2.0, 3.0]));
+        let input = Tensor::<TestBackend, 1>::ones([2]);
+        let tracer = Tracer::start();
+
+        let input = tracer.input(input);
+        let output = input.mul(&scale).add_scalar(1.0);
+        tracer.output(&output);
+        let graph = tracer.finish();
+
+        assert_eq!(
+            graph.nodes[1].op,
+            Op::Constant {
+                values: vec![2.0, 3.0]
+            }
+        );
+        assert_eq!(graph.nodes[2].inputs, vec![0, 1]);
+        assert_eq!(graph.nodes[3].op, Op::AddScalar { value: 1.0 });
+    }
+
+    #[test]
+    fn should_not_record_without_tracer() {
+        let tracer = Tracer::start();
+        drop(tracer);
+
+        let tensor = Tensor::<TestBackend, 1>::ones([2]);
+        let tracer = Tracer::start();
+        let output = tensor.clone().add(&tensor);
+        tracer.output(&output);
+        let graph = tracer.finish();
+
+        let ops: Vec<_> = graph.nodes.iter().map(|node| &node.op).collect();
+        assert_eq!(
+            ops,
+            vec![
+                &Op::Constant {
+                    values: vec![1.0, 1.0]
+                },
+                &Op::Add
+            ]
+        );
+        assert_eq!(graph.nodes[1].inputs, vec![0, 0]);
+    }
+
+    #[test]
+    fn should_serialize_infinite_values_to_json() {
+        let input = Tensor::<TestBackend, 2>::ones([2, 2]);
+        let tracer = Tracer::start();
+
+        let input = tracer.input(input);
+        let mask = BoolTensor::from_data(Data::from([[true, false], [false, true]]));
+        let output = input.mask_fill(&mask, f32::NEG_INFINITY);
+        tracer.output(&output);
+        let graph = tracer.finish();
+
+        assert_eq!(graph.nodes[1].elem, ElemType::Bool);
+        assert_eq!(
+            graph.nodes[1].op,
+            Op::Constant {
+                values: vec![1.0, 0.0, 0.0, 1.0]
+            }
+        );
+        assert_eq!(
+            graph.nodes[2].op,
+            Op::MaskFill {
+                value: f64::NEG_INFINITY
+            }
+        );
+        assert_eq!(Graph::from_json(&graph.to_json()).unwrap(), graph);
+    }
+}
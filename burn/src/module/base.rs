@@ -1,6 +1,7 @@
-use super::{State, StateNamed};
+use super::{ParamId, State, StateNamed};
 use crate::optim::Optimizer;
 use crate::tensor::backend::{ADBackend, Backend};
+use crate::tensor::Tensor;
 pub use burn_derive::Module;
 
 /// Trait for all neural network modules.
@@ -44,6 +45,12 @@ pub trait Module: Send + Sync + std::fmt::Debug + std::fmt::Display {
     fn state(&self) -> State<<Self::Backend as Backend>::Elem>;
     /// Detach the module from the graph.
     fn detach(&mut self);
+    /// Visit each tensor parameter of the module and all of its sub-modules.
+    ///
+    /// Does nothing by default, so modules implemented by hand without visiting their
+    /// parameters are skipped by visitors, and rejected by visitors needing all of them such as
+    /// the tracer. Derived modules visit all of their parameters.
+    fn visit<V: ModuleVisitor<Self::Backend>>(&self, _visitor: &mut V) {}
     /// Get the number of parameters the module has, including all of its sub-modules.
     fn num_params(&self) -> usize;
     /// Update the module parameters with the given gradients and [optimizer](Optimizer).
@@ -79,6 +86,12 @@ pub trait Module: Send + Sync + std::fmt::Debug + std::fmt::Display {
         Self::Backend: ADBackend;
 }
 
+/// Visitor of the tensor parameters of a [module](Module), see [visit](Module::visit).
+pub trait ModuleVisitor<B: Backend> {
+    /// Visit a tensor parameter with its id.
+    fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>);
+}
+
 /// Module with auto-differentiation backend.
 pub trait ADModule:
     Module<Backend = Self::ADBackend> + Send + Sync + std::fmt::Debug + std::fmt::Display
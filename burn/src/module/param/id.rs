@@ -1,4 +1,7 @@
-#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct ParamId {
     value: String,
 }
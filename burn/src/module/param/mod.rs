@@ -1,6 +1,7 @@
 mod base;
 mod id;
 mod module;
+mod quantized;
 mod tensor;
 
 pub use base::*;
@@ -1,5 +1,5 @@
 use super::{load_with_id, state_with_id, Param};
-use crate::module::{ADModule, LoadingError, Module, State, StateNamed};
+use crate::module::{ADModule, LoadingError, Module, ModuleVisitor, State, StateNamed};
 use crate::optim::Optimizer;
 use crate::tensor::backend::{ADBackend, Backend};
 
@@ -64,6 +64,10 @@ impl<M: Module> Module for Param<M> {
     fn detach(&mut self) {
         self.value.detach()
     }
+
+    fn visit<V: ModuleVisitor<Self::Backend>>(&self, visitor: &mut V) {
+        self.value.visit(visitor);
+    }
 }
 
 impl<M: Module> Module for Param<Vec<M>> {
@@ -165,6 +169,12 @@ impl<M: Module> Module for Param<Vec<M>> {
             value.detach();
         }
     }
+
+    fn visit<V: ModuleVisitor<Self::Backend>>(&self, visitor: &mut V) {
+        for module in self.value.iter() {
+            module.visit(visitor);
+        }
+    }
 }
 
 impl<M: Module> Param<Vec<M>> {
@@ -0,0 +1,125 @@
+use super::{load_with_id, state_with_id, Param};
+use crate::module::{LoadingError, Module, ModuleVisitor, State, StateNamed};
+use crate::optim::Optimizer;
+use crate::tensor::{
+    backend::{ADBackend, Backend},
+    Data, DataSerialize, QuantizationParams, QuantizedData, QuantizedTensor, Shape,
+};
+
+/// Quantized tensors are used for inference only, so they are never updated by an optimizer.
+impl<const D: usize, B: Backend> Module for Param<QuantizedTensor<B, D>> {
+    type Backend = B;
+
+    fn num_params(&self) -> usize {
+        self.value.shape().num_elements()
+    }
+
+    fn update_params<O: Optimizer<Backend = B>>(&mut self, _grads: &B::Gradients, _optim: &mut O)
+    where
+        B: ADBackend,
+    {
+    }
+
+    fn load_optim_state<O: Optimizer<Backend = B>>(
+        &self,
+        _optim: &mut O,
+        _state_optim: &StateNamed<B::Elem>,
+    ) where
+        B: ADBackend,
+    {
+    }
+
+    fn register_optim_state<O: Optimizer<Backend = B>>(
+        &self,
+        _optim: &O,
+        _state_optim: &mut StateNamed<B::Elem>,
+    ) where
+        B: ADBackend,
+    {
+    }
+
+    fn devices(&self) -> Vec<B::Device> {
+        vec![self.value.device()]
+    }
+
+    fn to_device(&mut self, device: B::Device) {
+        self.value = self.value.to_device(device);
+    }
+
+    fn state(&self) -> State<B::Elem> {
+        let data = self.value.data();
+        let num_channels = data.params.scale.len();
+        let mut state = StateNamed::new();
+
+        state.register_state(
+            "values",
+            State::Data(data.values.clone().convert().serialize()),
+        );
+        state.register_state(
+            "scale",
+            State::Data(
+                Data::new(data.params.scale.clone(), Shape::new([num_channels]))
+                    .convert()
+                    .serialize(),
+            ),
+        );
+        state.register_state(
+            "zero_point",
+            State::Data(
+                Data::new(data.params.zero_point.clone(), Shape::new([num_channels]))
+                    .convert()
+                    .serialize(),
+            ),
+        );
+
+        state_with_id(self.id.clone(), State::StateNamed(state))
+    }
+
+    fn load(&mut self, state: &State<B::Elem>) -> Result<(), LoadingError> {
+        let (id, state) = load_with_id(state)?;
+        self.id = id.clone();
+
+        let get = |name: &str| match state.get(name) {
+            Some(State::Data(data)) => Ok(DataSerialize {
+                value: data.value.clone(),
+                shape: data.shape.clone(),
+            }),
+            _ => Err(LoadingError::new(format!(
+                "Can't load quantized tensor without {}",
+                name
+            ))),
+        };
+
+        let values: DataSerialize<i8> = get("values")?.convert();
+        let scale: DataSerialize<f32> = get("scale")?.convert();
+        let zero_point: DataSerialize<i8> = get("zero_point")?.convert();
+
+        let data = QuantizedData {
+            values: Data::from(values),
+            params: QuantizationParams {
+                scheme: self.value.data().params.scheme,
+                scale: scale.value,
+                zero_point: zero_point.value,
+            },
+        };
+        self.value = QuantizedTensor::from_data_device(data, self.value.device());
+
+        Ok(())
+    }
+
+    fn detach(&mut self) {}
+
+    // Visitors see tensors, so quantized params are visited as their dequantized values.
+    fn visit<V: ModuleVisitor<Self::Backend>>(&self, visitor: &mut V) {
+        visitor.visit(&self.id, &self.value.dequantize());
+    }
+}
+
+impl<const D: usize, B: Backend> Param<QuantizedTensor<B, D>> {
+    pub fn inner(&self) -> Param<QuantizedTensor<B::InnerBackend, D>>
+    where
+        B: ADBackend,
+    {
+        Param::new(self.value.inner())
+    }
+}
@@ -32,7 +32,7 @@ pub struct MultiHeadAttentionConfig {
 /// - key: [Linear](nn::Linear) layer with `d_model` input and output features.
 /// - value: [Linear](nn::Linear) layer with `d_model` input and output features.
 /// - output: [Linear](nn::Linear) layer with `d_model` input and output features.
-#[derive(Module, Debug)]
+#[derive(Module, Debug, Clone)]
 pub struct MultiHeadAttention<B: Backend> {
     query: Param<nn::Linear<B>>,
     key: Param<nn::Linear<B>>,
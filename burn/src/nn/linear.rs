@@ -4,7 +4,7 @@ use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
 use crate::tensor::backend::Backend;
-use crate::tensor::{Distribution, ElementConversion, Tensor};
+use crate::tensor::{Distribution, ElementConversion, QuantizationScheme, QuantizedTensor, Tensor};
 use std::ops::Deref;
 
 /// Configuration to create a [Linear](Linear) layer.
@@ -30,7 +30,7 @@ pub struct LinearConfig {
 ///
 /// - bias (optional): Vector of size `d_output` initialized from a uniform distribution:
 ///     `U(-k, k)`, where `k = sqrt(1 / d_input)`
-#[derive(Module, Debug)]
+#[derive(Module, Debug, Clone)]
 pub struct Linear<B: Backend> {
     weight: Param<Tensor<B, 2>>,
     bias: Param<Option<Tensor<B, 1>>>,
@@ -68,4 +68,87 @@ impl<B: Backend> Linear<B> {
             None => output,
         }
     }
+
+    /// Convert the trained layer into a [quantized linear](QuantizedLinear) layer.
+    ///
+    /// The weights are quantized to int8 with one scale and zero-point per output feature, while
+    /// the bias is kept in full precision.
+    pub fn quantize(&self) -> QuantizedLinear<B> {
+        QuantizedLinear {
+            weight: Param::new(self.weight.quantize(QuantizationScheme::PerChannel(1))),
+            bias: Param::new(self.bias.deref().clone()),
+        }
+    }
+}
+
+/// A [linear](Linear) layer with int8 weights, used for inference only.
+///
+/// It is created from a trained layer with [quantize](Linear::quantize).
+#[derive(Module, Debug, Clone)]
+pub struct QuantizedLinear<B: Backend> {
+    weight: Param<QuantizedTensor<B, 2>>,
+    bias: Param<Option<Tensor<B, 1>>>,
+}
+
+impl<B: Backend> QuantizedLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let mut dims = input.dims();
+        let [d_input, d_output] = self.weight.shape().dims;
+        let num_rows = dims[..D - 1].iter().product::<usize>();
+
+        let output = input
+            .reshape([num_rows, d_input])
+            .matmul_quantized(&self.weight);
+
+        dims[D - 1] = d_output;
+        let output = output.reshape(dims);
+
+        match self.bias.deref() {
+            Some(bias) => output + bias.unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn quantized_linear_should_match_linear() {
+        let config = LinearConfig::new(32, 8);
+        let linear = Linear::<TestBackend>::new(&config);
+        let input = Tensor::<TestBackend, 3>::random([2, 4, 32], Distribution::Standard);
+
+        let output = linear.forward(input.clone());
+        let output_quantized = linear.quantize().forward(input);
+
+        assert_eq!(output_quantized.dims(), [2, 4, 8]);
+        output_quantized
+            .to_data()
+            .assert_close(&output.to_data(), 0.0, 0.05);
+    }
+
+    #[test]
+    fn quantized_linear_should_load_its_state() {
+        let config = LinearConfig::new(16, 4);
+        let quantized = Linear::<TestBackend>::new(&config).quantize();
+        let mut quantized_other = Linear::<TestBackend>::new(&config).quantize();
+        let input = Tensor::<TestBackend, 2>::random([3, 16], Distribution::Standard);
+
+        quantized_other.load(&quantized.state()).unwrap();
+
+        assert_eq!(quantized_other.num_params(), 16 * 4 + 4);
+        assert_eq!(
+            quantized_other.forward(input.clone()).to_data(),
+            quantized.forward(input).to_data()
+        );
+    }
 }
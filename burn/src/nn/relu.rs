@@ -19,6 +19,6 @@ impl ReLU {
     /// - input: `[..., any]`
     /// - output: `[..., any]`
     pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        crate::tensor::activation::relu(&input)
+        crate::tensor::activation::relu_owned(input)
     }
 }
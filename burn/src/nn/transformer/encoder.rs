@@ -8,7 +8,7 @@ use crate::{
         attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
         Dropout, DropoutConfig, LayerNorm, LayerNormConfig,
     },
-    tensor::{backend::Backend, BoolTensor, Tensor},
+    tensor::{backend::Backend, module::checkpoint, BoolTensor, Tensor},
 };
 
 /// Configuration to create a [Transformer Encoder](TransformerEncoder) layer.
@@ -25,6 +25,10 @@ pub struct TransformerEncoderConfig {
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     pub dropout: f64,
+    /// Recompute the activations of each layer during the backward pass instead of keeping them
+    /// in memory, see [checkpoint](crate::tensor::module::checkpoint). Default: false
+    #[config(default = false)]
+    pub checkpoint: bool,
 }
 
 /// The transformer encoder module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -35,6 +39,7 @@ pub struct TransformerEncoderConfig {
 #[derive(Module, Debug)]
 pub struct TransformerEncoder<B: Backend> {
     layers: Param<Vec<TransformerEncoderLayer<B>>>,
+    checkpoint: bool,
 }
 
 /// [Transformer Encoder](TransformerEncoder) forward pass input argument.
@@ -78,6 +83,7 @@ impl<B: Backend> TransformerEncoder<B> {
 
         Self {
             layers: Param::new(layers),
+            checkpoint: config.checkpoint,
         }
     }
 
@@ -91,14 +97,27 @@ impl<B: Backend> TransformerEncoder<B> {
         let mut x = input.tensor;
 
         for layer in self.layers.iter() {
-            x = layer.forward(x, input.mask_pad.clone(), input.mask_attn.clone());
+            if self.checkpoint && B::ad_enabled() {
+                // The layer is recomputed during the backward pass, after this function returns,
+                // so the segment owns a copy sharing the tensors of the layer.
+                let layer = layer.clone();
+                let mask_pad = input.mask_pad.clone();
+                let mask_attn = input.mask_attn.clone();
+
+                x = checkpoint(
+                    move |x| layer.forward(x, mask_pad.clone(), mask_attn.clone()),
+                    &x,
+                );
+            } else {
+                x = layer.forward(x, input.mask_pad.clone(), input.mask_attn.clone());
+            }
         }
 
         x
     }
 }
 
-#[derive(Module, Debug)]
+#[derive(Module, Debug, Clone)]
 struct TransformerEncoderLayer<B: Backend> {
     mha: Param<MultiHeadAttention<B>>,
     pwff: Param<PositionWiseFeedForward<B>>,
@@ -157,3 +176,68 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         self.norm_2.forward(x_2)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::{ModuleVisitor, ParamId},
+        tensor::{backend::ADBackend, Distribution},
+        TestADBackend,
+    };
+
+    struct GradsVisitor<'a> {
+        grads: &'a <TestADBackend as ADBackend>::Gradients,
+        norms: Vec<f32>,
+    }
+
+    impl<'a> ModuleVisitor<TestADBackend> for GradsVisitor<'a> {
+        fn visit<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<TestADBackend, D>) {
+            let grad = tensor.grad(self.grads).unwrap();
+            self.norms.push(grad.powf(2.0).sum().to_data().value[0]);
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_should_have_same_gradients() {
+        let [batch_size, seq_length, d_model, d_ff, n_heads, n_layers] = [2, 5, 16, 32, 2, 3];
+        let config =
+            TransformerEncoderConfig::new(d_model, d_ff, n_heads, n_layers).with_dropout(0.0);
+        let encoder = TransformerEncoder::<TestADBackend>::new(&config);
+        let mut encoder_checkpoint =
+            TransformerEncoder::<TestADBackend>::new(&config.with_checkpoint(true));
+        encoder_checkpoint.load(&encoder.state()).unwrap();
+        let tensor = Tensor::random([batch_size, seq_length, d_model], Distribution::Standard);
+
+        let output = encoder.forward(TransformerEncoderInput::new(tensor.clone()));
+        let output_checkpoint =
+            encoder_checkpoint.forward(TransformerEncoderInput::new(tensor.clone()));
+        let grads = output.backward();
+        let grads_checkpoint = output_checkpoint.backward();
+
+        output
+            .to_data()
+            .assert_approx_eq(&output_checkpoint.to_data(), 3);
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&tensor.grad(&grads_checkpoint).unwrap().to_data(), 3);
+
+        let mut visitor = GradsVisitor {
+            grads: &grads,
+            norms: Vec::new(),
+        };
+        encoder.visit(&mut visitor);
+        let mut visitor_checkpoint = GradsVisitor {
+            grads: &grads_checkpoint,
+            norms: Vec::new(),
+        };
+        encoder_checkpoint.visit(&mut visitor_checkpoint);
+
+        assert_eq!(visitor.norms.len(), visitor_checkpoint.norms.len());
+        for (norm, norm_checkpoint) in visitor.norms.iter().zip(visitor_checkpoint.norms) {
+            assert!((norm - norm_checkpoint).abs() <= 1e-3 * norm.max(1.0));
+        }
+    }
+}
@@ -25,7 +25,7 @@ pub struct PositionWiseFeedForwardConfig {
 ///
 /// - linear inner: Linear layer with `d_model` input features and `d_ff` output features.
 /// - linear outer: Linear layer with `d_ff` input features and `d_model` output features.
-#[derive(Module, Debug)]
+#[derive(Module, Debug, Clone)]
 pub struct PositionWiseFeedForward<B: Backend> {
     linear_inner: Param<Linear<B>>,
     linear_outer: Param<Linear<B>>,
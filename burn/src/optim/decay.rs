@@ -36,7 +36,7 @@ impl<B: ADBackend> WeightDecay<B> {
         let id = id.to_string();
 
         let grad = match self.gradients.get::<D>(&id) {
-            Some(grad_last_step) => grad_last_step.mul_scalar(self.penalty).add(&grad),
+            Some(grad_last_step) => grad_last_step.mul_scalar(self.penalty) + grad,
             None => grad,
         };
 
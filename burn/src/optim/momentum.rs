@@ -47,9 +47,9 @@ impl<B: ADBackend> Momentum<B> {
         let id = id.to_string();
 
         let velocity = match self.velocity.get::<D>(&id) {
-            Some(grad_last_step) => grad
-                .mul_scalar(1.0 - self.dampening)
-                .add(&grad_last_step.mul_scalar(self.momentum)),
+            Some(grad_last_step) => {
+                grad.mul_scalar(1.0 - self.dampening) + grad_last_step.mul_scalar(self.momentum)
+            }
             None => grad.clone(),
         };
 
@@ -57,7 +57,7 @@ impl<B: ADBackend> Momentum<B> {
         self.velocity.register(id, velocity.clone());
 
         match self.nesterov {
-            true => velocity.mul_scalar(self.momentum).add(&grad),
+            true => velocity.mul_scalar(self.momentum) + grad,
             false => velocity,
         }
     }
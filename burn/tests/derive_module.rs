@@ -1,6 +1,7 @@
 use burn::module::{Module, Param};
 use burn::tensor::backend::Backend;
 use burn::tensor::{Distribution, Shape, Tensor};
+use std::marker::PhantomData;
 
 pub type TestBackend = burn_ndarray::NdArrayBackend<f32>;
 
@@ -40,6 +41,14 @@ impl<B: Backend> ModuleComposed<B> {
     }
 }
 
+#[derive(Module, Debug)]
+struct ModuleWithoutParams<B>
+where
+    B: Backend,
+{
+    phantom: PhantomData<B>,
+}
+
 mod state {
     use super::*;
 
@@ -95,4 +104,12 @@ mod num_params {
         let module = ModuleComposed::<TestBackend>::new();
         assert_eq!(2 * 20 * 20, module.num_params());
     }
+
+    #[test]
+    fn should_calculate_num_params_without_params() {
+        let module = ModuleWithoutParams::<TestBackend> {
+            phantom: PhantomData,
+        };
+        assert_eq!(0, module.num_params());
+    }
 }
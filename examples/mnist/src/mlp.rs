@@ -61,4 +61,43 @@ impl<B: Backend> Mlp<B> {
 
         x
     }
+
+    /// Convert the trained module into a [quantized](QuantizedMlp) one for inference.
+    pub fn quantize(&self) -> QuantizedMlp<B> {
+        QuantizedMlp {
+            linears: Param::new(
+                self.linears
+                    .iter()
+                    .map(|linear| linear.quantize())
+                    .collect(),
+            ),
+            activation: nn::ReLU::new(),
+        }
+    }
+}
+
+/// Multilayer Perceptron module with int8 weights, used for inference only.
+#[derive(Module, Debug)]
+pub struct QuantizedMlp<B: Backend> {
+    linears: Param<Vec<nn::QuantizedLinear<B>>>,
+    activation: nn::ReLU,
+}
+
+impl<B: Backend> QuantizedMlp<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, d_model]`
+    /// - output: `[batch_size, d_model]`
+    pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+        let mut x = input;
+
+        for linear in self.linears.iter() {
+            x = linear.forward(x);
+            x = self.activation.forward(x);
+        }
+
+        x
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     data::MNISTBatch,
-    mlp::{Mlp, MlpConfig},
+    mlp::{Mlp, MlpConfig, QuantizedMlp},
 };
 use burn::{
     config::Config,
@@ -59,6 +59,14 @@ impl<B: Backend> Model<B> {
         x
     }
 
+    pub fn quantize(&self) -> QuantizedModel<B> {
+        QuantizedModel {
+            mlp: Param::new(self.mlp.quantize()),
+            input: Param::new(self.input.quantize()),
+            output: Param::new(self.output.quantize()),
+        }
+    }
+
     pub fn forward_classification(&self, item: MNISTBatch<B>) -> ClassificationOutput<B> {
         let targets = item.targets;
         let output = self.forward(item.images);
@@ -72,6 +80,25 @@ impl<B: Backend> Model<B> {
     }
 }
 
+#[derive(Module, Debug)]
+pub struct QuantizedModel<B: Backend> {
+    mlp: Param<QuantizedMlp<B>>,
+    input: Param<nn::QuantizedLinear<B>>,
+    output: Param<nn::QuantizedLinear<B>>,
+}
+
+impl<B: Backend> QuantizedModel<B> {
+    pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+        let mut x = input;
+
+        x = self.input.forward(x);
+        x = self.mlp.forward(x);
+        x = self.output.forward(x);
+
+        x
+    }
+}
+
 impl<B: ADBackend> TrainStep<MNISTBatch<B>, ClassificationOutput<B>, B::Gradients> for Model<B> {
     fn step(&self, item: MNISTBatch<B>) -> TrainOutput<ClassificationOutput<B>, B::Gradients> {
         let item = self.forward_classification(item);
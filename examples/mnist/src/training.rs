@@ -1,12 +1,18 @@
-use crate::data::MNISTBatcher;
+use crate::data::{MNISTBatch, MNISTBatcher};
 use crate::mlp::MlpConfig;
 use crate::model::{MnistConfig, Model};
 use burn::{
     config::Config,
-    data::{dataloader::DataLoaderBuilder, dataset::source::huggingface::MNISTDataset},
-    module::Module,
+    data::{
+        dataloader::{DataLoader, DataLoaderBuilder},
+        dataset::source::huggingface::MNISTDataset,
+    },
+    module::{ADModule, Module},
     optim::{decay::WeightDecayConfig, momentum::MomentumConfig, Sgd, SgdConfig},
-    tensor::backend::ADBackend,
+    tensor::{
+        backend::{ADBackend, Backend},
+        Tensor,
+    },
     train::{
         metric::{AccuracyMetric, LossMetric},
         LearnerBuilder,
@@ -53,9 +59,37 @@ pub fn run<B: ADBackend>(device: B::Device) {
         .num_epochs(config.num_epochs)
         .build(model, optim);
 
-    let _model_trained = learner.fit(dataloader_train, dataloader_test);
+    let model_trained = learner.fit(dataloader_train, dataloader_test.clone());
+
+    // Compare the accuracy of the int8 model with the full precision one.
+    let model = model_trained.inner();
+    let model_quantized = model.quantize();
+    let accuracy_float = accuracy(&dataloader_test, |images| model.forward(images));
+    let accuracy_quantized = accuracy(&dataloader_test, |images| model_quantized.forward(images));
+    println!(
+        "Test accuracy: {:.2} % (f32), {:.2} % (int8)",
+        accuracy_float, accuracy_quantized
+    );
 
     config
         .save(format!("{}/config.json", ARTIFACT_DIR).as_str())
         .unwrap();
 }
+
+fn accuracy<B: Backend, F>(dataloader: &Arc<dyn DataLoader<MNISTBatch<B>>>, forward: F) -> f64
+where
+    F: Fn(Tensor<B, 2>) -> Tensor<B, 2>,
+{
+    let mut num_correct = 0;
+    let mut num_items = 0;
+
+    for batch in dataloader.iter() {
+        let predictions = forward(batch.images).argmax(1);
+        let targets = batch.targets.argmax(1);
+
+        num_items += targets.shape().dims[0];
+        num_correct += predictions.equal(&targets).to_int().sum().to_data().value[0] as usize;
+    }
+
+    100.0 * num_correct as f64 / num_items as f64
+}